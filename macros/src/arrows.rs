@@ -0,0 +1,358 @@
+//! Tracing `--->` style arrows between boxes on a [`Canvas`].
+
+use std::collections::HashSet;
+
+use crate::boxes::Outline;
+use crate::canvas::Canvas;
+use crate::error::{Error, Result};
+
+/// An arrow connecting two boxes, identified by their index in the list of
+/// boxes passed to [`trace`].
+#[derive(Debug, PartialEq, Eq)]
+pub struct Arrow {
+    pub from: usize,
+    pub to: usize,
+    /// A word written along the arrow's shaft (e.g: `--yes-->`), if any.
+    pub label: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Dir {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Dir {
+    fn of_head(ch: char) -> Option<Dir> {
+        match ch {
+            '^' => Some(Dir::Up),
+            'v' => Some(Dir::Down),
+            '<' => Some(Dir::Left),
+            '>' => Some(Dir::Right),
+            _ => None,
+        }
+    }
+
+    fn reverse(self) -> Dir {
+        match self {
+            Dir::Up => Dir::Down,
+            Dir::Down => Dir::Up,
+            Dir::Left => Dir::Right,
+            Dir::Right => Dir::Left,
+        }
+    }
+
+    fn is_horizontal(self) -> bool {
+        matches!(self, Dir::Left | Dir::Right)
+    }
+
+    /// Check if `ch` is a piece of shaft that can be travelled along in this
+    /// direction.
+    fn is_shaft(self, ch: char) -> bool {
+        ch == '+' || ch == if self.is_horizontal() { '-' } else { '|' }
+    }
+
+    /// Return the position one step over in this direction.
+    fn step(self, (row, col): (usize, usize)) -> Option<(usize, usize)> {
+        match self {
+            Dir::Up => Some((row.checked_sub(1)?, col)),
+            Dir::Down => Some((row + 1, col)),
+            Dir::Left => Some((row, col.checked_sub(1)?)),
+            Dir::Right => Some((row, col + 1)),
+        }
+    }
+}
+
+fn is_word(ch: char) -> bool {
+    ch.is_alphanumeric() || ch == '_'
+}
+
+struct Tracer<'a> {
+    canvas: &'a Canvas,
+    boxes: &'a [Outline],
+}
+
+/// A way back from an arrowhead to the box it came from.
+struct Path {
+    from: usize,
+    label: Option<String>,
+    cells: Vec<(usize, usize)>,
+}
+
+impl Tracer<'_> {
+    /// Return the box drawn at (or around) the given position.
+    fn box_at(&self, (row, col): (usize, usize)) -> Option<usize> {
+        self.boxes
+            .iter()
+            .position(|b| (b.top..=b.bottom).contains(&row) && (b.left..=b.right).contains(&col))
+    }
+
+    /// Return the box whose edge passes through the given position.
+    fn edge_at(&self, (row, col): (usize, usize)) -> Option<usize> {
+        let i = self.box_at((row, col))?;
+        let b = &self.boxes[i];
+        if row == b.top || row == b.bottom || col == b.left || col == b.right {
+            Some(i)
+        } else {
+            None
+        }
+    }
+
+    /// Return the span of the word passing through the given position.
+    fn word_at(&self, (row, col): (usize, usize)) -> (usize, usize) {
+        let mut start = col;
+        while start > 0 && is_word(self.canvas.get(row, start - 1)) {
+            start -= 1;
+        }
+        let mut end = col;
+        while is_word(self.canvas.get(row, end + 1)) {
+            end += 1;
+        }
+        (start, end)
+    }
+
+    /// Walk backwards along an arrow's shaft, collecting every way back to a
+    /// box.
+    fn walk(
+        &self,
+        pos: (usize, usize),
+        dir: Dir,
+        label: Option<String>,
+        cells: &mut Vec<(usize, usize)>,
+        paths: &mut Vec<Path>,
+    ) {
+        let next = match dir.step(pos) {
+            Some(next) => next,
+            None => return,
+        };
+        if let Some(from) = self.edge_at(next) {
+            paths.push(Path {
+                from,
+                label,
+                cells: cells.clone(),
+            });
+            return;
+        }
+        if cells.contains(&next) {
+            return;
+        }
+
+        let ch = self.canvas.get(next.0, next.1);
+        if dir.is_shaft(ch) {
+            cells.push(next);
+            if ch == '+' {
+                let turns = if dir.is_horizontal() {
+                    [Dir::Up, Dir::Down]
+                } else {
+                    [Dir::Left, Dir::Right]
+                };
+                for d in [dir, turns[0], turns[1]] {
+                    self.walk(next, d, label.clone(), cells, paths);
+                }
+            } else {
+                self.walk(next, dir, label, cells, paths);
+            }
+            cells.pop();
+        } else if is_word(ch) {
+            // labels nearest the arrowhead win
+            let (start, end) = self.word_at(next);
+            if start == end && Dir::of_head(ch).is_some() {
+                // that's somebody else's arrowhead
+                return;
+            }
+            let word = self.canvas.text(next.0, start..end + 1);
+            let label = label.or(Some(word));
+            let next = match dir {
+                Dir::Left => (next.0, start),
+                Dir::Right => (next.0, end),
+                Dir::Up | Dir::Down => next,
+            };
+            self.walk(next, dir, label, cells, paths);
+        }
+    }
+}
+
+/// Find every arrow connecting the given boxes, in reading order of their
+/// arrowheads.
+///
+/// Arrows are made up of `-` and `|` shafts, with `+` used for corners and
+/// junctions, and one of `>`, `<`, `v` or `^` for a head. Arrows must start and
+/// end right up against the edges of a box, and several arrows may branch off
+/// of a single shaft. Any bits of shaft that aren't part of an arrow are
+/// reported as errors.
+pub fn trace(canvas: &Canvas, boxes: &[Outline]) -> Result<Vec<Arrow>> {
+    let tracer = Tracer { canvas, boxes };
+    let mut arrows = Vec::new();
+    let mut used = HashSet::new();
+
+    for row in 0..canvas.height() {
+        for col in 0..canvas.width(row) {
+            let head = (row, col);
+            if tracer.box_at(head).is_some() {
+                continue;
+            }
+            let dir = match Dir::of_head(canvas.get(row, col)) {
+                // make sure not to mistake the `v` in a word for an arrowhead
+                Some(_) if col > 0 && is_word(canvas.get(row, col - 1)) => continue,
+                Some(_) if is_word(canvas.get(row, col + 1)) => continue,
+                Some(dir) => dir,
+                None => continue,
+            };
+            let leads_in = dir
+                .reverse()
+                .step(head)
+                .map(|tail| canvas.get(tail.0, tail.1))
+                .is_some_and(|ch| dir.is_shaft(ch) || is_word(ch));
+            if !leads_in {
+                continue;
+            }
+
+            let to = dir.step(head).and_then(|tip| tracer.edge_at(tip));
+            let to = to.ok_or_else(|| {
+                Error::new(canvas.span(row, col), "this arrow doesn't point at a box")
+            })?;
+
+            let mut paths = Vec::new();
+            tracer.walk(head, dir.reverse(), None, &mut Vec::new(), &mut paths);
+
+            let mut sources = paths.iter().map(|p| p.from).collect::<Vec<_>>();
+            sources.sort_unstable();
+            sources.dedup();
+            let path = match sources.as_slice() {
+                [_] => paths.swap_remove(0),
+                [] => {
+                    return Err(Error::new(
+                        canvas.span(row, col),
+                        "this arrow doesn't start at a box",
+                    ))
+                }
+                _ => {
+                    return Err(Error::new(
+                        canvas.span(row, col),
+                        "this arrow starts at more than one box",
+                    ))
+                }
+            };
+
+            for p in &paths {
+                used.extend(p.cells.iter().copied());
+            }
+            used.extend(path.cells);
+            arrows.push(Arrow {
+                from: path.from,
+                to,
+                label: path.label,
+            });
+        }
+    }
+
+    for row in 0..canvas.height() {
+        for col in 0..canvas.width(row) {
+            if matches!(canvas.get(row, col), '-' | '|' | '+')
+                && tracer.box_at((row, col)).is_none()
+                && !used.contains(&(row, col))
+            {
+                return Err(Error::new(
+                    canvas.span(row, col),
+                    "this line isn't part of an arrow between two boxes",
+                ));
+            }
+        }
+    }
+
+    Ok(arrows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::boxes;
+
+    fn arrows(drawing: &str) -> Result<Vec<(usize, usize, Option<String>)>> {
+        let canvas = Canvas::from_str(drawing);
+        let boxes = boxes::find(&canvas);
+        Ok(trace(&canvas, &boxes)?
+            .into_iter()
+            .map(|a| (a.from, a.to, a.label))
+            .collect())
+    }
+
+    #[test]
+    fn straight() {
+        let drawing = "
++---+     +---+
+| a |---->| b |
++---+     +---+
+  |
+  v
++---+
+| c |
++---+
+";
+        assert_eq!(arrows(drawing).unwrap(), [(0, 1, None), (0, 2, None)]);
+    }
+
+    #[test]
+    fn corners_and_labels() {
+        let drawing = "
++---+
+| a |--+
++---+  |
+  ^   yes
+  |    |
+  no   v
+  |  +---+
+  +--| b |
+     +---+
+";
+        assert_eq!(
+            arrows(drawing).unwrap(),
+            [
+                (1, 0, Some("no".to_owned())),
+                (0, 1, Some("yes".to_owned())),
+            ]
+        );
+    }
+
+    #[test]
+    fn branches() {
+        let drawing = "
+    +---+
+    | a |
+    +---+
+      |
+  +---+---+
+  v       v
++---+   +---+
+| b |   | c |
++---+   +---+
+";
+        assert_eq!(arrows(drawing).unwrap(), [(0, 1, None), (0, 2, None)]);
+    }
+
+    #[test]
+    fn dangling() {
+        let drawing = "
++---+
+| a |---->
++---+
+";
+        assert!(arrows(drawing).is_err());
+
+        let drawing = "
+      +---+
+  --->| a |
+      +---+
+";
+        assert!(arrows(drawing).is_err());
+
+        let drawing = "
++---+     +---+
+| a |-----| b |
++---+     +---+
+";
+        assert!(arrows(drawing).is_err());
+    }
+}
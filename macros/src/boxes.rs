@@ -0,0 +1,202 @@
+//! Finding `+--+` style boxes on a [`Canvas`].
+
+use crate::canvas::Canvas;
+use crate::error::{Error, Result};
+
+/// A box drawn on a canvas, identified by the (inclusive) positions of its
+/// `+` corners.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Outline {
+    pub top: usize,
+    pub left: usize,
+    pub bottom: usize,
+    pub right: usize,
+}
+
+impl Outline {
+    /// Width of the box, counted in `--`.
+    ///
+    /// Errors out if the box's edges aren't made up of `--` pairs.
+    pub fn w(&self, canvas: &Canvas) -> Result<usize> {
+        let dashes = self.right - self.left - 1;
+        if !dashes.is_multiple_of(2) {
+            return Err(Error::new(
+                canvas.span(self.top, self.left),
+                "rectangle edges must be drawn using pairs of `--`",
+            ));
+        }
+        Ok(dashes / 2)
+    }
+
+    /// Height of the box, counted in `|`.
+    pub fn h(&self) -> usize {
+        self.bottom - self.top - 1
+    }
+
+    /// Check if `other` is drawn entirely inside this box, without sharing
+    /// any of its walls.
+    pub fn encloses(&self, other: &Outline) -> bool {
+        self.top < other.top
+            && self.left < other.left
+            && other.bottom < self.bottom
+            && other.right < self.right
+    }
+
+    /// Check if `other` shares any space with this box.
+    pub fn overlaps(&self, other: &Outline) -> bool {
+        self.top <= other.bottom
+            && other.top <= self.bottom
+            && self.left <= other.right
+            && other.left <= self.right
+    }
+}
+
+/// Find every box on the canvas.
+///
+/// Boxes that share walls (e.g: the cells of a table) are each reported
+/// individually, as the smallest box that can be traced from their top-left
+/// corner. Boxes are returned in reading order of their top-left corners.
+pub fn find(canvas: &Canvas) -> Vec<Outline> {
+    find_walled(canvas, '|', '|')
+}
+
+/// Like [`find`], except the left and right walls of the boxes are drawn
+/// using the given characters instead of `|`.
+pub fn find_walled(canvas: &Canvas, left_wall: char, right_wall: char) -> Vec<Outline> {
+    let mut found = Vec::new();
+    for top in 0..canvas.height() {
+        for left in 0..canvas.width(top) {
+            if let Some(outline) = trace(canvas, top, left, (left_wall, right_wall)) {
+                found.push(outline);
+            }
+        }
+    }
+    found
+}
+
+fn is_horizontal(ch: char) -> bool {
+    ch == '-' || ch == '+'
+}
+
+fn is_wall(ch: char, wall: char) -> bool {
+    ch == wall || ch == '+'
+}
+
+/// Trace the smallest box with a top-left corner at the given position.
+fn trace(canvas: &Canvas, top: usize, left: usize, walls: (char, char)) -> Option<Outline> {
+    let (left_wall, right_wall) = walls;
+    if canvas.get(top, left) != '+'
+        || !is_horizontal(canvas.get(top, left + 1))
+        || !is_wall(canvas.get(top + 1, left), left_wall)
+    {
+        return None;
+    }
+
+    // the top-right corner is the first `+` along the top edge with a wall
+    // hanging down from it
+    let mut right = left + 1;
+    loop {
+        match canvas.get(top, right) {
+            '+' if is_wall(canvas.get(top + 1, right), right_wall) => break,
+            ch if is_horizontal(ch) => right += 1,
+            _ => return None,
+        }
+    }
+
+    // ...and the bottom-left corner is the first `+` along the left edge with
+    // a floor sticking out of it
+    let mut bottom = top + 1;
+    loop {
+        match canvas.get(bottom, left) {
+            '+' if is_horizontal(canvas.get(bottom, left + 1)) => break,
+            ch if is_wall(ch, left_wall) => bottom += 1,
+            _ => return None,
+        }
+    }
+
+    let closed = canvas.get(bottom, right) == '+'
+        && (top + 1..bottom).all(|row| is_wall(canvas.get(row, right), right_wall))
+        && (left + 1..right).all(|col| is_horizontal(canvas.get(bottom, col)));
+
+    if closed {
+        Some(Outline {
+            top,
+            left,
+            bottom,
+            right,
+        })
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested() {
+        let canvas = Canvas::from_str(
+            "
++--------------+
+|  +----+      |
+|  |    | +--+ |
+|  +----+ +--+ |
++--------------+
+",
+        );
+        let found = find(&canvas);
+        assert_eq!(found.len(), 3);
+        assert!(found[0].encloses(&found[1]));
+        assert!(found[0].encloses(&found[2]));
+        assert!(!found[1].overlaps(&found[2]));
+        assert_eq!(found[1].w(&canvas).unwrap(), 2);
+        assert_eq!(found[1].h(), 1);
+        assert_eq!(found[2].h(), 0);
+    }
+
+    #[test]
+    fn shared_walls() {
+        let canvas = Canvas::from_str(
+            "
++----+--+
+|    |  |
++--+-+--+
+|  |    |
++--+----+
+",
+        );
+        let widths = find(&canvas)
+            .iter()
+            .map(|b| b.w(&canvas).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(widths, [2, 1, 1, 2]);
+    }
+
+    #[test]
+    fn open() {
+        let canvas = Canvas::from_str(
+            "
++----+
+|    |
++--  +
+",
+        );
+        assert!(find(&canvas).is_empty());
+    }
+
+    #[test]
+    fn walled() {
+        let canvas = Canvas::from_str(
+            "
++----+  +----+
+|    |  <    >
++----+  +----+
+",
+        );
+        assert_eq!(find(&canvas).len(), 1);
+        let found = find_walled(&canvas, '<', '>');
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].left, 8);
+    }
+}
@@ -0,0 +1,194 @@
+//! Reconstructs the 2D drawing behind a stream of tokens.
+//!
+//! `macro_rules!` only ever gets to see a flat list of tokens, which is why
+//! `analog_literal!` has to count tokens instead of looking at the picture.
+//! Procedural macros on the other hand get to peek at the line and column of
+//! every token, which is just enough to paint the drawing back onto a grid of
+//! characters.
+//!
+//! Comments never make it into the token stream, so they show up as blank
+//! space on the canvas.
+
+use proc_macro::{Delimiter, Span, TokenStream, TokenTree};
+
+use crate::error::{Error, Result};
+
+/// A grid of characters, along with the span of the token each character came
+/// from (if any).
+pub struct Canvas {
+    rows: Vec<Vec<(char, Option<Span>)>>,
+    line: usize,
+}
+
+impl Canvas {
+    /// Paint the tokens in `input` onto a canvas.
+    ///
+    /// Rows are relative to the line of the first token, whereas columns are
+    /// left as-is (i.e: relative to the start of the line in the source file).
+    pub fn from_tokens(input: TokenStream) -> Canvas {
+        let mut painted = Vec::new();
+        collect(input, &mut painted);
+
+        let mut canvas = Canvas {
+            rows: Vec::new(),
+            line: painted
+                .iter()
+                .map(|(_, span)| span.line())
+                .min()
+                .unwrap_or(1),
+        };
+        for (text, span) in painted {
+            let row = span.line() - canvas.line;
+            let col = span.column().saturating_sub(1);
+            canvas.paint(row, col, &text, Some(span));
+        }
+
+        canvas
+    }
+
+    /// Paint a plain old string onto a canvas. Handy for testing.
+    #[cfg(test)]
+    pub fn from_str(s: &str) -> Canvas {
+        let mut canvas = Canvas {
+            rows: Vec::new(),
+            line: 1,
+        };
+        canvas.paint(0, 0, s, None);
+        canvas
+    }
+
+    fn paint(&mut self, mut row: usize, col: usize, text: &str, span: Option<Span>) {
+        let mut c = col;
+        for ch in text.chars() {
+            if ch == '\n' {
+                row += 1;
+                c = 0;
+                continue;
+            }
+
+            if self.rows.len() <= row {
+                self.rows.resize_with(row + 1, Vec::new);
+            }
+            let cells = &mut self.rows[row];
+            if cells.len() <= c {
+                cells.resize(c + 1, (' ', None));
+            }
+            cells[c] = (ch, span);
+            c += 1;
+        }
+    }
+
+    /// The source line corresponding to the top row of the canvas.
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    /// Number of rows on the canvas.
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Number of columns in the given row.
+    pub fn width(&self, row: usize) -> usize {
+        self.rows.get(row).map_or(0, Vec::len)
+    }
+
+    /// Return the character at the given position, with anything off the edge
+    /// of the canvas being treated as whitespace.
+    pub fn get(&self, row: usize, col: usize) -> char {
+        self.rows
+            .get(row)
+            .and_then(|r| r.get(col))
+            .map_or(' ', |(ch, _)| *ch)
+    }
+
+    /// Return the characters in the given row between the given columns.
+    pub fn text(&self, row: usize, cols: std::ops::Range<usize>) -> String {
+        cols.map(|col| self.get(row, col)).collect()
+    }
+
+    /// Return the span of the token at the given position.
+    pub fn span(&self, row: usize, col: usize) -> Option<Span> {
+        self.rows.get(row)?.get(col)?.1
+    }
+}
+
+fn collect(input: TokenStream, painted: &mut Vec<(String, Span)>) {
+    for tt in input {
+        match tt {
+            TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Brace => ("{", "}"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::None => ("", ""),
+                };
+                if !open.is_empty() {
+                    painted.push((open.to_string(), group.span_open()));
+                }
+                collect(group.stream(), painted);
+                if !close.is_empty() {
+                    painted.push((close.to_string(), group.span_close()));
+                }
+            }
+            TokenTree::Ident(ident) => painted.push((ident.to_string(), ident.span())),
+            TokenTree::Punct(punct) => painted.push((punct.as_char().to_string(), punct.span())),
+            TokenTree::Literal(lit) => painted.push((lit.to_string(), lit.span())),
+        }
+    }
+}
+
+/// The spot on the canvas that positioned shapes are measured from.
+pub struct Origin {
+    pub line: usize,
+    pub col: usize,
+}
+
+impl Origin {
+    /// Figure out the origin of a drawing from the drawing itself.
+    ///
+    /// If the first row of the drawing is nothing but a lone `+`, that `+`
+    /// marks the origin. Otherwise, the origin is the top-left corner of
+    /// whatever was drawn. Either way, re-indenting the whole drawing doesn't
+    /// move anything.
+    pub fn of_drawing(canvas: &Canvas) -> Origin {
+        let blank = |row| canvas.text(row, 0..canvas.width(row)).trim().is_empty();
+        let top = (0..canvas.height()).find(|&row| !blank(row)).unwrap_or(0);
+
+        let first = canvas.text(top, 0..canvas.width(top));
+        if first.trim() == "+" {
+            return Origin {
+                line: canvas.line() + top,
+                col: first.find('+').unwrap_or(0),
+            };
+        }
+
+        let col = (0..canvas.height())
+            .filter_map(|row| (0..canvas.width(row)).find(|&col| canvas.get(row, col) != ' '))
+            .min()
+            .unwrap_or(0);
+        Origin {
+            line: canvas.line() + top,
+            col,
+        }
+    }
+
+    /// Return the `(x, y)` position of the given point on the canvas, counting
+    /// `--` horizontally and rows vertically.
+    pub fn locate(&self, canvas: &Canvas, row: usize, col: usize) -> Result<(usize, usize)> {
+        let line = canvas.line() + row;
+        if col < self.col {
+            return Err(Error::new(
+                canvas.span(row, col),
+                "positioned shapes can't be drawn to the left of the origin",
+            ));
+        }
+        if !(col - self.col).is_multiple_of(2) {
+            return Err(Error::new(
+                canvas.span(row, col),
+                "positions are measured in `--`, so this shape is off by a `-`",
+            ));
+        }
+        Ok(((col - self.col) / 2, line - self.line))
+    }
+}
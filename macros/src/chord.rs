@@ -0,0 +1,197 @@
+//! `analog_chord!`
+
+use proc_macro::TokenStream;
+
+use crate::canvas::Canvas;
+use crate::error::{Error, Result};
+
+pub fn expand(input: TokenStream) -> Result<TokenStream> {
+    let canvas = Canvas::from_tokens(input);
+
+    let mut code = String::from("$crate::music::Chord { frets: [");
+    for fret in parse(&canvas)? {
+        match fret {
+            Some(fret) => code.push_str(&format!("::core::option::Option::Some({}),", fret)),
+            None => code.push_str("::core::option::Option::None,"),
+        }
+    }
+    code.push_str("] }");
+    Ok(code.parse().unwrap())
+}
+
+const STRINGS: usize = 6;
+
+/// Check if the given row of the canvas is a `+--+--+` style fret.
+fn is_fret(canvas: &Canvas, row: usize) -> bool {
+    let text = canvas.text(row, 0..canvas.width(row));
+    let text = text.trim();
+    text.starts_with('+') && text.chars().all(|ch| matches!(ch, '+' | '-' | '='))
+}
+
+fn parse(canvas: &Canvas) -> Result<[Option<u8>; STRINGS]> {
+    let nut = (0..canvas.height())
+        .find(|&row| is_fret(canvas, row))
+        .ok_or_else(|| Error::new(None, "expected a chord box, starting with a `+--+--+` nut"))?;
+
+    let strings = (0..canvas.width(nut))
+        .filter(|&col| canvas.get(nut, col) == '+')
+        .collect::<Vec<_>>();
+    if strings.len() != STRINGS {
+        return Err(Error::new(
+            canvas.span(nut, strings[0]),
+            format!("expected {} strings, found {}", STRINGS, strings.len()),
+        ));
+    }
+    let right = strings[STRINGS - 1];
+
+    let mut frets: [Option<u8>; STRINGS] = [None; STRINGS];
+    let mut markers = [None; STRINGS];
+
+    // anything above the nut marks open and muted strings
+    for row in 0..nut {
+        for (string, &col) in strings.iter().enumerate() {
+            match canvas.get(row, col) {
+                ' ' => {}
+                'o' | 'O' | 'x' | 'X' if markers[string].is_none() => {
+                    markers[string] = Some((canvas.get(row, col).to_ascii_lowercase(), row))
+                }
+                _ => {
+                    return Err(Error::new(
+                        canvas.span(row, col),
+                        "expected a single `o` (open) or `x` (muted) above the nut",
+                    ))
+                }
+            }
+        }
+    }
+
+    let mut base = 1;
+    let mut fret = 0;
+    for row in nut + 1..canvas.height() {
+        if is_fret(canvas, row) {
+            continue;
+        }
+        fret += 1;
+
+        for (string, &col) in strings.iter().enumerate() {
+            match canvas.get(row, col) {
+                '|' => {}
+                '*' | 'o' | '@' => frets[string] = Some(fret),
+                _ => {
+                    return Err(Error::new(
+                        canvas.span(row, col),
+                        "expected either a `|` string or a `*` finger",
+                    ))
+                }
+            }
+        }
+
+        // a number to the right of the first fret shifts the whole box up the
+        // neck
+        let label = canvas.text(row, right + 1..canvas.width(row));
+        let label = label.trim();
+        if !label.is_empty() {
+            if fret != 1 {
+                return Err(Error::new(
+                    canvas.span(row, canvas.width(row) - 1),
+                    "only the first fret can be labeled with a fret number",
+                ));
+            }
+            base = label
+                .trim_end_matches("fr")
+                .parse::<u8>()
+                .ok()
+                .filter(|base| *base > 0)
+                .ok_or_else(|| {
+                    Error::new(
+                        canvas.span(row, canvas.width(row) - 1),
+                        "expected a fret number (e.g: `5fr`)",
+                    )
+                })?;
+        }
+    }
+
+    for (string, fret) in frets.iter_mut().enumerate() {
+        let col = strings[string];
+        match (*fret, markers[string]) {
+            (Some(f), None) => {
+                *fret = Some(f.checked_add(base - 1).ok_or_else(|| {
+                    Error::new(canvas.span(nut, col), "that's one long guitar neck")
+                })?)
+            }
+            (None, Some(('o', _))) => *fret = Some(0),
+            (None, Some(_)) => {}
+            (Some(_), Some((_, row))) => {
+                return Err(Error::new(
+                    canvas.span(row, col),
+                    "fretted strings can't be marked as open or muted",
+                ))
+            }
+            (None, None) => {
+                return Err(Error::new(
+                    canvas.span(nut, col),
+                    "strings must be either open (`o`), muted (`x`), or fretted (`*`)",
+                ))
+            }
+        }
+    }
+
+    Ok(frets)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn c_major() {
+        let canvas = Canvas::from_str(
+            "
+x        o     o
++==+==+==+==+==+
+|  |  |  |  *  |
++--+--+--+--+--+
+|  |  *  |  |  |
++--+--+--+--+--+
+|  *  |  |  |  |
++--+--+--+--+--+
+",
+        );
+        assert_eq!(
+            parse(&canvas).unwrap(),
+            [None, Some(3), Some(2), Some(0), Some(1), Some(0)]
+        );
+    }
+
+    #[test]
+    fn up_the_neck() {
+        let canvas = Canvas::from_str(
+            "
++--+--+--+--+--+
+*  |  |  |  *  *  5fr
++--+--+--+--+--+
+|  |  |  *  |  |
++--+--+--+--+--+
+|  *  *  |  |  |
++--+--+--+--+--+
+",
+        );
+        assert_eq!(
+            parse(&canvas).unwrap(),
+            [Some(5), Some(7), Some(7), Some(6), Some(5), Some(5)]
+        );
+    }
+
+    #[test]
+    fn unspecified() {
+        let canvas = Canvas::from_str(
+            "
+x        o
++--+--+--+--+--+
+|  |  |  |  *  |
++--+--+--+--+--+
+",
+        );
+        assert!(parse(&canvas).is_err());
+    }
+}
@@ -0,0 +1,280 @@
+//! `#[derive(AnalogDebug)]`
+
+use proc_macro::{Delimiter, Spacing, TokenStream, TokenTree};
+
+use crate::error::{Error, Result};
+use crate::krate;
+
+pub fn expand(input: TokenStream) -> Result<TokenStream> {
+    let krate = find_crate(&input.clone().into_iter().collect::<Vec<_>>())?;
+    let item = parse(input)?;
+
+    let field = |access: &str| {
+        format!(
+            "$crate::__private::autoref::Field::new(&self.{0}, \
+             (&&$crate::__private::autoref::Wrap(&self.{0})).drawing())",
+            access
+        )
+    };
+    let body = match &item.fields {
+        Fields::Named(names) => {
+            let mut body = format!(
+                "$crate::__private::autoref::debug_struct(f, {:?}, &[",
+                item.name
+            );
+            for name in names {
+                body.push_str(&format!("({:?}, {}),", name, field(name)));
+            }
+            body + "])"
+        }
+        Fields::Unnamed(n) => {
+            let mut body = format!("f.debug_tuple({:?})", item.name);
+            for i in 0..*n {
+                body.push_str(&format!(".field(&{})", field(&i.to_string())));
+            }
+            body + ".finish()"
+        }
+        Fields::Unit => format!("f.write_str({:?})", item.name),
+    };
+
+    let mut bounds = item.where_clause.clone();
+    for ty in &item.type_params {
+        if !bounds.is_empty() && !bounds.trim_end().ends_with(',') {
+            bounds.push(',');
+        }
+        bounds.push_str(&format!("{}: ::core::fmt::Debug,", ty));
+    }
+
+    Ok(format!(
+        "impl<{impl_generics}> ::core::fmt::Debug for {name}<{ty_generics}> where {bounds} {{
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {{
+                #[allow(unused_imports)]
+                use $crate::__private::autoref::{{Drawn as _, NotDrawn as _}};
+                {body}
+            }}
+        }}",
+        impl_generics = item.impl_generics.join(","),
+        name = item.name,
+        ty_generics = item.ty_generics.join(","),
+        bounds = bounds,
+        body = body,
+    )
+    .parse()
+    .map(|code| krate::splice(code, &krate))
+    .unwrap())
+}
+
+/// Look for a `#[analog_debug(crate = path)]` attribute, for crates that have
+/// renamed `analog_literals` to something else.
+fn find_crate(tokens: &[TokenTree]) -> Result<TokenStream> {
+    for pair in tokens.windows(2) {
+        let attr = match pair {
+            [hash, TokenTree::Group(g)] if is_punct(hash, '#') => g.stream(),
+            _ => continue,
+        };
+        let attr = attr.into_iter().collect::<Vec<_>>();
+        if let [TokenTree::Ident(name), TokenTree::Group(args)] = attr.as_slice() {
+            if name.to_string() != "analog_debug" {
+                continue;
+            }
+            let args = args.stream().into_iter().collect::<Vec<_>>();
+            return match args.as_slice() {
+                [TokenTree::Ident(kw), eq, path @ ..]
+                    if kw.to_string() == "crate" && is_punct(eq, '=') =>
+                {
+                    krate::parse_path(path)
+                }
+                _ => Err(Error::new(
+                    Some(name.span()),
+                    "expected `#[analog_debug(crate = path)]`",
+                )),
+            };
+        }
+    }
+    Ok(krate::default())
+}
+
+enum Fields {
+    Named(Vec<String>),
+    Unnamed(usize),
+    Unit,
+}
+
+struct Item {
+    name: String,
+    /// Generic parameters, as they appear after `impl`
+    impl_generics: Vec<String>,
+    /// Generic parameters, as they appear after the type's name
+    ty_generics: Vec<String>,
+    /// Names of the type parameters, which all need to be `Debug`
+    type_params: Vec<String>,
+    where_clause: String,
+    fields: Fields,
+}
+
+fn is_punct(tt: &TokenTree, ch: char) -> bool {
+    matches!(tt, TokenTree::Punct(p) if p.as_char() == ch)
+}
+
+/// Split a list of tokens on top-level commas, keeping track of `<>` (which,
+/// unlike the other brackets, don't get their own `Group`).
+fn split_commas(tokens: impl IntoIterator<Item = TokenTree>) -> Vec<Vec<TokenTree>> {
+    let mut parts = vec![Vec::new()];
+    let mut depth = 0;
+    let mut prev_dash = false;
+    for tt in tokens {
+        let dash = is_punct(&tt, '-');
+        match &tt {
+            TokenTree::Punct(p) if p.as_char() == '<' => depth += 1,
+            // don't mistake the `>` in `->` for a closing bracket
+            TokenTree::Punct(p) if p.as_char() == '>' && !prev_dash => depth -= 1,
+            TokenTree::Punct(p) if p.as_char() == ',' && depth == 0 => {
+                parts.push(Vec::new());
+                prev_dash = false;
+                continue;
+            }
+            _ => {}
+        }
+        prev_dash = dash;
+        parts.last_mut().unwrap().push(tt);
+    }
+    if parts.last().is_some_and(|part| part.is_empty()) {
+        parts.pop();
+    }
+    parts
+}
+
+fn to_string(tokens: &[TokenTree]) -> String {
+    tokens.iter().cloned().collect::<TokenStream>().to_string()
+}
+
+/// Skip past any attributes and visibility at the start of the tokens.
+fn skip_attrs_and_vis(tokens: &[TokenTree]) -> &[TokenTree] {
+    let mut tokens = tokens;
+    loop {
+        match tokens {
+            [hash, TokenTree::Group(_), rest @ ..] if is_punct(hash, '#') => tokens = rest,
+            [TokenTree::Ident(vis), TokenTree::Group(g), rest @ ..]
+                if vis.to_string() == "pub" && g.delimiter() == Delimiter::Parenthesis =>
+            {
+                tokens = rest
+            }
+            [TokenTree::Ident(vis), rest @ ..] if vis.to_string() == "pub" => tokens = rest,
+            _ => return tokens,
+        }
+    }
+}
+
+fn parse(input: TokenStream) -> Result<Item> {
+    let tokens = input.into_iter().collect::<Vec<_>>();
+    let tokens = skip_attrs_and_vis(&tokens);
+
+    let (name, rest) = match tokens {
+        [TokenTree::Ident(kw), TokenTree::Ident(name), rest @ ..] if kw.to_string() == "struct" => {
+            (name.to_string(), rest)
+        }
+        [TokenTree::Ident(kw), ..] if kw.to_string() == "enum" || kw.to_string() == "union" => {
+            return Err(Error::new(
+                Some(kw.span()),
+                "`AnalogDebug` can only be derived for structs",
+            ))
+        }
+        _ => return Err(Error::new(None, "expected a struct")),
+    };
+
+    // generics
+    let mut rest = rest.iter().cloned().peekable();
+    let mut generics = Vec::new();
+    if rest.peek().is_some_and(|tt| is_punct(tt, '<')) {
+        rest.next();
+        let mut depth = 1;
+        let mut prev_dash = false;
+        for tt in rest.by_ref() {
+            if is_punct(&tt, '<') {
+                depth += 1;
+            } else if is_punct(&tt, '>') && !prev_dash {
+                depth -= 1;
+                if depth == 0 {
+                    break;
+                }
+            }
+            prev_dash = is_punct(&tt, '-');
+            generics.push(tt);
+        }
+    }
+
+    let mut impl_generics = Vec::new();
+    let mut ty_generics = Vec::new();
+    let mut type_params = Vec::new();
+    for param in split_commas(generics) {
+        // defaults aren't allowed in `impl` blocks (but watch out for bounds
+        // like `Iterator<Item = u8>`)
+        let mut depth = 0;
+        let end = param
+            .iter()
+            .position(|tt| match tt {
+                TokenTree::Punct(p) if p.as_char() == '<' => {
+                    depth += 1;
+                    false
+                }
+                TokenTree::Punct(p) if p.as_char() == '>' => {
+                    depth -= 1;
+                    false
+                }
+                TokenTree::Punct(p) => {
+                    p.as_char() == '=' && p.spacing() == Spacing::Alone && depth == 0
+                }
+                _ => false,
+            })
+            .unwrap_or(param.len());
+        impl_generics.push(to_string(&param[..end]));
+        match &param[..] {
+            [tick, TokenTree::Ident(lifetime), ..] if is_punct(tick, '\'') => {
+                ty_generics.push(format!("'{}", lifetime))
+            }
+            [TokenTree::Ident(kw), TokenTree::Ident(name), ..] if kw.to_string() == "const" => {
+                ty_generics.push(name.to_string())
+            }
+            [TokenTree::Ident(name), ..] => {
+                ty_generics.push(name.to_string());
+                type_params.push(name.to_string());
+            }
+            _ => return Err(Error::new(None, "couldn't make sense of these generics")),
+        }
+    }
+
+    // everything else: the fields, and the where clause (which comes before
+    // the fields in a regular struct, and after them in a tuple struct)
+    let mut where_clause = Vec::new();
+    let mut fields = Fields::Unit;
+    for tt in rest {
+        match tt {
+            TokenTree::Group(g) if g.delimiter() == Delimiter::Brace => {
+                let fields_tokens = g.stream().into_iter().collect::<Vec<_>>();
+                let mut names = Vec::new();
+                for field in split_commas(fields_tokens) {
+                    match skip_attrs_and_vis(&field) {
+                        [TokenTree::Ident(name), ..] => names.push(name.to_string()),
+                        _ => return Err(Error::new(Some(g.span()), "expected a field name")),
+                    }
+                }
+                fields = Fields::Named(names);
+            }
+            TokenTree::Group(g) if g.delimiter() == Delimiter::Parenthesis => {
+                fields = Fields::Unnamed(split_commas(g.stream()).len());
+            }
+            TokenTree::Ident(kw) if kw.to_string() == "where" => {}
+            tt if is_punct(&tt, ';') => {}
+            tt => where_clause.push(tt),
+        }
+    }
+
+    Ok(Item {
+        name,
+        impl_generics,
+        ty_generics,
+        type_params,
+        where_clause: to_string(&where_clause),
+        fields,
+    })
+}
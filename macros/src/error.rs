@@ -0,0 +1,48 @@
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+/// An error pointing at some part of a malformed drawing.
+#[derive(Debug)]
+pub struct Error {
+    span: Option<Span>,
+    msg: String,
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub fn new(span: Option<Span>, msg: impl Into<String>) -> Error {
+        Error {
+            span,
+            msg: msg.into(),
+        }
+    }
+
+    #[cfg(test)]
+    pub fn msg(&self) -> &str {
+        &self.msg
+    }
+
+    /// Turn the error into a `compile_error! { ... }` invocation.
+    ///
+    /// Unlike `compile_error!(...)`, the braced form needs no trailing `;`,
+    /// so it works just as well in item position as it does in expression
+    /// position.
+    pub fn into_compile_error(self) -> TokenStream {
+        let span = self.span.unwrap_or_else(Span::call_site);
+
+        let mut msg = Literal::string(&self.msg);
+        msg.set_span(span);
+        let mut args = Group::new(Delimiter::Brace, TokenTree::from(msg).into());
+        args.set_span(span);
+        let mut bang = Punct::new('!', Spacing::Alone);
+        bang.set_span(span);
+
+        vec![
+            TokenTree::from(Ident::new("compile_error", span)),
+            TokenTree::from(bang),
+            TokenTree::from(args),
+        ]
+        .into_iter()
+        .collect()
+    }
+}
@@ -0,0 +1,140 @@
+//! `analog_literal_from_file!`
+
+use std::path::{Path, PathBuf};
+
+use proc_macro::{Delimiter, Group, TokenStream, TokenTree};
+
+use crate::error::{Error, Result};
+
+pub fn expand(input: TokenStream) -> Result<TokenStream> {
+    let mut tokens = input.into_iter();
+    let (path, span) = match (tokens.next(), tokens.next()) {
+        (Some(TokenTree::Literal(lit)), None) => match unquote(&lit.to_string()) {
+            Some(path) => (path, lit.span()),
+            None => return Err(Error::new(Some(lit.span()), "expected a string literal")),
+        },
+        _ => {
+            return Err(Error::new(
+                None,
+                "expected the path to a drawing (e.g: `\"diagrams/rig.txt\"`)",
+            ))
+        }
+    };
+
+    // just like `include_str!`, the path is relative to the file the macro
+    // was called from (falling back to the root of the crate, for the rare
+    // literal that didn't come from a file at all)
+    let dir = span
+        .local_file()
+        .and_then(|file| file.parent().map(Path::to_owned))
+        .or_else(|| std::env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from))
+        .unwrap_or_default();
+    // rustc hands out paths relative to wherever it's running, which is not
+    // where the generated `include_str!` is going to look
+    let dir = std::env::current_dir().unwrap_or_default().join(dir);
+    let full = dir.join(&path);
+    let drawing = std::fs::read_to_string(&full).map_err(|e| {
+        Error::new(
+            Some(span),
+            format!("couldn't read `{}`: {}", full.display(), e),
+        )
+    })?;
+    let drawing = drawing.parse::<TokenStream>().map_err(|_| {
+        Error::new(
+            Some(span),
+            format!(
+                "`{}` isn't made of valid Rust tokens (e.g: it might contain a `\\`)",
+                path
+            ),
+        )
+    })?;
+
+    // the `include_str!` is just there to make sure the crate gets rebuilt
+    // whenever the drawing changes
+    let mut code = format!(
+        "const _: &str = ::core::include_str!({:?}); $crate::analog_literal!",
+        full.display().to_string()
+    )
+    .parse::<TokenStream>()
+    .unwrap();
+    code.extend(Some(TokenTree::Group(Group::new(
+        Delimiter::Brace,
+        drawing,
+    ))));
+    Ok(TokenTree::Group(Group::new(Delimiter::Brace, code)).into())
+}
+
+/// Strip the quotes off a (possibly raw) string literal, resolving any escapes
+/// along the way.
+fn unquote(lit: &str) -> Option<String> {
+    if let Some(raw) = lit.strip_prefix('r') {
+        let hashes = raw.len() - raw.trim_start_matches('#').len();
+        let raw = &raw[hashes..raw.len().checked_sub(hashes)?];
+        return Some(raw.strip_prefix('"')?.strip_suffix('"')?.to_owned());
+    }
+
+    let lit = lit.strip_prefix('"')?.strip_suffix('"')?;
+    let mut s = String::new();
+    let mut chars = lit.chars();
+    while let Some(ch) = chars.next() {
+        match ch {
+            '\\' => match chars.next()? {
+                ch @ ('\\' | '"' | '\'') => s.push(ch),
+                'n' => s.push('\n'),
+                'r' => s.push('\r'),
+                't' => s.push('\t'),
+                '0' => s.push('\0'),
+                'x' => {
+                    let hex = chars.as_str().get(..2)?;
+                    let byte = u8::from_str_radix(hex, 16).ok().filter(u8::is_ascii)?;
+                    s.push(byte as char);
+                    chars = chars.as_str()[2..].chars();
+                }
+                'u' => {
+                    let rest = chars.as_str().strip_prefix('{')?;
+                    let (hex, rest) = rest.split_once('}')?;
+                    let code = u32::from_str_radix(&hex.replace('_', ""), 16).ok()?;
+                    s.push(char::from_u32(code)?);
+                    chars = rest.chars();
+                }
+                // a `\` at the end of a line skips over the newline, along
+                // with any indentation on the next one
+                '\n' => {
+                    chars = chars
+                        .as_str()
+                        .trim_start_matches([' ', '\t', '\n', '\r'])
+                        .chars()
+                }
+                _ => return None,
+            },
+            ch => s.push(ch),
+        }
+    }
+    Some(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unquote_paths() {
+        assert_eq!(unquote(r#""rig.txt""#).unwrap(), "rig.txt");
+        assert_eq!(unquote(r#""C:\\rig.txt""#).unwrap(), r"C:\rig.txt");
+        assert_eq!(unquote(r##"r#"rig "1".txt"#"##).unwrap(), r#"rig "1".txt"#);
+        assert_eq!(unquote(r#"r"C:\rig.txt""#).unwrap(), r"C:\rig.txt");
+        assert_eq!(unquote(r#""a\tb\n.txt""#).unwrap(), "a\tb\n.txt");
+        assert_eq!(
+            unquote(r#""\x41\u{1F980}\u{e_9}.txt""#).unwrap(),
+            "A🦀é.txt"
+        );
+        assert_eq!(
+            unquote("\"dia\\\n    grams/rig.txt\"").unwrap(),
+            "diagrams/rig.txt"
+        );
+        assert!(unquote(r#""\xff.txt""#).is_none());
+        assert!(unquote(r#""\q.txt""#).is_none());
+        assert!(unquote("b\"rig.txt\"").is_none());
+        assert!(unquote("42").is_none());
+    }
+}
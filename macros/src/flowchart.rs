@@ -0,0 +1,151 @@
+//! `analog_flowchart!`
+
+use proc_macro::TokenStream;
+
+use crate::arrows;
+use crate::boxes::{self, Outline};
+use crate::canvas::Canvas;
+use crate::error::{Error, Result};
+
+pub fn expand(input: TokenStream) -> Result<TokenStream> {
+    let canvas = Canvas::from_tokens(input);
+    let (nodes, edges) = build(&canvas)?;
+
+    let mut code = String::from("$crate::flowchart::Flowchart { nodes: [");
+    for Node { kind, label } in nodes {
+        code.push_str(&format!(
+            "$crate::flowchart::Node {{ \
+                kind: $crate::flowchart::NodeKind::{:?}, \
+                label: {:?}, \
+            }},",
+            kind, label
+        ));
+    }
+    code.push_str("], edges: [");
+    for edge in edges {
+        let label = match edge.label {
+            Some(label) => format!("::core::option::Option::Some({:?})", label),
+            None => "::core::option::Option::None".to_owned(),
+        };
+        code.push_str(&format!(
+            "$crate::flowchart::Edge {{ from: {}, to: {}, label: {} }},",
+            edge.from, edge.to, label
+        ));
+    }
+    code.push_str("] }");
+    Ok(code.parse().unwrap())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeKind {
+    Process,
+    Decision,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Node {
+    kind: NodeKind,
+    label: String,
+}
+
+/// Return the whitespace-normalized text written inside a box.
+pub fn label(canvas: &Canvas, outline: &Outline) -> String {
+    let mut words = Vec::new();
+    for row in outline.top + 1..outline.bottom {
+        let text = canvas.text(row, outline.left + 1..outline.right);
+        words.extend(text.split_whitespace().map(str::to_owned));
+    }
+    words.join(" ")
+}
+
+fn build(canvas: &Canvas) -> Result<(Vec<Node>, Vec<arrows::Arrow>)> {
+    let mut found = boxes::find(canvas)
+        .into_iter()
+        .map(|outline| (outline, NodeKind::Process))
+        .chain(
+            boxes::find_walled(canvas, '<', '>')
+                .into_iter()
+                .map(|outline| (outline, NodeKind::Decision)),
+        )
+        .collect::<Vec<_>>();
+    found.sort_by_key(|(outline, _)| (outline.top, outline.left));
+
+    for (i, (a, _)) in found.iter().enumerate() {
+        for (b, _) in &found[i + 1..] {
+            if a.overlaps(b) {
+                return Err(Error::new(
+                    canvas.span(b.top, b.left),
+                    "flowchart boxes must be drawn apart from one another",
+                ));
+            }
+        }
+    }
+
+    let outlines = found
+        .iter()
+        .map(|(outline, _)| *outline)
+        .collect::<Vec<_>>();
+    let edges = arrows::trace(canvas, &outlines)?;
+    let nodes = found
+        .iter()
+        .map(|(outline, kind)| Node {
+            kind: *kind,
+            label: label(canvas, outline),
+        })
+        .collect();
+    Ok((nodes, edges))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn flowchart() {
+        let canvas = Canvas::from_str(
+            "
++-----------+
+|   start   |
++-----------+
+      |
+      v
++-----------+         +---------+
+< is it on? >---no--->| flip it |
++-----------+         +---------+
+      |                    |
+     yes                   |
+      v                    |
++-----------+              |
+|   done    |<-------------+
++-----------+
+",
+        );
+        let (nodes, edges) = build(&canvas).unwrap();
+        let nodes = nodes
+            .iter()
+            .map(|n| (n.kind, n.label.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            nodes,
+            [
+                (NodeKind::Process, "start"),
+                (NodeKind::Decision, "is it on?"),
+                (NodeKind::Process, "flip it"),
+                (NodeKind::Process, "done"),
+            ]
+        );
+        let edges = edges
+            .into_iter()
+            .map(|e| (e.from, e.to, e.label))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            edges,
+            [
+                (0, 1, None),
+                (1, 2, Some("no".to_owned())),
+                (1, 3, Some("yes".to_owned())),
+                (2, 3, None),
+            ]
+        );
+    }
+}
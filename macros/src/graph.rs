@@ -0,0 +1,92 @@
+//! `analog_graph!`
+
+use proc_macro::TokenStream;
+
+use crate::arrows;
+use crate::boxes;
+use crate::canvas::Canvas;
+use crate::error::{Error, Result};
+use crate::flowchart;
+
+pub fn expand(input: TokenStream) -> Result<TokenStream> {
+    let canvas = Canvas::from_tokens(input);
+    let (nodes, edges) = build(&canvas)?;
+
+    let mut code = String::from("$crate::graph::Graph { nodes: [");
+    for node in nodes {
+        code.push_str(&format!("{:?},", node));
+    }
+    code.push_str("], edges: [");
+    for (from, to) in edges {
+        code.push_str(&format!("({}, {}),", from, to));
+    }
+    code.push_str("] }");
+    Ok(code.parse().unwrap())
+}
+
+type Edge = (usize, usize);
+
+fn build(canvas: &Canvas) -> Result<(Vec<String>, Vec<Edge>)> {
+    let found = boxes::find(canvas);
+    if found.is_empty() {
+        return Err(Error::new(None, "expected at least one box"));
+    }
+
+    for (i, a) in found.iter().enumerate() {
+        for b in &found[i + 1..] {
+            if a.overlaps(b) {
+                return Err(Error::new(
+                    canvas.span(b.top, b.left),
+                    "boxes must be drawn apart from one another",
+                ));
+            }
+        }
+    }
+
+    let edges = arrows::trace(canvas, &found)?
+        .into_iter()
+        .map(|arrow| (arrow.from, arrow.to))
+        .collect();
+    let nodes = found
+        .iter()
+        .map(|outline| flowchart::label(canvas, outline))
+        .collect();
+    Ok((nodes, edges))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn graph() {
+        let canvas = Canvas::from_str(
+            "
++-----+     +------+     +-------+
+| cli |---->| core |<----| tests |
++-----+     +------+     +-------+
+   |           ^
+   |           |
+   v           |
++--------+     |
+| render |-----+
++--------+
+",
+        );
+        let (nodes, edges) = build(&canvas).unwrap();
+        assert_eq!(nodes, ["cli", "core", "tests", "render"]);
+        assert_eq!(edges, [(0, 1), (2, 1), (3, 1), (0, 3)]);
+    }
+
+    #[test]
+    fn dangling() {
+        let canvas = Canvas::from_str(
+            "
++-----+     +------+
+| cli |---->| core |---->
++-----+     +------+
+",
+        );
+        assert!(build(&canvas).is_err());
+    }
+}
@@ -0,0 +1,141 @@
+//! `analog_intervals!`
+
+use proc_macro::TokenStream;
+
+use crate::canvas::Canvas;
+use crate::error::{Error, Result};
+
+pub fn expand(input: TokenStream) -> Result<TokenStream> {
+    let canvas = Canvas::from_tokens(input);
+    let intervals = parse(&canvas)?;
+
+    let [a, b] = match intervals.as_slice() {
+        [a, b] => [a, b],
+        _ => {
+            return Err(Error::new(
+                None,
+                "expected a pair of intervals, one per row (e.g: `[----]`)",
+            ))
+        }
+    };
+    Ok(format!("({}, {})", a.to_code(), b.to_code())
+        .parse()
+        .unwrap())
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Interval {
+    start: usize,
+    end: usize,
+}
+
+impl Interval {
+    fn to_code(&self) -> String {
+        format!(
+            "$crate::interval::Interval {{ start: {}, end: {} }}",
+            self.start, self.end
+        )
+    }
+}
+
+/// Parse every interval on the canvas, measuring them all from the leftmost
+/// `[` (which is where the ruler starts).
+fn parse(canvas: &Canvas) -> Result<Vec<Interval>> {
+    let mut drawn = Vec::new();
+    for row in 0..canvas.height() {
+        let open = match (0..canvas.width(row)).find(|&col| canvas.get(row, col) != ' ') {
+            Some(col) => col,
+            None => continue,
+        };
+
+        let expected = |col| {
+            Error::new(
+                canvas.span(row, col),
+                "expected an interval, drawn as `[`, some `--`, and a `]`",
+            )
+        };
+        if canvas.get(row, open) != '[' {
+            return Err(expected(open));
+        }
+        let dashes = (open + 1..canvas.width(row))
+            .take_while(|&col| canvas.get(row, col) == '-')
+            .count();
+        let close = open + 1 + dashes;
+        if canvas.get(row, close) != ']' {
+            return Err(expected(close));
+        }
+        if canvas.text(row, close + 1..canvas.width(row)).trim() != "" {
+            return Err(Error::new(
+                canvas.span(row, close + 1),
+                "expected only one interval per row",
+            ));
+        }
+        if dashes % 2 != 0 {
+            return Err(Error::new(
+                canvas.span(row, close),
+                "intervals are measured in `--`, so this one's got a `-` too many (or too few)",
+            ));
+        }
+        drawn.push((row, open, dashes / 2));
+    }
+
+    let ruler = drawn.iter().map(|&(_, open, _)| open).min().unwrap_or(0);
+    drawn
+        .into_iter()
+        .map(|(row, open, len)| {
+            if (open - ruler) % 2 != 0 {
+                return Err(Error::new(
+                    canvas.span(row, open),
+                    "intervals are measured in `--`, so this one's off by a `-`",
+                ));
+            }
+            let start = (open - ruler) / 2;
+            Ok(Interval {
+                start,
+                end: start + len,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shared_ruler() {
+        let canvas = Canvas::from_str(
+            "
+    [--------]
+        [--------]
+                []",
+        );
+        assert_eq!(
+            parse(&canvas).unwrap(),
+            vec![
+                Interval { start: 0, end: 4 },
+                Interval { start: 2, end: 6 },
+                Interval { start: 6, end: 6 },
+            ]
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        let err = |s: &str| parse(&Canvas::from_str(s)).unwrap_err().msg().to_owned();
+
+        assert_eq!(
+            err("[---]"),
+            "intervals are measured in `--`, so this one's got a `-` too many (or too few)"
+        );
+        assert_eq!(
+            err("[----"),
+            "expected an interval, drawn as `[`, some `--`, and a `]`"
+        );
+        assert_eq!(err("[--] [--]"), "expected only one interval per row");
+        assert_eq!(
+            err("[--]\n   [--]"),
+            "intervals are measured in `--`, so this one's off by a `-`"
+        );
+    }
+}
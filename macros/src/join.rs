@@ -0,0 +1,62 @@
+//! `analog_literal! { ... ;; ... }`
+//!
+//! `analog_literal!` parses the first literal in the invocation as usual, and
+//! hands it off here (already parsed) along with everything after the first
+//! `;;`. The rest gets chopped up into one `analog_literal!` per literal, and
+//! the whole lot is bundled up into a tuple.
+
+use proc_macro::{Delimiter, Group, Punct, Spacing, Span, TokenStream, TokenTree};
+
+use crate::error::{Error, Result};
+
+pub fn expand(input: TokenStream) -> Result<TokenStream> {
+    let mut tokens = input.into_iter();
+    let (first, scalar) = match (tokens.next(), tokens.next()) {
+        (Some(TokenTree::Group(first)), Some(TokenTree::Group(scalar))) => (first, scalar),
+        _ => return Err(Error::new(None, "malformed analog_literal_join! input")),
+    };
+
+    let mut elems = vec![TokenTree::Group(first)];
+    for (sep, part) in split(tokens) {
+        if part.is_empty() {
+            return Err(Error::new(
+                sep,
+                "expected another analog literal after the `;;`",
+            ));
+        }
+
+        let mut body = scalar.stream();
+        if !body.is_empty() {
+            body.extend(Some(TokenTree::Punct(Punct::new(';', Spacing::Alone))));
+        }
+        body.extend(part);
+
+        elems.push(TokenTree::Punct(Punct::new(',', Spacing::Alone)));
+        elems.extend("$crate::analog_literal!".parse::<TokenStream>().unwrap());
+        elems.push(TokenTree::Group(Group::new(Delimiter::Brace, body)));
+    }
+
+    let tuple = Group::new(Delimiter::Parenthesis, elems.into_iter().collect());
+    Ok(TokenTree::Group(tuple).into())
+}
+
+/// Chop a stream of tokens up at every `;;`, returning each chunk alongside
+/// the span of the `;;` that came before it (if there was one).
+pub fn split(tokens: impl IntoIterator<Item = TokenTree>) -> Vec<(Option<Span>, Vec<TokenTree>)> {
+    let tokens = tokens.into_iter().collect::<Vec<_>>();
+    let is_semi =
+        |i: usize| matches!(tokens.get(i), Some(TokenTree::Punct(p)) if p.as_char() == ';');
+
+    let mut parts = vec![(None, Vec::new())];
+    let mut i = 0;
+    while i < tokens.len() {
+        if is_semi(i) && is_semi(i + 1) {
+            parts.push((Some(tokens[i].span()), Vec::new()));
+            i += 2;
+            continue;
+        }
+        parts.last_mut().unwrap().1.push(tokens[i].clone());
+        i += 1;
+    }
+    parts
+}
@@ -0,0 +1,103 @@
+//! `analog_keyboard!`
+
+use proc_macro::TokenStream;
+
+use crate::boxes;
+use crate::canvas::Canvas;
+use crate::error::{Error, Result};
+use crate::flowchart;
+
+pub fn expand(input: TokenStream) -> Result<TokenStream> {
+    let canvas = Canvas::from_tokens(input);
+
+    let mut code = String::from("[");
+    for key in build(&canvas)? {
+        code.push_str(&format!(
+            "$crate::keyboard::Key {{ row: {}, col: {}, label: {:?}, width: {} }},",
+            key.row, key.col, key.label, key.width
+        ));
+    }
+    code.push(']');
+    Ok(code.parse().unwrap())
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Key {
+    row: usize,
+    col: usize,
+    label: String,
+    width: usize,
+}
+
+fn build(canvas: &Canvas) -> Result<Vec<Key>> {
+    let found = boxes::find(canvas);
+    if found.is_empty() {
+        return Err(Error::new(None, "expected at least one keycap"));
+    }
+
+    for a in &found {
+        for b in &found {
+            if a.encloses(b) {
+                return Err(Error::new(
+                    canvas.span(b.top, b.left),
+                    "keycaps can't be drawn inside other keycaps",
+                ));
+            }
+        }
+    }
+
+    // keys belong to whichever row their top edge is on
+    let mut tops = found.iter().map(|outline| outline.top).collect::<Vec<_>>();
+    tops.dedup();
+
+    let mut keys = Vec::<Key>::new();
+    for outline in &found {
+        let row = tops.iter().position(|top| *top == outline.top).unwrap();
+        let col = keys.iter().filter(|key| key.row == row).count();
+        keys.push(Key {
+            row,
+            col,
+            label: flowchart::label(canvas, outline),
+            width: outline.w(canvas)?,
+        });
+    }
+    Ok(keys)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn staggered() {
+        let canvas = Canvas::from_str(
+            "
++----+----+----+
+| Q  | W  | E  |
++-+--+-+--+-+--+-+
+  | A  | S  | D  |
++-+----+-+--+-+--+
+| Shift  | Z  |
++--------+----+
+",
+        );
+        let keys = build(&canvas).unwrap();
+        let keys = keys
+            .iter()
+            .map(|k| (k.row, k.col, k.label.as_str(), k.width))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            keys,
+            [
+                (0, 0, "Q", 2),
+                (0, 1, "W", 2),
+                (0, 2, "E", 2),
+                (1, 0, "A", 2),
+                (1, 1, "S", 2),
+                (1, 2, "D", 2),
+                (2, 0, "Shift", 4),
+                (2, 1, "Z", 2),
+            ]
+        );
+    }
+}
@@ -0,0 +1,123 @@
+//! Figuring out what `analog_literals` is called in the crate using it.
+//!
+//! Nobody has to call the crate `analog_literals` (e.g: `al = { package =
+//! "analog_literals", ... }`), so generated code can't just hardcode
+//! `::analog_literals::` paths. Instead, almost every proc macro is called
+//! through a `macro_rules!` wrapper that passes its `$crate` along as the very
+//! first token, which is then spliced into the generated code wherever it says
+//! `$crate`.
+//!
+//! The exceptions are macros which can't be called through a wrapper, like
+//! `#[derive(AnalogDebug)]`, and macros which re-read the source code of their
+//! own invocation, like `analog_labeled!` (a wrapper would leave them reading
+//! the wrapper instead). Those default to `::analog_literals`, and take the path
+//! to use instead as a `crate = path;` prefix (or attribute).
+
+use proc_macro::{Group, TokenStream, TokenTree};
+
+use crate::error::{Error, Result};
+
+/// Expand a macro called through a `macro_rules!` wrapper, which has put its
+/// `$crate` right in front of the actual input.
+pub fn expand_wrapped(
+    input: TokenStream,
+    expand: impl FnOnce(TokenStream) -> Result<TokenStream>,
+) -> TokenStream {
+    let mut tokens = input.into_iter();
+    let krate = match tokens.next() {
+        Some(krate @ TokenTree::Ident(_)) => TokenStream::from(krate),
+        _ => {
+            return Error::new(None, "expected a `$crate` in front of the input")
+                .into_compile_error()
+        }
+    };
+    match expand(tokens.collect()) {
+        Ok(code) => splice(code, &krate),
+        Err(e) => e.into_compile_error(),
+    }
+}
+
+/// Expand a macro called directly by the user, which may start with a
+/// `crate = path;` prefix.
+pub fn expand_direct(
+    input: TokenStream,
+    expand: impl FnOnce(TokenStream) -> Result<TokenStream>,
+) -> TokenStream {
+    let (krate, input) = match split_prefix(input) {
+        Ok(split) => split,
+        Err(e) => return e.into_compile_error(),
+    };
+    match expand(input) {
+        Ok(code) => splice(code, &krate.unwrap_or_else(default)),
+        Err(e) => e.into_compile_error(),
+    }
+}
+
+/// The path to use when nobody said otherwise.
+pub fn default() -> TokenStream {
+    "::analog_literals".parse().unwrap()
+}
+
+/// Split a `crate = path;` prefix off the front of the input, if there is one.
+fn split_prefix(input: TokenStream) -> Result<(Option<TokenStream>, TokenStream)> {
+    let tokens = input.into_iter().collect::<Vec<_>>();
+    match tokens.as_slice() {
+        [TokenTree::Ident(kw), TokenTree::Punct(eq), ..]
+            if kw.to_string() == "crate" && eq.as_char() == '=' =>
+        {
+            let semi = tokens
+                .iter()
+                .position(|tt| matches!(tt, TokenTree::Punct(p) if p.as_char() == ';'))
+                .ok_or_else(|| {
+                    Error::new(
+                        Some(kw.span()),
+                        "expected a `;` after the path to `analog_literals`",
+                    )
+                })?;
+            let path = parse_path(&tokens[2..semi])?;
+            Ok((Some(path), tokens[semi + 1..].iter().cloned().collect()))
+        }
+        _ => Ok((None, tokens.into_iter().collect())),
+    }
+}
+
+/// Check that the tokens look like a path (e.g: `al` or `::some::al`).
+pub fn parse_path(tokens: &[TokenTree]) -> Result<TokenStream> {
+    let valid = !tokens.is_empty()
+        && tokens.iter().all(|tt| match tt {
+            TokenTree::Ident(_) => true,
+            TokenTree::Punct(p) => p.as_char() == ':',
+            _ => false,
+        });
+    if !valid {
+        return Err(Error::new(
+            tokens.first().map(TokenTree::span),
+            "expected a path to `analog_literals` (e.g: `crate = al;`)",
+        ));
+    }
+    Ok(tokens.iter().cloned().collect())
+}
+
+/// Replace every `$crate` in the generated code with `krate`.
+pub fn splice(code: TokenStream, krate: &TokenStream) -> TokenStream {
+    let mut out = Vec::new();
+    let mut tokens = code.into_iter().peekable();
+    while let Some(tt) = tokens.next() {
+        match tt {
+            TokenTree::Punct(p)
+                if p.as_char() == '$'
+                    && matches!(tokens.peek(), Some(TokenTree::Ident(i)) if i.to_string() == "crate") =>
+            {
+                tokens.next();
+                out.extend(krate.clone());
+            }
+            TokenTree::Group(g) => {
+                let mut spliced = Group::new(g.delimiter(), splice(g.stream(), krate));
+                spliced.set_span(g.span());
+                out.push(TokenTree::Group(spliced));
+            }
+            tt => out.push(tt),
+        }
+    }
+    out.into_iter().collect()
+}
@@ -0,0 +1,146 @@
+//! `analog_labeled!`
+
+use proc_macro::{Span, TokenStream};
+
+use crate::boxes::{self, Outline};
+use crate::canvas::Canvas;
+use crate::error::{Error, Result};
+
+pub fn expand(input: TokenStream) -> Result<TokenStream> {
+    let canvas = Canvas::from_tokens(input);
+    let outline = match boxes::find(&canvas).as_slice() {
+        [outline] => *outline,
+        [] => return Err(Error::new(None, "expected a rectangle")),
+        _ => return Err(Error::new(None, "expected a single rectangle")),
+    };
+
+    // comments never make it into the token stream, so the only way to get at
+    // them is to go back to the source code itself
+    let call_site = Span::call_site();
+    let source = call_site.source_text().ok_or_else(|| {
+        Error::new(
+            None,
+            "labels can only be captured from literals written directly in the source code",
+        )
+    })?;
+    let source = Source {
+        text: &source,
+        line: call_site.start().line(),
+        col: call_site.start().column() - 1,
+    };
+
+    let label = find_label(&canvas, &source, &outline)?;
+    Ok(format!(
+        "$crate::LabeledRectangle {{ \
+            rect: $crate::Rectangle {{ w: {}, h: {} }}, \
+            label: {:?}, \
+        }}",
+        outline.w(&canvas)?,
+        outline.h(),
+        label
+    )
+    .parse()
+    .unwrap())
+}
+
+/// A snippet of source code, starting at the given (0-indexed) column of the
+/// given line.
+struct Source<'a> {
+    text: &'a str,
+    line: usize,
+    col: usize,
+}
+
+impl Source<'_> {
+    /// Return the characters on the given source line between the given
+    /// columns (exclusive).
+    fn slice(&self, line: usize, start: usize, end: usize) -> String {
+        let (text, offset) = match line.checked_sub(self.line) {
+            Some(0) => (self.text.lines().next(), self.col),
+            Some(n) => (self.text.lines().nth(n), 0),
+            None => (None, 0),
+        };
+        text.unwrap_or("")
+            .chars()
+            .skip((start + 1).saturating_sub(offset))
+            .take(end.saturating_sub(start + 1))
+            .collect()
+    }
+}
+
+fn find_label(canvas: &Canvas, source: &Source<'_>, outline: &Outline) -> Result<String> {
+    let mut labels = Vec::new();
+    for row in outline.top + 1..outline.bottom {
+        let mut interior = source.slice(canvas.line() + row, outline.left, outline.right);
+        while let Some(start) = interior.find("/*") {
+            let rest = &interior[start + 2..];
+            let end = match rest.find("*/") {
+                Some(end) => end,
+                None => break,
+            };
+            labels.push(rest[..end].trim().to_owned());
+            interior = rest[end + 2..].to_owned();
+        }
+    }
+
+    match labels.len() {
+        1 => Ok(labels.pop().unwrap()),
+        0 => Err(Error::new(
+            canvas.span(outline.top, outline.left),
+            "expected a `/* label */` comment inside the rectangle",
+        )),
+        _ => Err(Error::new(
+            canvas.span(outline.top, outline.left),
+            "expected exactly one `/* label */` comment inside the rectangle",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn label(drawing: &str) -> Result<String> {
+        let canvas = Canvas::from_str(drawing);
+        let outline = boxes::find(&canvas)[0];
+        let source = Source {
+            text: drawing,
+            line: 1,
+            col: 0,
+        };
+        find_label(&canvas, &source, &outline)
+    }
+
+    #[test]
+    fn single() {
+        let drawing = "
++--------------+
+|              |
+| /* Submit */ |
+|              |
++--------------+
+";
+        assert_eq!(label(drawing).unwrap(), "Submit");
+    }
+
+    #[test]
+    fn none() {
+        let drawing = "
++--------------+
+|              |
++--------------+
+";
+        assert!(label(drawing).is_err());
+    }
+
+    #[test]
+    fn many() {
+        let drawing = "
++--------------+
+| /* one */    |
+|    /* two */ |
++--------------+
+";
+        assert!(label(drawing).is_err());
+    }
+}
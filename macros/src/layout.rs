@@ -0,0 +1,172 @@
+//! `analog_layout!`
+
+use proc_macro::TokenStream;
+
+use crate::boxes::{self, Outline};
+use crate::canvas::Canvas;
+use crate::error::{Error, Result};
+
+pub fn expand(input: TokenStream) -> Result<TokenStream> {
+    let canvas = Canvas::from_tokens(input);
+    let tree = build(&canvas)?;
+
+    let mut code = String::new();
+    emit(&canvas, &tree, None, &mut code)?;
+    Ok(code.parse().unwrap())
+}
+
+/// A box, along with all the boxes drawn directly inside of it.
+#[derive(Debug)]
+struct Node {
+    outline: Outline,
+    children: Vec<Node>,
+}
+
+fn build(canvas: &Canvas) -> Result<Node> {
+    let found = boxes::find(canvas);
+
+    for (i, a) in found.iter().enumerate() {
+        for b in &found[i + 1..] {
+            if a.overlaps(b) && !a.encloses(b) && !b.encloses(a) {
+                return Err(Error::new(
+                    canvas.span(b.top, b.left),
+                    "rectangles in a layout must either be nested or drawn apart",
+                ));
+            }
+        }
+    }
+
+    let mut roots = found
+        .iter()
+        .filter(|b| !found.iter().any(|other| other.encloses(b)));
+    let root = match (roots.next(), roots.next()) {
+        (Some(root), None) => *root,
+        (None, _) => return Err(Error::new(None, "expected a rectangle")),
+        (Some(_), Some(extra)) => {
+            return Err(Error::new(
+                canvas.span(extra.top, extra.left),
+                "expected all rectangles to be drawn inside a single outermost rectangle",
+            ))
+        }
+    };
+
+    Ok(adopt(root, &found))
+}
+
+fn adopt(outline: Outline, found: &[Outline]) -> Node {
+    // direct children are enclosed by `outline`, but not by any other box
+    // that's also enclosed by `outline`
+    let inside = found
+        .iter()
+        .filter(|b| outline.encloses(b))
+        .collect::<Vec<_>>();
+    let children = inside
+        .iter()
+        .filter(|b| !inside.iter().any(|other| other.encloses(b)))
+        .map(|b| adopt(**b, found))
+        .collect();
+
+    Node { outline, children }
+}
+
+fn emit(canvas: &Canvas, node: &Node, parent: Option<&Outline>, code: &mut String) -> Result<()> {
+    let outline = &node.outline;
+    let (x, y) = match parent {
+        None => (0, 0),
+        Some(parent) => {
+            let cols = outline.left - parent.left - 1;
+            if !cols.is_multiple_of(2) {
+                return Err(Error::new(
+                    canvas.span(outline.top, outline.left),
+                    "positions are measured in `--`, so this shape is off by a `-`",
+                ));
+            }
+            (cols / 2, outline.top - parent.top - 1)
+        }
+    };
+
+    code.push_str(&format!(
+        "$crate::Layout {{ \
+            x: {}, \
+            y: {}, \
+            rect: $crate::Rectangle {{ w: {}, h: {} }}, \
+            children: &[",
+        x,
+        y,
+        outline.w(canvas)?,
+        outline.h(),
+    ));
+    for child in &node.children {
+        emit(canvas, child, Some(outline), code)?;
+        code.push(',');
+    }
+    code.push_str("] }");
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tree() {
+        let canvas = Canvas::from_str(
+            "
++----------------------+
+|                      |
+|   +------------+     |
+|   | +--+ +--+  |     |
+|   | +--+ +--+  |     |
+|   +------------+ +-+ |
+|                  +-+ |
++----------------------+
+",
+        );
+        let root = build(&canvas).unwrap();
+        assert_eq!(root.children.len(), 2);
+        assert_eq!(root.children[0].children.len(), 2);
+        assert_eq!(root.children[1].children.len(), 0);
+    }
+
+    #[test]
+    fn misaligned() {
+        let canvas = Canvas::from_str(
+            "
++----------+
+|   +--+   |
+|   +--+   |
++----------+
+",
+        );
+        let root = build(&canvas).unwrap();
+        let err = emit(&canvas, &root, None, &mut String::new()).unwrap_err();
+        assert!(err.msg().contains("off by a `-`"));
+    }
+
+    #[test]
+    fn siblings() {
+        let canvas = Canvas::from_str(
+            "
++--+ +--+
+|  | |  |
++--+ +--+
+",
+        );
+        let err = build(&canvas).unwrap_err();
+        assert!(err.msg().contains("single outermost"));
+    }
+
+    #[test]
+    fn shared_walls() {
+        let canvas = Canvas::from_str(
+            "
++----+----+
+|    |    |
++----+----+
+",
+        );
+        let err = build(&canvas).unwrap_err();
+        assert!(err.msg().contains("nested or drawn apart"));
+    }
+}
@@ -0,0 +1,128 @@
+//! Procedural macros backing the [`analog_literals`](https://docs.rs/analog_literals)
+//! crate.
+//!
+//! Don't use this crate directly! Everything in here is re-exported (and
+//! documented) by `analog_literals` proper.
+
+extern crate proc_macro;
+
+mod arrows;
+mod boxes;
+mod canvas;
+mod chord;
+mod debug;
+mod error;
+mod file;
+mod flowchart;
+mod graph;
+mod interval;
+mod join;
+mod keyboard;
+mod krate;
+mod labeled;
+mod layout;
+mod piano;
+mod polyomino;
+mod repr;
+mod scene;
+mod stairs;
+mod strict;
+mod table;
+mod venn;
+
+use proc_macro::TokenStream;
+
+#[proc_macro]
+pub fn analog_layout(input: TokenStream) -> TokenStream {
+    krate::expand_wrapped(input, layout::expand)
+}
+
+#[proc_macro]
+pub fn analog_scene(input: TokenStream) -> TokenStream {
+    krate::expand_wrapped(input, scene::expand)
+}
+
+#[proc_macro]
+pub fn analog_positioned(input: TokenStream) -> TokenStream {
+    krate::expand_wrapped(input, scene::expand_one)
+}
+
+#[proc_macro]
+pub fn analog_labeled(input: TokenStream) -> TokenStream {
+    krate::expand_direct(input, labeled::expand)
+}
+
+#[proc_macro]
+pub fn analog_repr(input: TokenStream) -> TokenStream {
+    krate::expand_wrapped(input, repr::expand)
+}
+
+#[proc_macro]
+pub fn analog_flowchart(input: TokenStream) -> TokenStream {
+    krate::expand_wrapped(input, flowchart::expand)
+}
+
+#[proc_macro]
+pub fn analog_graph(input: TokenStream) -> TokenStream {
+    krate::expand_wrapped(input, graph::expand)
+}
+
+#[proc_macro]
+pub fn analog_table(input: TokenStream) -> TokenStream {
+    krate::expand_wrapped(input, table::expand)
+}
+
+#[proc_macro]
+pub fn analog_keyboard(input: TokenStream) -> TokenStream {
+    krate::expand_wrapped(input, keyboard::expand)
+}
+
+#[proc_macro]
+pub fn analog_chord(input: TokenStream) -> TokenStream {
+    krate::expand_wrapped(input, chord::expand)
+}
+
+#[proc_macro]
+pub fn analog_piano(input: TokenStream) -> TokenStream {
+    krate::expand_wrapped(input, piano::expand)
+}
+
+#[proc_macro]
+pub fn analog_polyomino(input: TokenStream) -> TokenStream {
+    krate::expand_wrapped(input, polyomino::expand)
+}
+
+#[proc_macro]
+pub fn analog_venn(input: TokenStream) -> TokenStream {
+    krate::expand_wrapped(input, venn::expand)
+}
+
+#[proc_macro]
+pub fn analog_intervals(input: TokenStream) -> TokenStream {
+    krate::expand_wrapped(input, interval::expand)
+}
+
+#[proc_macro]
+pub fn analog_stairs(input: TokenStream) -> TokenStream {
+    krate::expand_wrapped(input, stairs::expand)
+}
+
+#[proc_macro]
+pub fn analog_literal_strict(input: TokenStream) -> TokenStream {
+    krate::expand_wrapped(input, strict::expand)
+}
+
+#[proc_macro]
+pub fn analog_literal_join(input: TokenStream) -> TokenStream {
+    krate::expand_wrapped(input, join::expand)
+}
+
+#[proc_macro]
+pub fn analog_literal_from_file(input: TokenStream) -> TokenStream {
+    krate::expand_wrapped(input, file::expand)
+}
+
+#[proc_macro_derive(AnalogDebug, attributes(analog_debug))]
+pub fn analog_debug(input: TokenStream) -> TokenStream {
+    debug::expand(input).unwrap_or_else(error::Error::into_compile_error)
+}
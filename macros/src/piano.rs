@@ -0,0 +1,158 @@
+//! `analog_piano!`
+
+use proc_macro::TokenStream;
+
+use crate::canvas::Canvas;
+use crate::error::{Error, Result};
+
+pub fn expand(input: TokenStream) -> Result<TokenStream> {
+    let canvas = Canvas::from_tokens(input);
+    let mut mask = 0u128;
+    for semitone in parse(&canvas)? {
+        mask |= 1 << semitone;
+    }
+    Ok(format!("$crate::music::PianoKeys {{ mask: {:#x} }}", mask)
+        .parse()
+        .unwrap())
+}
+
+/// Semitones of the white keys in an octave, starting from C.
+const WHITE: [usize; 7] = [0, 2, 4, 5, 7, 9, 11];
+
+fn is_marked(ch: char) -> bool {
+    matches!(ch, '*' | 'o' | '@')
+}
+
+/// A black key, identified by the (inclusive) columns of its walls and the
+/// row of its `+-+` bottom.
+struct Black {
+    left: usize,
+    right: usize,
+    bottom: usize,
+}
+
+impl Black {
+    fn contains(&self, row: usize, col: usize) -> bool {
+        row <= self.bottom && (self.left..=self.right).contains(&col)
+    }
+}
+
+/// Return the semitones (counting up from the leftmost C) of every marked key.
+fn parse(canvas: &Canvas) -> Result<Vec<usize>> {
+    let bottom = (0..canvas.height())
+        .rev()
+        .find(|&row| !canvas.text(row, 0..canvas.width(row)).trim().is_empty())
+        .ok_or_else(|| Error::new(None, "expected a piano"))?;
+    let walls = (0..canvas.width(bottom))
+        .filter(|&col| canvas.get(bottom, col) == '+')
+        .collect::<Vec<_>>();
+    if walls.len() < 2 {
+        return Err(Error::new(
+            None,
+            "expected the piano to end with a `+---+---+` row under the white keys",
+        ));
+    }
+
+    // black keys hang down from the top, ending in a `+-+`
+    let mut blacks = Vec::new();
+    for row in 1..bottom {
+        let mut col = 0;
+        while col < canvas.width(row) {
+            if canvas.get(row, col) != '+' || canvas.get(row - 1, col) != '|' {
+                col += 1;
+                continue;
+            }
+            let mut right = col + 1;
+            while canvas.get(row, right) == '-' {
+                right += 1;
+            }
+            if right > col + 1 && canvas.get(row, right) == '+' && canvas.get(row - 1, right) == '|'
+            {
+                blacks.push(Black {
+                    left: col,
+                    right,
+                    bottom: row,
+                });
+            }
+            col = right;
+        }
+    }
+
+    let mut marked = Vec::new();
+    for black in &blacks {
+        // black keys sit on top of the wall between two white keys
+        let white = walls[1..walls.len() - 1]
+            .iter()
+            .position(|&wall| black.left < wall && wall < black.right);
+        let semitone = match white {
+            Some(i) if WHITE[(i + 1) % 7] - WHITE[i % 7] == 2 => 12 * (i / 7) + WHITE[i % 7] + 1,
+            _ => {
+                return Err(Error::new(
+                    canvas.span(black.bottom, black.left),
+                    "black keys must straddle the wall between C-D, D-E, F-G, G-A, or A-B",
+                ))
+            }
+        };
+        let pressed = (0..black.bottom)
+            .any(|row| (black.left + 1..black.right).any(|col| is_marked(canvas.get(row, col))));
+        if pressed {
+            marked.push(semitone);
+        }
+    }
+
+    for (i, pair) in walls.windows(2).enumerate() {
+        let pressed = (0..bottom).any(|row| {
+            (pair[0] + 1..pair[1]).any(|col| {
+                is_marked(canvas.get(row, col)) && !blacks.iter().any(|b| b.contains(row, col))
+            })
+        });
+        if pressed {
+            marked.push(12 * (i / 7) + WHITE[i % 7]);
+        }
+    }
+
+    if let Some(&too_high) = marked.iter().find(|&&semitone| semitone >= 128) {
+        return Err(Error::new(
+            None,
+            format!(
+                "that's {} semitones above the lowest C, which is a bit much for MIDI",
+                too_high
+            ),
+        ));
+    }
+
+    marked.sort_unstable();
+    Ok(marked)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chord() {
+        let canvas = Canvas::from_str(
+            "
+|  | |  | |  |  | |  | |  | |  |
+|  | |  |*|  |  | |  | |  | |  |
+|  +-+  +-+  |  +-+  +-+  +-+  |
+| * |    |   |   | *  |    |   |
++---+----+---+---+----+----+---+
+",
+        );
+        assert_eq!(parse(&canvas).unwrap(), [0, 3, 7]);
+    }
+
+    #[test]
+    fn misplaced_black_key() {
+        let canvas = Canvas::from_str(
+            "
+|  | |  | |  | |  |
+|  +-+  +-+  +-+  |
+|   |    |    |   |
++---+----+----+---+
+",
+        );
+        assert!(parse(&canvas).is_err());
+    }
+}
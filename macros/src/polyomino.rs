@@ -0,0 +1,122 @@
+//! `analog_polyomino!`
+
+use proc_macro::TokenStream;
+
+use crate::canvas::Canvas;
+use crate::error::{Error, Result};
+
+pub fn expand(input: TokenStream) -> Result<TokenStream> {
+    let canvas = Canvas::from_tokens(input);
+    let (mask, w, h) = parse(&canvas)?;
+    Ok(format!(
+        "$crate::polyomino::Polyomino {{ \
+            mask: {:#x}, \
+            bounds: $crate::Rectangle {{ w: {}, h: {} }}, \
+        }}",
+        mask, w, h
+    )
+    .parse()
+    .unwrap())
+}
+
+/// Largest supported polyomino, in either direction.
+const MAX: usize = 8;
+
+/// Return the occupancy mask (with bit `y * 8 + x` being the cell at `(x, y)`)
+/// and bounding width and height of the drawn polyomino.
+fn parse(canvas: &Canvas) -> Result<(u64, usize, usize)> {
+    let mut cells = Vec::new();
+    let mut width = None;
+    for row in 0..canvas.height() {
+        let mut line = Vec::new();
+        for col in 0..canvas.width(row) {
+            match canvas.get(row, col) {
+                ' ' => {}
+                '#' => line.push(true),
+                '.' => line.push(false),
+                _ => {
+                    return Err(Error::new(
+                        canvas.span(row, col),
+                        "polyominoes must be drawn using `#` and `.`",
+                    ))
+                }
+            }
+        }
+        if line.is_empty() {
+            continue;
+        }
+
+        match width {
+            None => width = Some(line.len()),
+            Some(width) if width != line.len() => {
+                return Err(Error::new(
+                    (0..canvas.width(row)).find_map(|col| canvas.span(row, col)),
+                    format!("expected {} cells in this row, found {}", width, line.len()),
+                ))
+            }
+            Some(_) => {}
+        }
+        cells.push(line);
+    }
+
+    // trim the drawing down to its bounding box
+    let filled = cells
+        .iter()
+        .enumerate()
+        .flat_map(|(y, line)| {
+            line.iter()
+                .enumerate()
+                .filter(|(_, filled)| **filled)
+                .map(move |(x, _)| (x, y))
+        })
+        .collect::<Vec<_>>();
+    if filled.is_empty() {
+        return Err(Error::new(None, "expected at least one `#`"));
+    }
+    let min_x = filled.iter().map(|(x, _)| *x).min().unwrap();
+    let max_x = filled.iter().map(|(x, _)| *x).max().unwrap();
+    let min_y = filled.iter().map(|(_, y)| *y).min().unwrap();
+    let max_y = filled.iter().map(|(_, y)| *y).max().unwrap();
+
+    let (w, h) = (max_x - min_x + 1, max_y - min_y + 1);
+    if w > MAX || h > MAX {
+        return Err(Error::new(
+            None,
+            format!("polyominoes can be at most {}x{} cells big", MAX, MAX),
+        ));
+    }
+
+    let mut mask = 0u64;
+    for (x, y) in filled {
+        mask |= 1 << ((y - min_y) * MAX + (x - min_x));
+    }
+    Ok((mask, w, h))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn t_piece() {
+        let canvas = Canvas::from_str(
+            "
+. . . .
+. # # #
+. . # .
+",
+        );
+        assert_eq!(parse(&canvas).unwrap(), (0b0000_0010_0000_0111, 3, 2));
+    }
+
+    #[test]
+    fn ragged() {
+        let canvas = Canvas::from_str(
+            "
+. # .
+# # # .
+",
+        );
+        assert!(parse(&canvas).is_err());
+    }
+}
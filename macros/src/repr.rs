@@ -0,0 +1,235 @@
+//! `analog_repr!`
+
+use proc_macro::{Spacing, TokenStream, TokenTree};
+
+use crate::boxes;
+use crate::canvas::Canvas;
+use crate::error::{Error, Result};
+
+pub fn expand(input: TokenStream) -> Result<TokenStream> {
+    let mut header = Vec::new();
+    let mut tokens = input.into_iter().peekable();
+    loop {
+        match tokens.next() {
+            Some(TokenTree::Punct(p)) if p.as_char() == '=' && p.spacing() == Spacing::Joint => {
+                match tokens.next() {
+                    Some(TokenTree::Punct(p)) if p.as_char() == '>' => break,
+                    _ => return Err(Error::new(Some(p.span()), "expected `=>`")),
+                }
+            }
+            Some(tt) => header.push(tt),
+            None => {
+                return Err(Error::new(
+                    None,
+                    "expected `Type => <diagram>` (e.g: `pub Header => +--+ ...`)",
+                ))
+            }
+        }
+    }
+
+    let (vis, ty) = split_visibility(header);
+    if ty.is_empty() {
+        return Err(Error::new(None, "expected a type before the `=>`"));
+    }
+
+    let canvas = Canvas::from_tokens(tokens.collect());
+    let fields = fields(&canvas)?;
+
+    let mut code = String::new();
+    let mut offset = 0;
+    for field in &fields {
+        if field.name != "_" {
+            let suffix = field.name.trim_start_matches("r#").to_uppercase();
+            code.push_str(&format!(
+                "#[doc = \"Offset of `{ty}::{name}`, as drawn.\"] \
+                 {vis} const OFFSET_{suffix}: usize = {offset}; \
+                 #[doc = \"Size of `{ty}::{name}`, as drawn.\"] \
+                 {vis} const SIZE_{suffix}: usize = {size}; \
+                 const _: () = ::core::assert!( \
+                    ::core::mem::offset_of!({ty}, {name}) == {offset}, \
+                    \"the offset of `{ty}::{name}` doesn't match its diagram\", \
+                 ); \
+                 const _: () = ::core::assert!( \
+                    $crate::__private::size_of_field(|v: &{ty}| &v.{name}) == {size}, \
+                    \"the size of `{ty}::{name}` doesn't match its diagram\", \
+                 );",
+                ty = ty,
+                vis = vis,
+                name = field.name,
+                suffix = suffix,
+                offset = offset,
+                size = field.size,
+            ));
+        }
+        offset += field.size;
+    }
+    code.push_str(&format!(
+        "const _: () = ::core::assert!( \
+            ::core::mem::size_of::<{ty}>() == {size}, \
+            \"the size of `{ty}` doesn't match its diagram\", \
+         );",
+        ty = ty,
+        size = offset,
+    ));
+
+    Ok(code.parse().unwrap())
+}
+
+/// Split a leading `pub` / `pub(...)` off of the type name.
+fn split_visibility(header: Vec<TokenTree>) -> (String, String) {
+    let mut tokens = header.into_iter().peekable();
+    let mut vis = TokenStream::new();
+    if let Some(TokenTree::Ident(ident)) = tokens.peek() {
+        if ident.to_string() == "pub" {
+            vis.extend(tokens.next());
+            if let Some(TokenTree::Group(_)) = tokens.peek() {
+                vis.extend(tokens.next());
+            }
+        }
+    }
+    (vis.to_string(), tokens.collect::<TokenStream>().to_string())
+}
+
+/// A field in a struct layout diagram.
+#[derive(Debug, PartialEq, Eq)]
+struct Field {
+    name: String,
+    size: usize,
+}
+
+/// Read the fields (in order) out of a layout diagram.
+fn fields(canvas: &Canvas) -> Result<Vec<Field>> {
+    let outlines = boxes::find(canvas);
+    if outlines.is_empty() {
+        return Err(Error::new(None, "expected a layout diagram"));
+    }
+
+    for row in 0..canvas.height() {
+        for col in 0..canvas.width(row) {
+            let drawn = outlines
+                .iter()
+                .any(|o| (o.top..=o.bottom).contains(&row) && (o.left..=o.right).contains(&col));
+            if !drawn && canvas.get(row, col) != ' ' {
+                return Err(Error::new(
+                    canvas.span(row, col),
+                    "this isn't part of the layout diagram",
+                ));
+            }
+        }
+    }
+
+    let mut fields = Vec::new();
+    for outline in outlines {
+        let mut text = String::new();
+        for row in outline.top + 1..outline.bottom {
+            for col in outline.left + 1..outline.right {
+                text.push(canvas.get(row, col));
+            }
+            text.push(' ');
+        }
+
+        let mut words = text.split_whitespace();
+        let name = match (words.next(), words.next()) {
+            (Some(name), None) if is_ident(name) => name.to_owned(),
+            _ => {
+                return Err(Error::new(
+                    canvas.span(outline.top, outline.left),
+                    "expected a field name (or `_` for padding) inside each box",
+                ))
+            }
+        };
+
+        fields.push(Field {
+            name,
+            size: outline.w(canvas)?,
+        });
+    }
+
+    Ok(fields)
+}
+
+fn is_ident(s: &str) -> bool {
+    let s = s.trim_start_matches("r#");
+    let mut chars = s.chars();
+    matches!(chars.next(), Some(c) if c == '_' || c.is_alphabetic())
+        && chars.all(|c| c == '_' || c.is_alphanumeric())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_row() {
+        let canvas = Canvas::from_str(
+            "
++--------+----+----+----------------+
+|  tag   |len | _  |      crc       |
++--------+----+----+----------------+
+",
+        );
+        let fields = fields(&canvas).unwrap();
+        let fields = fields
+            .iter()
+            .map(|f| (f.name.as_str(), f.size))
+            .collect::<Vec<_>>();
+        assert_eq!(fields, [("tag", 4), ("len", 2), ("_", 2), ("crc", 8)]);
+    }
+
+    #[test]
+    fn multi_row() {
+        let canvas = Canvas::from_str(
+            "
++--------+--------+
+|  src   |  dst   |
++--------+--------+
++----------------+
+|      seq       |
++----------------+
+",
+        );
+        let fields = fields(&canvas).unwrap();
+        let fields = fields
+            .iter()
+            .map(|f| (f.name.as_str(), f.size))
+            .collect::<Vec<_>>();
+        assert_eq!(fields, [("src", 4), ("dst", 4), ("seq", 8)]);
+    }
+
+    #[test]
+    fn odd_width() {
+        let canvas = Canvas::from_str(
+            "
++---------+
+|   tag   |
++---------+
+",
+        );
+        let err = fields(&canvas).unwrap_err();
+        assert!(err.msg().contains("`--`"));
+    }
+
+    #[test]
+    fn stray_content() {
+        for diagram in [
+            "+--+---\n|a |\n+--+",
+            "+--+\n|a | xyz\n+--+",
+            "+--+\n|a |\n+--+\n !!",
+        ] {
+            let err = fields(&Canvas::from_str(diagram)).unwrap_err();
+            assert_eq!(err.msg(), "this isn't part of the layout diagram");
+        }
+    }
+
+    #[test]
+    fn not_a_field() {
+        let canvas = Canvas::from_str(
+            "
++--------+
+| 2 + 2  |
++--------+
+",
+        );
+        assert!(fields(&canvas).is_err());
+    }
+}
@@ -0,0 +1,171 @@
+//! `analog_scene!` and `analog_positioned!`
+
+use proc_macro::TokenStream;
+
+use crate::boxes::{self, Outline};
+use crate::canvas::{Canvas, Origin};
+use crate::error::{Error, Result};
+
+pub fn expand(input: TokenStream) -> Result<TokenStream> {
+    let canvas = Canvas::from_tokens(input);
+    let shapes = build(&canvas, &Origin::of_drawing(&canvas))?;
+
+    let mut code = String::from("[");
+    for shape in shapes {
+        code.push_str(&shape.to_code());
+        code.push(',');
+    }
+    code.push(']');
+    Ok(code.parse().unwrap())
+}
+
+pub fn expand_one(input: TokenStream) -> Result<TokenStream> {
+    let canvas = Canvas::from_tokens(input);
+    let shapes = build(&canvas, &Origin::of_drawing(&canvas))?;
+
+    match shapes.as_slice() {
+        [shape] => Ok(shape.to_code().parse().unwrap()),
+        [] => Err(Error::new(None, "expected a rectangle")),
+        _ => Err(Error::new(
+            None,
+            "expected a single rectangle (use `analog_scene!` to draw several)",
+        )),
+    }
+}
+
+/// A rectangle, and where it was drawn.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Positioned {
+    pub x: usize,
+    pub y: usize,
+    pub w: usize,
+    pub h: usize,
+}
+
+impl Positioned {
+    pub fn new(canvas: &Canvas, origin: &Origin, outline: &Outline) -> Result<Positioned> {
+        let (x, y) = origin.locate(canvas, outline.top, outline.left)?;
+        Ok(Positioned {
+            x,
+            y,
+            w: outline.w(canvas)?,
+            h: outline.h(),
+        })
+    }
+
+    pub fn to_code(&self) -> String {
+        format!(
+            "$crate::PositionedRectangle {{ x: {}, y: {}, w: {}, h: {} }}",
+            self.x, self.y, self.w, self.h
+        )
+    }
+}
+
+fn build(canvas: &Canvas, origin: &Origin) -> Result<Vec<Positioned>> {
+    let found = boxes::find(canvas);
+
+    for (i, a) in found.iter().enumerate() {
+        for b in &found[i + 1..] {
+            if a.overlaps(b) {
+                return Err(Error::new(
+                    canvas.span(b.top, b.left),
+                    "rectangles in a scene must be drawn apart from one another",
+                ));
+            }
+        }
+    }
+
+    found
+        .iter()
+        .map(|outline| Positioned::new(canvas, origin, outline))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn positions() {
+        let canvas = Canvas::from_str(
+            "
+
+    +--+        +------+
+    |  |        |      |
+    +--+        |      |
+                +------+
+  +----+
+  +----+
+",
+        );
+        let origin = Origin { line: 2, col: 2 };
+        let shapes = build(&canvas, &origin).unwrap();
+        assert_eq!(
+            shapes,
+            [
+                Positioned {
+                    x: 1,
+                    y: 1,
+                    w: 1,
+                    h: 1
+                },
+                Positioned {
+                    x: 7,
+                    y: 1,
+                    w: 3,
+                    h: 2
+                },
+                Positioned {
+                    x: 0,
+                    y: 5,
+                    w: 2,
+                    h: 0
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn origin() {
+        let marked = Canvas::from_str(
+            "
+        +
+
+                +--+
+                |  |
+                +--+",
+        );
+        let unmarked = Canvas::from_str(
+            "
+    +--+
+    |  |  +--+
+    +--+  +--+",
+        );
+        let locate = |canvas: &Canvas| {
+            build(canvas, &Origin::of_drawing(canvas))
+                .unwrap()
+                .iter()
+                .map(|shape| (shape.x, shape.y))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(locate(&marked), [(4, 2)]);
+        assert_eq!(locate(&unmarked), [(0, 0), (3, 1)]);
+
+        let odd = Canvas::from_str("+\n +--+\n +--+");
+        assert!(build(&odd, &Origin::of_drawing(&odd)).is_err());
+    }
+
+    #[test]
+    fn nested() {
+        let canvas = Canvas::from_str(
+            "
++--------+
+| +--+   |
+| +--+   |
++--------+
+",
+        );
+        let origin = Origin { line: 1, col: 0 };
+        assert!(build(&canvas, &origin).is_err());
+    }
+}
@@ -0,0 +1,241 @@
+//! `analog_stairs!`
+
+use proc_macro::TokenStream;
+
+use crate::canvas::Canvas;
+use crate::error::{Error, Result};
+
+pub fn expand(input: TokenStream) -> Result<TokenStream> {
+    let canvas = Canvas::from_tokens(input);
+    let stairs = parse(&canvas)?;
+    Ok(format!(
+        "$crate::stairs::Stairs {{ steps: {}, rise: {}, run: {}, total_w: {}, total_h: {} }}",
+        stairs.steps,
+        stairs.rise,
+        stairs.run,
+        stairs.steps * stairs.run,
+        stairs.steps * stairs.rise,
+    )
+    .parse()
+    .unwrap())
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Stairs {
+    steps: usize,
+    rise: usize,
+    run: usize,
+}
+
+/// Walk up the staircase, starting from the `+` at the very bottom, and
+/// measure every riser and tread along the way.
+fn parse(canvas: &Canvas) -> Result<Stairs> {
+    let mut visited = (0..canvas.height())
+        .map(|row| vec![false; canvas.width(row)])
+        .collect::<Vec<_>>();
+
+    let bottom = (0..canvas.height())
+        .rev()
+        .find(|&row| (0..canvas.width(row)).any(|col| canvas.get(row, col) == '+'))
+        .ok_or_else(|| Error::new(None, "expected a staircase"))?;
+    let start = (0..canvas.width(bottom))
+        .filter(|&col| canvas.get(bottom, col) == '+')
+        .find(|&col| bottom > 0 && canvas.get(bottom - 1, col) == '|')
+        .ok_or_else(|| {
+            Error::new(
+                (0..canvas.width(bottom)).find_map(|col| canvas.span(bottom, col)),
+                "expected the bottom of the staircase to be a `+` with a `|` going up from it",
+            )
+        })?;
+
+    let (mut row, mut col) = (bottom, start);
+    visited[row][col] = true;
+
+    // every step is a riser followed by a tread
+    let mut steps = Vec::<(usize, usize, (usize, usize))>::new();
+    let mut dir = None;
+    while row > 0 && canvas.get(row - 1, col) == '|' {
+        let corner = (row, col);
+
+        let mut rise = 0;
+        while row > 0 && canvas.get(row - 1, col) == '|' {
+            row -= 1;
+            rise += 1;
+            visited[row][col] = true;
+        }
+        if row == 0 || canvas.get(row - 1, col) != '+' {
+            return Err(Error::new(
+                canvas.span(row, col),
+                "expected this riser to end in a `+`",
+            ));
+        }
+        row -= 1;
+        visited[row][col] = true;
+
+        let left = col > 0 && canvas.get(row, col - 1) == '-';
+        let right = canvas.get(row, col + 1) == '-';
+        let step: isize = match (left, right) {
+            (false, true) => 1,
+            (true, false) => -1,
+            _ => {
+                return Err(Error::new(
+                    canvas.span(row, col),
+                    "expected a tread going either left or right from here",
+                ))
+            }
+        };
+        if *dir.get_or_insert(step) != step {
+            return Err(Error::new(
+                canvas.span(row, col),
+                "staircases have to keep going in the same direction",
+            ));
+        }
+
+        let mut dashes = 0;
+        loop {
+            col = col.wrapping_add_signed(step);
+            match canvas.get(row, col) {
+                '-' => {
+                    dashes += 1;
+                    visited[row][col] = true;
+                }
+                '+' => {
+                    visited[row][col] = true;
+                    break;
+                }
+                _ => {
+                    return Err(Error::new(
+                        canvas.span(row, col.wrapping_add_signed(-step)),
+                        "expected this tread to end in a `+`",
+                    ))
+                }
+            }
+        }
+        if dashes % 2 != 0 {
+            return Err(Error::new(
+                canvas.span(row, col),
+                "treads are measured in `--`, so this one's got a `-` too many (or too few)",
+            ));
+        }
+
+        steps.push((rise, dashes / 2, corner));
+    }
+
+    for (row, cells) in visited.iter().enumerate() {
+        for (col, &visited) in cells.iter().enumerate() {
+            if !visited && canvas.get(row, col) != ' ' {
+                return Err(Error::new(
+                    canvas.span(row, col),
+                    "this isn't part of the staircase",
+                ));
+            }
+        }
+    }
+
+    let (rise, run, _) = steps[0];
+    for &(r, w, (row, col)) in &steps[1..] {
+        if (r, w) != (rise, run) {
+            return Err(Error::new(
+                canvas.span(row, col),
+                format!(
+                    "every step has to be the same size, but this one is {} `|` tall and {} `--` \
+                     deep (the first one is {} `|` tall and {} `--` deep)",
+                    r, w, rise, run
+                ),
+            ));
+        }
+    }
+
+    Ok(Stairs {
+        steps: steps.len(),
+        rise,
+        run,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse_str(s: &str) -> std::result::Result<Stairs, String> {
+        parse(&Canvas::from_str(s)).map_err(|e| e.msg().to_owned())
+    }
+
+    #[test]
+    fn stairs() {
+        assert_eq!(
+            parse_str(
+                "
+          +----+
+          |
+     +----+
+     |
++----+
+|
++"
+            ),
+            Ok(Stairs {
+                steps: 3,
+                rise: 1,
+                run: 2,
+            })
+        );
+
+        assert_eq!(
+            parse_str(
+                "
++--+
+|  |
+|  +--+
+|     |
++     +"
+            )
+            .unwrap_err(),
+            "this isn't part of the staircase"
+        );
+
+        assert_eq!(
+            parse_str(
+                "
++------+
+       |
+       |
+       +"
+            ),
+            Ok(Stairs {
+                steps: 1,
+                rise: 2,
+                run: 3,
+            })
+        );
+    }
+
+    #[test]
+    fn uneven() {
+        assert!(parse_str(
+            "
+       +--+
+       |
+  +----+
+  |
+  +"
+        )
+        .unwrap_err()
+        .starts_with("every step has to be the same size"));
+
+        assert_eq!(
+            parse_str(
+                "
+     +--+
+     |
+  +--+
+  |
+  +--+
+     |
+     +"
+            )
+            .unwrap_err(),
+            "staircases have to keep going in the same direction"
+        );
+    }
+}
@@ -0,0 +1,316 @@
+//! `analog_literal! { strict; ... }`
+//!
+//! `analog_literal!` proper only ever counts tokens, so it's none the wiser if
+//! a row has been nudged a few columns out of place. This paints the drawing
+//! onto a [`Canvas`], works out what the literal _should_ look like based on
+//! its top edge and number of rows, and makes sure every character is right
+//! where it belongs. The actual parsing is then left to `analog_literal!`, as
+//! usual.
+
+use proc_macro::{Delimiter, Group, Span, TokenStream, TokenTree};
+
+use crate::canvas::Canvas;
+use crate::error::{Error, Result};
+use crate::join;
+
+pub fn expand(input: TokenStream) -> Result<TokenStream> {
+    for (i, (_, tokens)) in join::split(input.clone()).into_iter().enumerate() {
+        // any other prefixes (e.g: `u32;`) get passed along untouched
+        let start = match i {
+            0 => tokens
+                .iter()
+                .rposition(|tt| matches!(tt, TokenTree::Punct(p) if p.as_char() == ';'))
+                .map_or(0, |i| i + 1),
+            _ => 0,
+        };
+        let drawing = strip_doc_comments(tokens[start..].iter().cloned());
+
+        check(&Canvas::from_tokens(drawing))?;
+    }
+
+    let mut code = "$crate::analog_literal!".parse::<TokenStream>().unwrap();
+    code.extend(Some(TokenTree::Group(Group::new(Delimiter::Brace, input))));
+    Ok(code)
+}
+
+/// Doc comments show up as `#[doc = "..."]` tokens, which would otherwise get
+/// painted right on top of the drawing.
+fn strip_doc_comments(tokens: impl Iterator<Item = TokenTree>) -> TokenStream {
+    let tokens = tokens.collect::<Vec<_>>();
+    let mut out = Vec::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        if let TokenTree::Punct(p) = &tokens[i] {
+            if p.as_char() == '#' {
+                let bang =
+                    matches!(&tokens.get(i + 1), Some(TokenTree::Punct(p)) if p.as_char() == '!');
+                let attr = i + 1 + bang as usize;
+                if let Some(TokenTree::Group(g)) = tokens.get(attr) {
+                    if g.delimiter() == Delimiter::Bracket
+                        && g.stream().to_string().starts_with("doc")
+                    {
+                        i = attr + 1;
+                        continue;
+                    }
+                }
+            }
+        }
+        out.push(tokens[i].clone());
+        i += 1;
+    }
+    out.into_iter().collect()
+}
+
+/// Make sure the literal on the canvas is drawn exactly the way it ought to
+/// be (give or take how far it's indented).
+fn check(canvas: &Canvas) -> Result<()> {
+    let rows = (0..canvas.height())
+        .filter(|&row| first(canvas, row).is_some())
+        .collect::<Vec<_>>();
+    let (top, bottom) = match (rows.first(), rows.last()) {
+        (Some(&top), Some(&bottom)) => (top, bottom),
+        _ => return Err(Error::new(None, "expected an analog literal")),
+    };
+    let height = bottom - top + 1;
+
+    let left = first(canvas, top).unwrap();
+    let edge = (left..canvas.width(top))
+        .take_while(|&col| matches!(canvas.get(top, col), '+' | '-' | 'I'))
+        .count();
+    let w = edge.saturating_sub(2) / 2;
+
+    let slanted = (top + 1..=bottom)
+        .take_while(|&row| first(canvas, row).map(|col| canvas.get(row, col)) == Some('/'))
+        .count();
+
+    let (expected, indent) = if height == 1 {
+        let end = if canvas.get(top, left) == 'I' {
+            'I'
+        } else {
+            '+'
+        };
+        (vec![edge_of(canvas.get(top, left), w, end)], 0)
+    } else if slanted == 0 {
+        (rectangle(w, height - 2), 0)
+    } else if height == slanted + 2 {
+        (cuboid(w, 0, slanted), slanted + 1)
+    } else {
+        (
+            cuboid(w, height.saturating_sub(slanted + 3), slanted),
+            slanted + 1,
+        )
+    };
+
+    let origin = left.checked_sub(indent).ok_or_else(|| {
+        Error::new(
+            canvas.span(top, left),
+            "the top edge of this cuboid isn't indented far enough",
+        )
+    })?;
+
+    for row in 0..height.max(expected.len()) {
+        let expected_row = expected.get(row).map_or(&[][..], Vec::as_slice);
+        let cols = canvas.width(top + row).max(origin + expected_row.len());
+        let mut mismatched = (0..cols).filter_map(|col| {
+            let want = col
+                .checked_sub(origin)
+                .and_then(|col| expected_row.get(col))
+                .copied()
+                .unwrap_or(' ');
+            let got = canvas.get(top + row, col);
+            (want != got).then_some((col, want, got))
+        });
+
+        // pointing at a character that's out of place beats pointing at the
+        // empty spot it should've been in
+        let mismatch = mismatched.clone().find(|&(_, _, got)| got != ' ');
+        match mismatch.or_else(|| mismatched.next()) {
+            None => {}
+            Some((col, _, got)) if got != ' ' => {
+                return Err(Error::new(
+                    canvas.span(top + row, col),
+                    format!("this `{}` is out of line", got),
+                ))
+            }
+            Some((_, want, _)) => {
+                return Err(Error::new(
+                    row_span(canvas, top + row).or_else(|| row_span(canvas, bottom)),
+                    format!("this row is missing a `{}` (is it out of line?)", want),
+                ))
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Return the column of the first non-blank character in the given row.
+fn first(canvas: &Canvas, row: usize) -> Option<usize> {
+    (0..canvas.width(row)).find(|&col| canvas.get(row, col) != ' ')
+}
+
+fn row_span(canvas: &Canvas, row: usize) -> Option<Span> {
+    (0..canvas.width(row)).find_map(|col| canvas.span(row, col))
+}
+
+/// A horizontal edge, `w` `--` wide.
+fn edge_of(start: char, w: usize, end: char) -> Vec<char> {
+    let mut row = vec![start];
+    row.extend(std::iter::repeat_n('-', 2 * w));
+    row.push(end);
+    row
+}
+
+fn rectangle(w: usize, h: usize) -> Vec<Vec<char>> {
+    let mut rows = vec![edge_of('+', w, '+')];
+    for _ in 0..h {
+        let mut row = vec![' '; 2 * w + 2];
+        row[0] = '|';
+        row[2 * w + 1] = '|';
+        rows.push(row);
+    }
+    rows.push(edge_of('+', w, '+'));
+    rows
+}
+
+/// A cuboid, drawn exactly the way `analog_literals` would render it.
+fn cuboid(w: usize, h: usize, l: usize) -> Vec<Vec<char>> {
+    let back = l + 2 * w + 2;
+    let mut rows = vec![vec![' '; back + 1]; if h == 0 { l + 2 } else { l + h + 3 }];
+    let mut put = |row: usize, col: usize, ch: char| rows[row][col] = ch;
+
+    // the top face
+    put(0, l + 1, '+');
+    for col in l + 2..back {
+        put(0, col, '-');
+    }
+    put(0, back, '+');
+    for row in 1..=l {
+        put(row, l + 1 - row, '/');
+        put(row, back - row, '/');
+    }
+
+    // the front face (or with no height, just the one edge of it)
+    let front = if h == 0 {
+        vec![edge_of('+', w, '+')]
+    } else {
+        rectangle(w, h)
+    };
+    for (i, front_row) in front.iter().enumerate() {
+        for (col, &ch) in front_row.iter().enumerate() {
+            if ch != ' ' {
+                put(l + 1 + i, col, ch);
+            }
+        }
+    }
+
+    // the right face
+    if h != 0 {
+        for row in 1..=l + h + 1 {
+            match row {
+                _ if row <= h => put(row, back, '|'),
+                _ if row == h + 1 => put(row, back, '+'),
+                _ => put(row, back - (row - h - 1), '/'),
+            }
+        }
+    }
+
+    // trailing whitespace isn't part of the drawing
+    for row in rows.iter_mut() {
+        while row.last() == Some(&' ') {
+            row.pop();
+        }
+    }
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_str(s: &str) -> std::result::Result<(), String> {
+        check(&Canvas::from_str(s)).map_err(|e| e.msg().to_owned())
+    }
+
+    #[test]
+    fn aligned() {
+        check_str("+------+").unwrap();
+        check_str("I----I").unwrap();
+        check_str(
+            "
+    +----+
+    |    |
+    |    |
+    +----+",
+        )
+        .unwrap();
+        check_str(
+            "
+         +----------+
+        /          /|
+       /          / |
+      /          /  +
+     /          /  /
+    +----------+  /
+    |          | /
+    |          |/
+    +----------+",
+        )
+        .unwrap();
+        check_str(
+            "
+      +----+
+     /    /
+    +----+",
+        )
+        .unwrap();
+        check_str("++\n||\n++").unwrap();
+    }
+
+    #[test]
+    fn misaligned() {
+        let err = check_str(
+            "
+    +----+
+     |    |
+    +----+",
+        )
+        .unwrap_err();
+        assert_eq!(err, "this `|` is out of line");
+
+        let err = check_str(
+            "
+    +----+
+    |
+    +----+",
+        )
+        .unwrap_err();
+        assert_eq!(err, "this row is missing a `|` (is it out of line?)");
+
+        let err = check_str(
+            "
+    +----+
+    |     |
+    +----+",
+        )
+        .unwrap_err();
+        assert_eq!(err, "this `|` is out of line");
+
+        let err = check_str(
+            "
+      +----+
+     /    /|
+    +----+  +
+    |    |/
+    +----+",
+        )
+        .unwrap_err();
+        assert_eq!(err, "this `+` is out of line");
+
+        let err = check_str("+-- --+").unwrap_err();
+        assert_eq!(err, "this `-` is out of line");
+
+        let err = check_str(" +----+\n/    /\n+----+").unwrap_err();
+        assert_eq!(err, "the top edge of this cuboid isn't indented far enough");
+    }
+}
@@ -0,0 +1,224 @@
+//! `analog_table!`
+
+use proc_macro::TokenStream;
+
+use crate::canvas::Canvas;
+use crate::error::{Error, Result};
+
+pub fn expand(input: TokenStream) -> Result<TokenStream> {
+    let canvas = Canvas::from_tokens(input);
+    let table = parse(&canvas)?;
+
+    let mut code = String::from("$crate::table::Table { headers: [");
+    for header in &table.headers {
+        code.push_str(&format!("{:?},", header));
+    }
+    code.push_str("], rows: [");
+    for row in &table.rows {
+        code.push('[');
+        for cell in row {
+            code.push_str(&format!("{:?},", cell));
+        }
+        code.push_str("],");
+    }
+    code.push_str("] }");
+    Ok(code.parse().unwrap())
+}
+
+#[derive(Debug)]
+struct Table {
+    headers: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+/// Check if the given row of the canvas is a `+----+----+` style border.
+fn is_border(canvas: &Canvas, row: usize) -> bool {
+    let text = canvas.text(row, 0..canvas.width(row));
+    let text = text.trim();
+    text.starts_with('+') && text.chars().all(|ch| matches!(ch, '+' | '-' | '='))
+}
+
+fn parse(canvas: &Canvas) -> Result<Table> {
+    let rows = (0..canvas.height())
+        .filter(|&row| !canvas.text(row, 0..canvas.width(row)).trim().is_empty())
+        .collect::<Vec<_>>();
+    let top = match rows.first() {
+        Some(&top) if is_border(canvas, top) => top,
+        _ => {
+            return Err(Error::new(
+                None,
+                "expected a table, starting with a `+---+` border",
+            ))
+        }
+    };
+
+    // the top border decides where all the columns are
+    let walls = (0..canvas.width(top))
+        .filter(|&col| canvas.get(top, col) == '+')
+        .collect::<Vec<_>>();
+    if walls.len() < 2 {
+        return Err(Error::new(
+            canvas.span(top, walls[0]),
+            "expected at least one column",
+        ));
+    }
+    let right = walls[walls.len() - 1];
+
+    let mut headers = None;
+    let mut body = Vec::new();
+    let mut pending = Vec::new();
+    for &row in &rows[1..] {
+        if is_border(canvas, row) {
+            if canvas.width(row) != right + 1 {
+                return Err(Error::new(
+                    canvas.span(row, canvas.width(row) - 1),
+                    "this border isn't the same width as the top of the table",
+                ));
+            }
+            for &col in &walls {
+                if canvas.get(row, col) != '+' {
+                    return Err(Error::new(
+                        canvas.span(row, col),
+                        "expected a `+` lined up with the column above",
+                    ));
+                }
+            }
+
+            if headers.is_none() {
+                if pending.len() != 1 {
+                    return Err(Error::new(
+                        canvas.span(row, walls[0]),
+                        "expected a single header row above the first separator",
+                    ));
+                }
+                headers = pending.pop();
+            } else {
+                body.append(&mut pending);
+            }
+            continue;
+        }
+
+        for &col in &walls {
+            if canvas.get(row, col) != '|' {
+                let span = canvas
+                    .span(row, col)
+                    .or_else(|| (0..canvas.width(row)).find_map(|c| canvas.span(row, c)));
+                return Err(Error::new(
+                    span,
+                    "expected a `|` lined up with the column borders",
+                ));
+            }
+        }
+        if canvas.width(row) != right + 1 {
+            return Err(Error::new(
+                canvas.span(row, canvas.width(row) - 1),
+                "this row sticks out past the edge of the table",
+            ));
+        }
+
+        pending.push(
+            walls
+                .windows(2)
+                .map(|w| canvas.text(row, w[0] + 1..w[1]).trim().to_owned())
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    if !pending.is_empty() {
+        return Err(Error::new(
+            None,
+            "expected the table to end with a `+---+` border",
+        ));
+    }
+    let headers = headers.ok_or_else(|| {
+        Error::new(
+            None,
+            "expected a header row, separated from the body by a `+===+` (or `+---+`) border",
+        )
+    })?;
+
+    Ok(Table {
+        headers,
+        rows: body,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn table() {
+        let canvas = Canvas::from_str(
+            "
++---------+--------------+
+| command | what it does |
++=========+==============+
+| build   | builds       |
+| test    |              |
++---------+--------------+
+| clean   | deletes 5 GB |
++---------+--------------+
+",
+        );
+        let table = parse(&canvas).unwrap();
+        assert_eq!(table.headers, ["command", "what it does"]);
+        assert_eq!(
+            table.rows,
+            [["build", "builds"], ["test", ""], ["clean", "deletes 5 GB"],]
+        );
+    }
+
+    #[test]
+    fn misaligned() {
+        let canvas = Canvas::from_str(
+            "
++---------+--------+
+| command | alias  |
++=========+========+
+| build  | b       |
++---------+--------+
+",
+        );
+        assert!(parse(&canvas).is_err());
+
+        let canvas = Canvas::from_str(
+            "
++---------+--------+
+| command | alias  |
++=========+=========+
+| build   | b      |
++---------+--------+
+",
+        );
+        assert!(parse(&canvas).is_err());
+    }
+
+    #[test]
+    fn dashed_header() {
+        let canvas = Canvas::from_str(
+            "
++---------+--------+
+| command | alias  |
++---------+--------+
+| build   | b      |
++---------+--------+
+",
+        );
+        let table = parse(&canvas).unwrap();
+        assert_eq!(table.headers, ["command", "alias"]);
+        assert_eq!(table.rows, [["build", "b"]]);
+    }
+
+    #[test]
+    fn no_header() {
+        let canvas = Canvas::from_str(
+            "
++---------+--------+
+| build   | b      |
+| test    | t      |
+",
+        );
+        assert!(parse(&canvas).is_err());
+    }
+}
@@ -0,0 +1,197 @@
+//! `analog_venn!`
+
+use proc_macro::TokenStream;
+
+use crate::canvas::Canvas;
+use crate::error::{Error, Result};
+
+pub fn expand(input: TokenStream) -> Result<TokenStream> {
+    let canvas = Canvas::from_tokens(input);
+    let venn = parse(&canvas)?;
+    Ok(format!(
+        "$crate::venn::Venn {{ left_only: {}, right_only: {}, both: {} }}",
+        venn.left_only, venn.right_only, venn.both
+    )
+    .parse()
+    .unwrap())
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+struct Venn {
+    left_only: usize,
+    right_only: usize,
+    both: usize,
+}
+
+/// Add up everything written in a region of the diagram: numbers count for
+/// themselves, and every `*` counts for one.
+fn count(canvas: &Canvas, row: usize, start: usize, end: usize) -> Result<usize> {
+    let text = canvas.text(row, start..end);
+    let mut total = 0usize;
+    for word in text.split_whitespace() {
+        let n = if word.chars().all(|ch| ch == '*') {
+            word.len()
+        } else if word.starts_with(|ch: char| ch.is_ascii_digit()) {
+            word.replace('_', "").parse::<usize>().map_err(|_| {
+                Error::new(
+                    canvas.span(row, start + text.find(word).unwrap()),
+                    "expected a plain old number",
+                )
+            })?
+        } else if word.chars().all(|ch| ch.is_alphanumeric() || ch == '_') {
+            // labels are just for show
+            0
+        } else {
+            return Err(Error::new(
+                canvas.span(row, start + text.find(word).unwrap()),
+                "expected a number, some `*` shading, or a label",
+            ));
+        };
+        total = add(canvas, row, start, total, n)?;
+    }
+    Ok(total)
+}
+
+fn add(canvas: &Canvas, row: usize, col: usize, a: usize, b: usize) -> Result<usize> {
+    a.checked_add(b)
+        .ok_or_else(|| Error::new(canvas.span(row, col), "that's a lot of stuff"))
+}
+
+fn parse(canvas: &Canvas) -> Result<Venn> {
+    let mut venn = Venn::default();
+    let mut circles = false;
+    for row in 0..canvas.height() {
+        let parens = (0..canvas.width(row))
+            .filter(|&col| matches!(canvas.get(row, col), '(' | ')'))
+            .collect::<Vec<_>>();
+        if parens.is_empty() {
+            continue;
+        }
+        let shape = parens
+            .iter()
+            .map(|&col| canvas.get(row, col))
+            .collect::<String>();
+
+        let [a, b, c, d] = match parens.as_slice() {
+            [a, b, c, d] if shape == "(())" || shape == "()()" => [*a, *b, *c, *d],
+            _ => {
+                return Err(Error::new(
+                    canvas.span(row, parens[0]),
+                    "expected each row to cut through both circles, e.g: `(  (  )  )`",
+                ))
+            }
+        };
+        circles = true;
+
+        venn.left_only = add(
+            canvas,
+            row,
+            a,
+            venn.left_only,
+            count(canvas, row, a + 1, b)?,
+        )?;
+        if shape == "(())" {
+            venn.both = add(canvas, row, b, venn.both, count(canvas, row, b + 1, c)?)?;
+        } else if let Some(offset) = canvas.text(row, b + 1..c).find(|ch: char| ch != ' ') {
+            // the circles don't overlap here, so there's nowhere for this to go
+            return Err(Error::new(
+                canvas.span(row, b + 1 + offset),
+                "this is outside both circles",
+            ));
+        }
+        venn.right_only = add(
+            canvas,
+            row,
+            c,
+            venn.right_only,
+            count(canvas, row, c + 1, d)?,
+        )?;
+    }
+
+    if !circles {
+        return Err(Error::new(None, "expected a pair of `(` `)` circles"));
+    }
+    Ok(venn)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts() {
+        let canvas = Canvas::from_str(
+            "
+  (  users  ) (  admins  )
+ (          ( )           )
+(    1_200   ( 3 )    7    )
+ (          ( )           )
+  (         ) (           )
+",
+        );
+        assert_eq!(
+            parse(&canvas).unwrap(),
+            Venn {
+                left_only: 1200,
+                right_only: 7,
+                both: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn shading() {
+        let canvas = Canvas::from_str(
+            "
+ (  **  ( ** )      )
+(  ***  ( ** )   *   )
+ (      (    )      )
+",
+        );
+        assert_eq!(
+            parse(&canvas).unwrap(),
+            Venn {
+                left_only: 5,
+                right_only: 1,
+                both: 4,
+            }
+        );
+    }
+
+    #[test]
+    fn lopsided() {
+        let canvas = Canvas::from_str(
+            "
+(    1    ( 3 )    7    )    )
+",
+        );
+        assert!(parse(&canvas).is_err());
+    }
+
+    #[test]
+    fn outside() {
+        let canvas = Canvas::from_str(
+            "
+ (     )     (     )
+(   1   )  4  (   3   )
+ (     )     (     )
+",
+        );
+        assert_eq!(
+            parse(&canvas).unwrap_err().msg(),
+            "this is outside both circles"
+        );
+    }
+
+    #[test]
+    fn too_much_stuff() {
+        let max = usize::MAX;
+        let canvas = Canvas::from_str(&format!(
+            "
+ ( {max} ( 1 ) 2 )
+(   1   (   )   )
+"
+        ));
+        assert_eq!(parse(&canvas).unwrap_err().msg(), "that's a lot of stuff");
+    }
+}
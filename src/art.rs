@@ -0,0 +1,104 @@
+//! Serializing analog literals as... analog literals.
+//!
+//! With the `serde` feature enabled, every analog literal implements
+//! `Serialize` and `Deserialize` the boring way (i.e: as a bunch of numbers).
+//! That's great for machines, but it does mean that the lovingly drawn
+//! rectangle in the source code turns into a `{"w":3,"h":1}` the moment it
+//! hits disk.
+//!
+//! This module fixes that. Slap it on a field using `#[serde(with = ...)]`, and
+//! the field gets serialized as the drawing itself, and deserialized by parsing
+//! the drawing back out again (using the exact same rules as the
+//! [`parse`](crate::parse) module, comments and all).
+//!
+//! Works with [`Line`](crate::Line), [`Rectangle`](crate::Rectangle),
+//! [`Cuboid`](crate::Cuboid), and [`Shape`](crate::parse::Shape).
+//!
+//! # Example
+//!
+//! ```rust
+//! use analog_literals::{analog_literal, Rectangle};
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "analog_literals::art")]
+//!     window: Rectangle,
+//!     margin: Rectangle,
+//! }
+//!
+//! let config = Config {
+//!     window: analog_literal! {
+//!         +------+
+//!         |      |
+//!         +------+
+//!     },
+//!     margin: analog_literal! {
+//!         +--+
+//!         |  |
+//!         +--+
+//!     },
+//! };
+//!
+//! let json = serde_json::to_string(&config).unwrap();
+//! assert_eq!(
+//!     json,
+//!     r#"{"window":"+------+\n|      |\n+------+","margin":{"w":1,"h":1}}"#
+//! );
+//!
+//! let config: Config = serde_json::from_str(&json).unwrap();
+//! assert_eq!(config.window.area(), 3);
+//! ```
+
+use core::fmt;
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+use serde::de::{self, Deserializer, Visitor};
+use serde::ser::Serializer;
+
+use crate::parse::{ParseError, Shape};
+use crate::{Cuboid, Line, Rectangle};
+
+/// Analog literals which can be drawn, and then parsed back out again.
+///
+/// Implemented for [`Line`], [`Rectangle`], [`Cuboid`], and [`Shape`].
+pub trait Art: fmt::Display + FromStr<Err = ParseError> + sealed::Sealed {}
+
+impl Art for Line {}
+impl Art for Rectangle {}
+impl Art for Cuboid {}
+impl Art for Shape {}
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for super::Line {}
+    impl Sealed for super::Rectangle {}
+    impl Sealed for super::Cuboid {}
+    impl Sealed for super::Shape {}
+}
+
+/// Serialize an analog literal as its (ASCII) drawing.
+pub fn serialize<T: Art, S: Serializer>(shape: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(shape)
+}
+
+/// Deserialize an analog literal by parsing its drawing.
+pub fn deserialize<'de, T: Art, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    struct ArtVisitor<T>(PhantomData<T>);
+
+    impl<T: Art> Visitor<'_> for ArtVisitor<T> {
+        type Value = T;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("an analog literal")
+        }
+
+        fn visit_str<E: de::Error>(self, s: &str) -> Result<T, E> {
+            s.parse().map_err(E::custom)
+        }
+    }
+
+    deserializer.deserialize_str(ArtVisitor(PhantomData))
+}
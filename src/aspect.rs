@@ -0,0 +1,110 @@
+//! Aspect ratios, for when only the shape of a rectangle matters (and not how
+//! big it is).
+//!
+//! Get one out of any [`Rectangle`](crate::Rectangle) using
+//! [`Rectangle::aspect`](crate::Rectangle::aspect).
+
+use core::hash::{Hash, Hasher};
+
+/// A `w:h` aspect ratio.
+///
+/// Two aspect ratios are equal if they describe the same shape, so `16:9` and
+/// `32:18` are one and the same. Use [`AspectRatio::reduced`] to get at the
+/// simplest way of writing a ratio.
+///
+/// # Example
+///
+/// ```rust
+/// # use analog_literals::{analog_literal, aspect::AspectRatio};
+/// let widescreen = analog_literal! {
+///     +--------------------------------+
+///     |                                |
+///     |                                |
+///     |                                |
+///     |                                |
+///     |                                |
+///     |                                |
+///     |                                |
+///     |                                |
+///     |                                |
+///     +--------------------------------+
+/// };
+///
+/// assert_eq!(widescreen.aspect(), AspectRatio::new(16, 9));
+/// assert_eq!(widescreen.scaled(2).aspect(), AspectRatio::new(16, 9));
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct AspectRatio {
+    /// The `w` in `w:h`
+    pub w: usize,
+    /// The `h` in `w:h`
+    pub h: usize,
+}
+
+const fn gcd(mut a: usize, mut b: usize) -> usize {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+impl AspectRatio {
+    /// Create a new `w:h` aspect ratio.
+    pub const fn new(w: usize, h: usize) -> AspectRatio {
+        AspectRatio { w, h }
+    }
+
+    /// Return the aspect ratio in lowest terms (e.g: `32:18` becomes `16:9`).
+    ///
+    /// A `0:0` aspect ratio doesn't have any lowest terms to speak of, so it's
+    /// returned as-is.
+    ///
+    /// ```rust
+    /// # use analog_literals::aspect::AspectRatio;
+    /// const WIDESCREEN: AspectRatio = AspectRatio::new(32, 18).reduced();
+    ///
+    /// assert_eq!((WIDESCREEN.w, WIDESCREEN.h), (16, 9));
+    /// assert_eq!(AspectRatio::new(0, 5).reduced().h, 1);
+    /// ```
+    pub const fn reduced(&self) -> AspectRatio {
+        match gcd(self.w, self.h) {
+            0 => *self,
+            n => AspectRatio {
+                w: self.w / n,
+                h: self.h / n,
+            },
+        }
+    }
+
+    /// Check if two aspect ratios describe the same shape.
+    ///
+    /// Same thing as `self == other`, except it works in a `const` context.
+    pub const fn same_as(&self, other: &AspectRatio) -> bool {
+        let (a, b) = (self.reduced(), other.reduced());
+        a.w == b.w && a.h == b.h
+    }
+}
+
+impl PartialEq for AspectRatio {
+    fn eq(&self, other: &AspectRatio) -> bool {
+        self.same_as(other)
+    }
+}
+
+impl Eq for AspectRatio {}
+
+impl Hash for AspectRatio {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        let AspectRatio { w, h } = self.reduced();
+        w.hash(state);
+        h.hash(state);
+    }
+}
+
+impl core::fmt::Display for AspectRatio {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}:{}", self.w, self.h)
+    }
+}
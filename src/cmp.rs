@@ -0,0 +1,149 @@
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+
+use crate::{Cuboid, Rectangle};
+
+/// A [`Rectangle`] that's compared (and ordered) by its area, and nothing else.
+///
+/// Rectangles don't implement `Ord` themselves, since there's no one true way
+/// to decide whether a tall rectangle is bigger than a wide one. If all that
+/// matters is how much floor space something takes up, this is the way to go.
+///
+/// Note that this means `ByArea(a) == ByArea(b)` whenever `a` and `b` have the
+/// same area, regardless of their actual dimensions.
+///
+/// # Example
+///
+/// ```rust
+/// # use analog_literals::{analog_literal, ByArea, Rectangle};
+/// let mut boxes = [
+///     Rectangle { w: 3, h: 3 },
+///     Rectangle { w: 1, h: 2 },
+///     analog_literal! {
+///         +--------+
+///         |        |
+///         +--------+
+///     },
+/// ];
+/// boxes.sort_by_key(|rect| core::cmp::Reverse(ByArea(*rect)));
+///
+/// assert_eq!(boxes[0], Rectangle { w: 3, h: 3 });
+/// assert_eq!(boxes[1], Rectangle { w: 4, h: 1 });
+/// assert_eq!(boxes[2], Rectangle { w: 1, h: 2 });
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct ByArea(pub Rectangle);
+
+impl PartialEq for ByArea {
+    fn eq(&self, other: &ByArea) -> bool {
+        self.0.area() == other.0.area()
+    }
+}
+
+impl Eq for ByArea {}
+
+impl PartialOrd for ByArea {
+    fn partial_cmp(&self, other: &ByArea) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ByArea {
+    fn cmp(&self, other: &ByArea) -> Ordering {
+        self.0.area().cmp(&other.0.area())
+    }
+}
+
+impl Hash for ByArea {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.area().hash(state)
+    }
+}
+
+/// A [`Cuboid`] that's compared (and ordered) by its volume, and nothing else.
+///
+/// Just like [`ByArea`], except for cuboids. Great for figuring out which
+/// mining rig to shove into the closet first.
+///
+/// # Example
+///
+/// ```rust
+/// # use analog_literals::{ByVolume, Cuboid};
+/// let rigs = [
+///     Cuboid { w: 21, h: 1, l: 16 },
+///     Cuboid { w: 6, h: 6, l: 6 },
+///     Cuboid { w: 10, h: 3, l: 10 },
+/// ];
+///
+/// let biggest = rigs.iter().max_by_key(|rig| ByVolume(**rig)).unwrap();
+/// assert_eq!(biggest.volume(), 336);
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct ByVolume(pub Cuboid);
+
+impl PartialEq for ByVolume {
+    fn eq(&self, other: &ByVolume) -> bool {
+        self.0.volume() == other.0.volume()
+    }
+}
+
+impl Eq for ByVolume {}
+
+impl PartialOrd for ByVolume {
+    fn partial_cmp(&self, other: &ByVolume) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ByVolume {
+    fn cmp(&self, other: &ByVolume) -> Ordering {
+        self.0.volume().cmp(&other.0.volume())
+    }
+}
+
+impl Hash for ByVolume {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.volume().hash(state)
+    }
+}
+
+/// Return whichever rectangle has the larger area, or `b` if they're tied
+/// (just like [`core::cmp::max`]).
+///
+/// Unlike going through [`ByArea`], this works in `const` contexts too.
+///
+/// ```rust
+/// # use analog_literals::{max_by_area, Rectangle};
+/// const WINDOW: Rectangle = Rectangle { w: 4, h: 2 };
+/// const DOOR: Rectangle = Rectangle { w: 2, h: 5 };
+/// const BIGGER: Rectangle = max_by_area(WINDOW, DOOR);
+///
+/// assert_eq!(BIGGER, DOOR);
+/// ```
+pub const fn max_by_area(a: Rectangle, b: Rectangle) -> Rectangle {
+    if a.area() > b.area() {
+        a
+    } else {
+        b
+    }
+}
+
+/// Return whichever cuboid has the larger volume, or `b` if they're tied (just
+/// like [`core::cmp::max`]).
+///
+/// Unlike going through [`ByVolume`], this works in `const` contexts too.
+///
+/// ```rust
+/// # use analog_literals::{max_by_volume, Cuboid};
+/// const FRIDGE: Cuboid = Cuboid { w: 3, h: 9, l: 3 };
+/// const FREEZER: Cuboid = Cuboid { w: 6, h: 3, l: 4 };
+///
+/// assert_eq!(max_by_volume(FRIDGE, FREEZER), FRIDGE);
+/// ```
+pub const fn max_by_volume(a: Cuboid, b: Cuboid) -> Cuboid {
+    if a.volume() > b.volume() {
+        a
+    } else {
+        b
+    }
+}
@@ -0,0 +1,201 @@
+//! A gallery of real-world shapes, drawn to scale.
+//!
+//! Besides being genuinely handy, these make for a decent showcase of what
+//! analog literals look like out in the wild. Since every `--` and `|` is a
+//! whole number of _something_, each constant notes what that something is
+//! (i.e: its scale). Use [`Rectangle::scaled`] or [`Cuboid::scaled`] to get
+//! back to real-world units.
+//!
+//! # Example
+//!
+//! ```rust
+//! use analog_literals::consts::{FULL_HD, LARGE_MOVING_BOX, LETTER};
+//! use analog_literals::{Cuboid, Rectangle};
+//!
+//! // one `--` per 120 pixels
+//! assert_eq!(FULL_HD.scaled(120), Rectangle { w: 1920, h: 1080 });
+//! // ...which makes 4K just twice as big
+//! assert_eq!(FULL_HD.scaled(2 * 120), Rectangle { w: 3840, h: 2160 });
+//!
+//! // one `--` per half inch
+//! assert_eq!(LETTER.w as f32 / 2.0, 8.5);
+//!
+//! // one `--` per two inches, so a whopping 7776 cubic inches of storage
+//! assert_eq!(LARGE_MOVING_BOX.scaled(2).volume(), 18 * 18 * 24);
+//! ```
+
+use crate::{analog_literal, Cuboid, Rectangle};
+
+//============================================================================//
+//                                   Paper                                    //
+//============================================================================//
+
+/// A sheet of A4 paper (210mm x 297mm), at roughly 17.5mm per `--`.
+///
+/// Every ISO 216 paper size has an aspect ratio of 1:√2, which is famously
+/// irrational, and therefore famously hard to draw. 12:17 is within 0.2% of it,
+/// which is close enough for government work.
+pub const A4: Rectangle = analog_literal! {
+    +------------------------+
+    |                        |
+    |                        |
+    |                        |
+    |                        |
+    |                        |
+    |                        |
+    |                        |
+    |                        |
+    |                        |
+    |                        |
+    |                        |
+    |                        |
+    |                        |
+    |                        |
+    |                        |
+    |                        |
+    |                        |
+    +------------------------+
+};
+
+/// A sheet of US Letter paper (8.5in x 11in), at one `--` per half inch.
+pub const LETTER: Rectangle = analog_literal! {
+    +----------------------------------+
+    |                                  |
+    |                                  |
+    |                                  |
+    |                                  |
+    |                                  |
+    |                                  |
+    |                                  |
+    |                                  |
+    |                                  |
+    |                                  |
+    |                                  |
+    |                                  |
+    |                                  |
+    |                                  |
+    |                                  |
+    |                                  |
+    |                                  |
+    |                                  |
+    |                                  |
+    |                                  |
+    |                                  |
+    |                                  |
+    +----------------------------------+
+};
+
+//============================================================================//
+//                                  Screens                                   //
+//============================================================================//
+
+/// A VGA screen (640px x 480px), at one `--` per 160 pixels.
+pub const VGA: Rectangle = analog_literal! {
+    +--------+
+    |        |
+    |        |
+    |        |
+    +--------+
+};
+
+/// A Full HD screen (1920px x 1080px), at one `--` per 120 pixels.
+///
+/// Scale it up by 2 for a 4K UHD screen (3840px x 2160px).
+pub const FULL_HD: Rectangle = analog_literal! {
+    +--------------------------------+
+    |                                |
+    |                                |
+    |                                |
+    |                                |
+    |                                |
+    |                                |
+    |                                |
+    |                                |
+    |                                |
+    +--------------------------------+
+};
+
+//============================================================================//
+//                                  Shipping                                  //
+//============================================================================//
+
+/// A small moving box (16in x 12in x 12in), at one `--` per two inches.
+pub const SMALL_MOVING_BOX: Cuboid = analog_literal! {
+           +----------------+
+          /                /|
+         /                / |
+        /                /  |
+       /                /   |
+      /                /    |
+     /                /     |
+    +----------------+      +
+    |                |     /
+    |                |    /
+    |                |   /
+    |                |  /
+    |                | /
+    |                |/
+    +----------------+
+};
+
+/// A medium moving box (18in x 18in x 16in), at one `--` per two inches.
+pub const MEDIUM_MOVING_BOX: Cuboid = analog_literal! {
+              +------------------+
+             /                  /|
+            /                  / |
+           /                  /  |
+          /                  /   |
+         /                  /    |
+        /                  /     |
+       /                  /      |
+      /                  /       |
+     /                  /        +
+    +------------------+        /
+    |                  |       /
+    |                  |      /
+    |                  |     /
+    |                  |    /
+    |                  |   /
+    |                  |  /
+    |                  | /
+    |                  |/
+    +------------------+
+};
+
+/// A large moving box (18in x 18in x 24in), at one `--` per two inches.
+pub const LARGE_MOVING_BOX: Cuboid = analog_literal! {
+              +------------------+
+             /                  /|
+            /                  / |
+           /                  /  |
+          /                  /   |
+         /                  /    |
+        /                  /     |
+       /                  /      |
+      /                  /       |
+     /                  /        |
+    +------------------+         |
+    |                  |         |
+    |                  |         |
+    |                  |         +
+    |                  |        /
+    |                  |       /
+    |                  |      /
+    |                  |     /
+    |                  |    /
+    |                  |   /
+    |                  |  /
+    |                  | /
+    |                  |/
+    +------------------+
+};
+
+/// The footprint of a EUR-pallet (1200mm x 800mm), at one `--` per 200mm.
+pub const EURO_PALLET: Rectangle = analog_literal! {
+    +------------+
+    |            |
+    |            |
+    |            |
+    |            |
+    +------------+
+};
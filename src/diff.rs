@@ -0,0 +1,70 @@
+//! Pretty-printing the differences between two analog literals, for
+//! `assert_analog_eq!`.
+
+use core::fmt;
+
+use crate::parse::Shape;
+use crate::render;
+
+/// A pair of analog literals that may (or may not) be the same.
+pub struct Diff {
+    left: Shape,
+    right: Shape,
+}
+
+impl Diff {
+    /// Line up a pair of analog literals for comparison.
+    pub fn new(left: impl Into<Shape>, right: impl Into<Shape>) -> Diff {
+        Diff {
+            left: left.into(),
+            right: right.into(),
+        }
+    }
+
+    /// Check if the two analog literals are the same.
+    pub fn is_eq(&self) -> bool {
+        self.left == self.right
+    }
+}
+
+/// The shape itself, minus the [`Shape`] wrapped around it (nobody needs to be
+/// told that their `Rectangle` is a `Rectangle(Rectangle { .. })`).
+fn unwrapped(shape: &Shape) -> &dyn fmt::Debug {
+    match shape {
+        Shape::Line(line) => line,
+        Shape::Rectangle(rect) => rect,
+        Shape::Cuboid(cuboid) => cuboid,
+    }
+}
+
+/// Point out a single mismatched dimension.
+fn dim(f: &mut fmt::Formatter<'_>, name: &str, a: usize, b: usize) -> fmt::Result {
+    if a != b {
+        write!(f, " {}: {} != {}", name, a, b)?;
+    }
+    Ok(())
+}
+
+impl fmt::Display for Diff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "assertion `left == right` failed")?;
+        writeln!(f, "  left: {:?}", unwrapped(&self.left))?;
+        writeln!(f, " right: {:?}", unwrapped(&self.right))?;
+        write!(f, "  diff:")?;
+        match (self.left, self.right) {
+            (Shape::Line(a), Shape::Line(b)) => dim(f, "len", a.0, b.0)?,
+            (Shape::Rectangle(a), Shape::Rectangle(b)) => {
+                dim(f, "w", a.w, b.w)?;
+                dim(f, "h", a.h, b.h)?;
+            }
+            (Shape::Cuboid(a), Shape::Cuboid(b)) => {
+                dim(f, "w", a.w, b.w)?;
+                dim(f, "h", a.h, b.h)?;
+                dim(f, "l", a.l, b.l)?;
+            }
+            _ => write!(f, " not even the same kind of shape")?,
+        }
+        write!(f, "\n\n")?;
+        render::side_by_side(f, &self.left, &self.right)
+    }
+}
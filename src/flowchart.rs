@@ -0,0 +1,79 @@
+//! Flowcharts, drawn right in the source code.
+//!
+//! README flowcharts have a nasty habit of describing what the code did three
+//! refactors ago. The types in this module let you draw the flowchart once,
+//! and then actually _use_ it, which should make it slightly more embarrassing
+//! to let it rot.
+//!
+//! Use the [`analog_flowchart!`](crate::analog_flowchart) macro to construct
+//! them.
+
+/// The different kinds of boxes that can appear in a [`Flowchart`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum NodeKind {
+    /// A regular old rectangle, where stuff gets done.
+    ///
+    /// ```text
+    /// +-----------+
+    /// | do things |
+    /// +-----------+
+    /// ```
+    Process,
+    /// A box with pointy sides, where choices get made.
+    ///
+    /// Traditionally, this would be a diamond, but seeing as `\` isn't a valid
+    /// Rust token, pointy sides will have to do.
+    ///
+    /// ```text
+    /// +-----------+
+    /// < is it on? >
+    /// +-----------+
+    /// ```
+    Decision,
+}
+
+/// A box in a [`Flowchart`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Node {
+    /// What sort of box this is
+    pub kind: NodeKind,
+    /// The text written inside the box, with any runs of whitespace (including
+    /// line breaks) collapsed down to a single space
+    pub label: &'static str,
+}
+
+/// An arrow in a [`Flowchart`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Edge {
+    /// Index of the node the arrow starts at
+    pub from: usize,
+    /// Index of the node the arrow points at
+    pub to: usize,
+    /// A word written along the arrow (e.g: `---yes--->`), if any
+    pub label: Option<&'static str>,
+}
+
+/// A flowchart with `N` nodes and `E` edges.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Flowchart<const N: usize, const E: usize> {
+    /// Every box in the flowchart, in reading order (top to bottom, left to
+    /// right) of their top-left corners
+    pub nodes: [Node; N],
+    /// Every arrow in the flowchart, in reading order of their arrowheads
+    pub edges: [Edge; E],
+}
+
+impl<const N: usize, const E: usize> Flowchart<N, E> {
+    /// Iterate over all the arrows leaving the given node.
+    pub fn edges_from(&self, node: usize) -> impl Iterator<Item = &Edge> {
+        self.edges.iter().filter(move |edge| edge.from == node)
+    }
+
+    /// Follow the arrow leaving the given node (with the given label, if any),
+    /// returning the index of the node it points at.
+    pub fn next(&self, node: usize, label: Option<&str>) -> Option<usize> {
+        self.edges_from(node)
+            .find(|edge| edge.label == label)
+            .map(|edge| edge.to)
+    }
+}
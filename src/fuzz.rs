@@ -0,0 +1,282 @@
+//! Conjuring up random analog literals, for fuzzing and property testing.
+//!
+//! With the `arbitrary` feature enabled, [`Line`](crate::Line),
+//! [`Rectangle`](crate::Rectangle), and [`Cuboid`](crate::Cuboid) implement
+//! `arbitrary::Arbitrary`. With the `proptest` feature enabled, they implement
+//! `proptest::arbitrary::Arbitrary` too. And with the `rand` feature enabled,
+//! they can be sampled from [`Bounds`] (or `rand`'s `StandardUniform`), since
+//! it implements `rand::distr::Distribution` for each of them.
+//!
+//! Either way, the dimensions of each literal are kept within some [`Bounds`].
+//! Left to its own devices, a fuzzer would happily cook up a rectangle that's
+//! `usize::MAX` `--` wide, which isn't something anyone has ever drawn (or
+//! should ever try to).
+//!
+//! # Example
+//!
+//! Using `proptest`, the bounds are passed as the strategy's parameters:
+//!
+//! ```rust
+//! # #[cfg(feature = "proptest")]
+//! # {
+//! use analog_literals::fuzz::Bounds;
+//! use analog_literals::Rectangle;
+//! use proptest::prelude::*;
+//!
+//! proptest!(|(rect in any_with::<Rectangle>(Bounds::new(1, 10)))| {
+//!     prop_assert!((1..=100).contains(&rect.area()));
+//! });
+//! # }
+//! ```
+//!
+//! Using `arbitrary`, the bounds can be used to generate literals directly:
+//!
+//! ```rust
+//! # #[cfg(feature = "arbitrary")]
+//! # {
+//! use analog_literals::fuzz::Bounds;
+//! use arbitrary::Unstructured;
+//!
+//! let mut u = Unstructured::new(&[1, 2, 3, 4, 5, 6]);
+//! let cube = Bounds::new(2, 4).cuboid(&mut u).unwrap();
+//! assert!((8..=64).contains(&cube.volume()));
+//! # }
+//! ```
+//!
+//! Using `rand`, the bounds are a distribution like any other:
+//!
+//! ```rust
+//! # #[cfg(feature = "rand")]
+//! # {
+//! use analog_literals::fuzz::Bounds;
+//! use analog_literals::{how_many_fit, Cuboid};
+//! use rand::distr::Distribution;
+//! use rand::rngs::SmallRng;
+//! use rand::SeedableRng;
+//!
+//! let mut rng = SmallRng::seed_from_u64(0xb0c5);
+//! let truck = Cuboid { w: 40, h: 40, l: 40 };
+//!
+//! for parcel in Bounds::new(1, 8).sample_iter(&mut rng).take(100) {
+//!     let parcel: Cuboid = parcel;
+//!     assert!(how_many_fit(&parcel, &truck) >= 125);
+//! }
+//! # }
+//! ```
+
+use core::ops::RangeInclusive;
+
+/// The (inclusive) range that each dimension of a randomly generated analog
+/// literal falls into.
+///
+/// Always has `min <= max`, so the range is never empty.
+#[derive(Debug, Copy, Clone)]
+pub struct Bounds {
+    min: usize,
+    max: usize,
+}
+
+impl Bounds {
+    /// The bounds used when none are specified: anything from nothing at all,
+    /// up to a respectable `32`.
+    pub const DEFAULT: Bounds = Bounds { min: 0, max: 32 };
+
+    /// Create a new set of bounds.
+    ///
+    /// Panics if `min > max`.
+    ///
+    /// ```rust
+    /// use analog_literals::fuzz::Bounds;
+    ///
+    /// let bounds = Bounds::new(1, 8);
+    /// assert_eq!((bounds.min(), bounds.max()), (1, 8));
+    /// assert_eq!(bounds.range(), 1..=8);
+    /// ```
+    pub const fn new(min: usize, max: usize) -> Bounds {
+        assert!(min <= max, "min must be <= max");
+        Bounds { min, max }
+    }
+
+    /// Smallest possible dimension.
+    pub const fn min(&self) -> usize {
+        self.min
+    }
+
+    /// Largest possible dimension.
+    pub const fn max(&self) -> usize {
+        self.max
+    }
+
+    /// Return the bounds as a range.
+    pub const fn range(&self) -> RangeInclusive<usize> {
+        self.min..=self.max
+    }
+}
+
+impl Default for Bounds {
+    fn default() -> Bounds {
+        Bounds::DEFAULT
+    }
+}
+
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impls {
+    use arbitrary::{Arbitrary, Result, Unstructured};
+
+    use super::Bounds;
+    use crate::{Cuboid, Line, Rectangle};
+
+    impl Bounds {
+        fn dimension(&self, u: &mut Unstructured<'_>) -> Result<usize> {
+            u.int_in_range(self.range())
+        }
+
+        /// Generate a line within these bounds.
+        ///
+        /// Requires the `arbitrary` feature.
+        pub fn line(&self, u: &mut Unstructured<'_>) -> Result<Line> {
+            Ok(Line(self.dimension(u)?))
+        }
+
+        /// Generate a rectangle within these bounds.
+        ///
+        /// Requires the `arbitrary` feature.
+        pub fn rectangle(&self, u: &mut Unstructured<'_>) -> Result<Rectangle> {
+            Ok(Rectangle {
+                w: self.dimension(u)?,
+                h: self.dimension(u)?,
+            })
+        }
+
+        /// Generate a cuboid within these bounds.
+        ///
+        /// Requires the `arbitrary` feature.
+        pub fn cuboid(&self, u: &mut Unstructured<'_>) -> Result<Cuboid> {
+            Ok(Cuboid {
+                w: self.dimension(u)?,
+                h: self.dimension(u)?,
+                l: self.dimension(u)?,
+            })
+        }
+    }
+
+    /// Uses [`Bounds::DEFAULT`].
+    impl<'a> Arbitrary<'a> for Line {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Bounds::DEFAULT.line(u)
+        }
+    }
+
+    /// Uses [`Bounds::DEFAULT`].
+    impl<'a> Arbitrary<'a> for Rectangle {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Bounds::DEFAULT.rectangle(u)
+        }
+    }
+
+    /// Uses [`Bounds::DEFAULT`].
+    impl<'a> Arbitrary<'a> for Cuboid {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Bounds::DEFAULT.cuboid(u)
+        }
+    }
+}
+
+#[cfg(feature = "proptest")]
+mod proptest_impls {
+    use core::ops::RangeInclusive;
+
+    use proptest::arbitrary::Arbitrary;
+    use proptest::strategy::{Map, Strategy};
+
+    use super::Bounds;
+    use crate::{Cuboid, Line, Rectangle};
+
+    type Dimension = RangeInclusive<usize>;
+
+    /// Parameterized by [`Bounds`] (i.e: `any_with::<Line>(bounds)`).
+    impl Arbitrary for Line {
+        type Parameters = Bounds;
+        type Strategy = Map<Dimension, fn(usize) -> Line>;
+
+        fn arbitrary_with(bounds: Bounds) -> Self::Strategy {
+            bounds.range().prop_map(Line as fn(_) -> _)
+        }
+    }
+
+    /// Parameterized by [`Bounds`] (i.e: `any_with::<Rectangle>(bounds)`).
+    impl Arbitrary for Rectangle {
+        type Parameters = Bounds;
+        type Strategy = Map<(Dimension, Dimension), fn((usize, usize)) -> Rectangle>;
+
+        fn arbitrary_with(bounds: Bounds) -> Self::Strategy {
+            (bounds.range(), bounds.range()).prop_map((|(w, h)| Rectangle { w, h }) as fn(_) -> _)
+        }
+    }
+
+    /// Parameterized by [`Bounds`] (i.e: `any_with::<Cuboid>(bounds)`).
+    impl Arbitrary for Cuboid {
+        type Parameters = Bounds;
+        type Strategy = Map<(Dimension, Dimension, Dimension), fn((usize, usize, usize)) -> Cuboid>;
+
+        fn arbitrary_with(bounds: Bounds) -> Self::Strategy {
+            (bounds.range(), bounds.range(), bounds.range())
+                .prop_map((|(w, h, l)| Cuboid { w, h, l }) as fn(_) -> _)
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+mod rand_impls {
+    use rand::distr::{Distribution, StandardUniform};
+    use rand::{Rng, RngExt};
+
+    use super::Bounds;
+    use crate::{Cuboid, Line, Rectangle};
+
+    impl Distribution<Line> for Bounds {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Line {
+            Line(rng.random_range(self.range()))
+        }
+    }
+
+    impl Distribution<Rectangle> for Bounds {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Rectangle {
+            Rectangle {
+                w: rng.random_range(self.range()),
+                h: rng.random_range(self.range()),
+            }
+        }
+    }
+
+    impl Distribution<Cuboid> for Bounds {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Cuboid {
+            Cuboid {
+                w: rng.random_range(self.range()),
+                h: rng.random_range(self.range()),
+                l: rng.random_range(self.range()),
+            }
+        }
+    }
+
+    /// Uses [`Bounds::DEFAULT`].
+    impl Distribution<Line> for StandardUniform {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Line {
+            Bounds::DEFAULT.sample(rng)
+        }
+    }
+
+    /// Uses [`Bounds::DEFAULT`].
+    impl Distribution<Rectangle> for StandardUniform {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Rectangle {
+            Bounds::DEFAULT.sample(rng)
+        }
+    }
+
+    /// Uses [`Bounds::DEFAULT`].
+    impl Distribution<Cuboid> for StandardUniform {
+        fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Cuboid {
+            Bounds::DEFAULT.sample(rng)
+        }
+    }
+}
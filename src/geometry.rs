@@ -0,0 +1,154 @@
+//! Collision checks and friends for [`PositionedRectangle`]s.
+//!
+//! Once literals know where they were drawn (courtesy of
+//! [`analog_positioned!`](crate::analog_positioned) and
+//! [`analog_scene!`](crate::analog_scene)), the first thing anyone wants to
+//! know is whether they're bumping into one another. This module adds:
+//!
+//! - [`PositionedRectangle::contains_point`]
+//! - [`PositionedRectangle::intersects`]
+//! - [`PositionedRectangle::intersection`]
+//! - [`PositionedRectangle::union_bounds`]
+//!
+//! All of which are `const`, naturally.
+//!
+//! For the purposes of everything in here, a positioned rectangle covers the
+//! `w` by `h` grid of cells whose top-left corner is at `(x, y)`. That is, a
+//! cell `(x, y)` is inside the rectangle if `self.x <= x < self.x + self.w`
+//! (and likewise for `y`), so rectangles that merely touch don't overlap, and
+//! rectangles with no width or height don't cover anything at all. Rectangles
+//! that would run off the end of a `usize` are cut short at `usize::MAX`.
+//!
+//! # Example
+//!
+//! ```rust
+//! use analog_literals::geometry::PositionedRectangle;
+//!
+//! let wall = PositionedRectangle { x: 0, y: 0, w: 4, h: 1 };
+//! let ball = PositionedRectangle { x: 3, y: 0, w: 1, h: 1 };
+//! let goal = PositionedRectangle { x: 4, y: 0, w: 1, h: 1 };
+//!
+//! assert!(wall.contains_point(3, 0));
+//! assert!(wall.intersects(&ball));
+//! assert!(!wall.intersects(&goal)); // touching doesn't count
+//! assert_eq!(wall.union_bounds(&goal).w, 5);
+//! ```
+
+pub use crate::PositionedRectangle;
+
+const fn max(a: usize, b: usize) -> usize {
+    if a > b {
+        a
+    } else {
+        b
+    }
+}
+
+const fn min(a: usize, b: usize) -> usize {
+    if a < b {
+        a
+    } else {
+        b
+    }
+}
+
+impl PositionedRectangle {
+    /// Check if the cell at `(x, y)` is inside the rectangle.
+    pub const fn contains_point(&self, x: usize, y: usize) -> bool {
+        self.x <= x
+            && x < self.x.saturating_add(self.w)
+            && self.y <= y
+            && y < self.y.saturating_add(self.h)
+    }
+
+    /// Check if the two rectangles overlap.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "macros")] {
+    /// # use analog_literals::{analog_scene, PositionedRectangle};
+    /// const ROOM: [PositionedRectangle; 3] = analog_scene! {
+    ///     +------------------+
+    ///     |                  |    +--+
+    ///     |                  |    |  |
+    ///     |                  |    +--+
+    ///     +------------------+
+    ///
+    ///             +------+
+    ///             |      |
+    ///             +------+
+    /// };
+    ///
+    /// let [table, lamp, mat] = ROOM;
+    /// let player = PositionedRectangle { x: 6, y: 2, w: 1, h: 1 };
+    ///
+    /// assert!(player.intersects(&table));
+    /// assert!(!player.intersects(&lamp));
+    /// assert!(!table.intersects(&mat));
+    /// # }
+    /// ```
+    pub const fn intersects(&self, other: &PositionedRectangle) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    /// Return the part of the grid covered by both rectangles, or `None` if
+    /// they don't overlap.
+    ///
+    /// ```rust
+    /// # use analog_literals::PositionedRectangle;
+    /// let a = PositionedRectangle { x: 0, y: 0, w: 4, h: 3 };
+    /// let b = PositionedRectangle { x: 2, y: 1, w: 4, h: 4 };
+    ///
+    /// assert_eq!(a.intersection(&b), Some(PositionedRectangle { x: 2, y: 1, w: 2, h: 2 }));
+    /// ```
+    pub const fn intersection(&self, other: &PositionedRectangle) -> Option<PositionedRectangle> {
+        let x = max(self.x, other.x);
+        let y = max(self.y, other.y);
+        let right = min(
+            self.x.saturating_add(self.w),
+            other.x.saturating_add(other.w),
+        );
+        let bottom = min(
+            self.y.saturating_add(self.h),
+            other.y.saturating_add(other.h),
+        );
+
+        if x < right && y < bottom {
+            Some(PositionedRectangle {
+                x,
+                y,
+                w: right - x,
+                h: bottom - y,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Return the smallest rectangle with both rectangles inside it.
+    ///
+    /// ```rust
+    /// # use analog_literals::PositionedRectangle;
+    /// let a = PositionedRectangle { x: 1, y: 0, w: 2, h: 1 };
+    /// let b = PositionedRectangle { x: 4, y: 3, w: 1, h: 2 };
+    ///
+    /// assert_eq!(a.union_bounds(&b), PositionedRectangle { x: 1, y: 0, w: 4, h: 5 });
+    /// ```
+    pub const fn union_bounds(&self, other: &PositionedRectangle) -> PositionedRectangle {
+        let x = min(self.x, other.x);
+        let y = min(self.y, other.y);
+        PositionedRectangle {
+            x,
+            y,
+            w: max(
+                self.x.saturating_add(self.w),
+                other.x.saturating_add(other.w),
+            ) - x,
+            h: max(
+                self.y.saturating_add(self.h),
+                other.y.saturating_add(other.h),
+            ) - y,
+        }
+    }
+}
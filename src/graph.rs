@@ -0,0 +1,34 @@
+//! Box-and-arrow diagrams, drawn right in the source code.
+//!
+//! Architecture docs are full of dependency diagrams, and every single one of
+//! them is out of date. The [`Graph`] type in this module lets the compiler
+//! have a look at those diagrams too, so that an arrow pointing at nothing in
+//! particular is a compile error instead of a code review comment.
+//!
+//! Use the [`analog_graph!`](crate::analog_graph) macro to construct them.
+
+/// A directed graph with `N` nodes and `E` edges.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Graph<const N: usize, const E: usize> {
+    /// The text written inside each box, in reading order (top to bottom,
+    /// left to right) of their top-left corners
+    pub nodes: [&'static str; N],
+    /// `(from, to)` pairs of node indices, one for each arrow, in reading order
+    /// of their arrowheads
+    pub edges: [(usize, usize); E],
+}
+
+impl<const N: usize, const E: usize> Graph<N, E> {
+    /// Return the index of the node with the given label.
+    pub fn find(&self, label: &str) -> Option<usize> {
+        self.nodes.iter().position(|node| *node == label)
+    }
+
+    /// Iterate over the indices of every node the given node points at.
+    pub fn successors(&self, node: usize) -> impl Iterator<Item = usize> + '_ {
+        self.edges
+            .iter()
+            .filter(move |(from, _)| *from == node)
+            .map(|(_, to)| *to)
+    }
+}
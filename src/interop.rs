@@ -0,0 +1,13 @@
+//! Conversions to and from other crates' types, each behind its own feature.
+//!
+//! Nothing in here is public - it's all trait impls (and the odd inherent
+//! method).
+
+#[cfg(feature = "defmt")]
+mod defmt;
+#[cfg(feature = "glam")]
+mod glam;
+#[cfg(feature = "mint")]
+mod mint;
+#[cfg(feature = "taffy")]
+mod taffy;
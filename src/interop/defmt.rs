@@ -0,0 +1,62 @@
+use ::defmt::{write, Format, Formatter};
+
+use crate::parse::Shape;
+use crate::{Cuboid, LabeledRectangle, Line, PositionedRectangle, Rectangle};
+
+/// Logged as `{w=..}`.
+impl Format for Line {
+    fn format(&self, f: Formatter<'_>) {
+        write!(f, "{{w={=usize}}}", self.0)
+    }
+}
+
+/// Logged as `{w=..,h=..}`.
+impl Format for Rectangle {
+    fn format(&self, f: Formatter<'_>) {
+        write!(f, "{{w={=usize},h={=usize}}}", self.w, self.h)
+    }
+}
+
+/// Logged as `{w=..,h=..,l=..}`.
+impl Format for Cuboid {
+    fn format(&self, f: Formatter<'_>) {
+        write!(
+            f,
+            "{{w={=usize},h={=usize},l={=usize}}}",
+            self.w, self.h, self.l
+        )
+    }
+}
+
+/// Logged exactly like the shape inside.
+impl Format for Shape {
+    fn format(&self, f: Formatter<'_>) {
+        match self {
+            Shape::Line(line) => line.format(f),
+            Shape::Rectangle(rect) => rect.format(f),
+            Shape::Cuboid(cuboid) => cuboid.format(f),
+        }
+    }
+}
+
+/// Logged as `{x=..,y=..,w=..,h=..}`.
+impl Format for PositionedRectangle {
+    fn format(&self, f: Formatter<'_>) {
+        write!(
+            f,
+            "{{x={=usize},y={=usize},w={=usize},h={=usize}}}",
+            self.x, self.y, self.w, self.h
+        )
+    }
+}
+
+/// Logged as `{w=..,h=..,label=..}`.
+impl Format for LabeledRectangle {
+    fn format(&self, f: Formatter<'_>) {
+        write!(
+            f,
+            "{{w={=usize},h={=usize},label={=str}}}",
+            self.rect.w, self.rect.h, self.label
+        )
+    }
+}
@@ -0,0 +1,103 @@
+use core::convert::TryFrom;
+use core::num::TryFromIntError;
+
+use ::glam::{UVec2, UVec3, Vec2, Vec3};
+
+use crate::{Cuboid, Rectangle};
+
+/// Fails if either dimension doesn't fit in a `u32` (which would make for one
+/// heck of a drawing).
+///
+/// ```rust
+/// # use analog_literals::analog_literal;
+/// # use core::convert::TryFrom;
+/// use glam::UVec2;
+///
+/// let hitbox = UVec2::try_from(analog_literal! {
+///     +------+
+///     |      |
+///     +------+
+/// })
+/// .unwrap();
+/// assert_eq!(hitbox, UVec2::new(3, 1));
+/// ```
+impl TryFrom<Rectangle> for UVec2 {
+    type Error = TryFromIntError;
+
+    fn try_from(rect: Rectangle) -> Result<Self, Self::Error> {
+        Ok(UVec2::new(u32::try_from(rect.w)?, u32::try_from(rect.h)?))
+    }
+}
+
+/// Fails if any dimension doesn't fit in a `u32`.
+///
+/// ```rust
+/// # use analog_literals::analog_literal;
+/// # use core::convert::TryFrom;
+/// use glam::UVec3;
+///
+/// let hitbox = UVec3::try_from(analog_literal! {
+///       +------+
+///      /      /|
+///     +------+ |
+///     |      | +
+///     |      |/
+///     +------+
+/// })
+/// .unwrap();
+/// assert_eq!(hitbox, UVec3::new(3, 2, 1));
+/// ```
+impl TryFrom<Cuboid> for UVec3 {
+    type Error = TryFromIntError;
+
+    fn try_from(cuboid: Cuboid) -> Result<Self, Self::Error> {
+        Ok(UVec3::new(
+            u32::try_from(cuboid.w)?,
+            u32::try_from(cuboid.h)?,
+            u32::try_from(cuboid.l)?,
+        ))
+    }
+}
+
+impl Rectangle {
+    /// Convert the rectangle into a `glam::Vec2`, using `as` casts (so
+    /// unreasonably large rectangles lose a bit of precision).
+    ///
+    /// Requires the `glam` feature.
+    ///
+    /// ```rust
+    /// # use analog_literals::analog_literal;
+    /// let rect = analog_literal! {
+    ///     +------+
+    ///     |      |
+    ///     +------+
+    /// };
+    /// assert_eq!(rect.as_vec2(), glam::Vec2::new(3.0, 1.0));
+    /// ```
+    pub fn as_vec2(&self) -> Vec2 {
+        Vec2::new(self.w as f32, self.h as f32)
+    }
+}
+
+impl Cuboid {
+    /// Convert the cuboid into a `glam::Vec3`, using `as` casts (so
+    /// unreasonably large cuboids lose a bit of precision).
+    ///
+    /// Requires the `glam` feature.
+    ///
+    /// ```rust
+    /// # use analog_literals::analog_literal;
+    /// let cube = analog_literal! {
+    ///       +------+
+    ///      /      /|
+    ///     +------+ |
+    ///     |      | +
+    ///     |      |/
+    ///     +------+
+    /// };
+    /// assert_eq!(cube.as_vec3(), glam::Vec3::new(3.0, 2.0, 1.0));
+    /// ```
+    pub fn as_vec3(&self) -> Vec3 {
+        Vec3::new(self.w as f32, self.h as f32, self.l as f32)
+    }
+}
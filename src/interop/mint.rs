@@ -0,0 +1,78 @@
+use crate::{Cuboid, Rectangle};
+
+/// ```rust
+/// # use analog_literals::analog_literal;
+/// let v: mint::Vector2<usize> = analog_literal! {
+///     +------+
+///     |      |
+///     +------+
+/// }
+/// .into();
+/// assert_eq!((v.x, v.y), (3, 1));
+/// ```
+impl From<Rectangle> for ::mint::Vector2<usize> {
+    fn from(rect: Rectangle) -> Self {
+        ::mint::Vector2 {
+            x: rect.w,
+            y: rect.h,
+        }
+    }
+}
+
+/// Any vector makes for a perfectly good rectangle, so this never fails (which
+/// means `TryFrom` works too, if that's what the surrounding code expects).
+///
+/// ```rust
+/// # use analog_literals::Rectangle;
+/// # use core::convert::TryFrom;
+/// let rect = Rectangle::from(mint::Vector2 { x: 3, y: 1 });
+/// assert_eq!(rect.area(), 3);
+///
+/// let rect = Rectangle::try_from(mint::Vector2 { x: 2, y: 2 }).unwrap();
+/// assert_eq!(rect.area(), 4);
+/// ```
+impl From<::mint::Vector2<usize>> for Rectangle {
+    fn from(v: ::mint::Vector2<usize>) -> Self {
+        Rectangle { w: v.x, h: v.y }
+    }
+}
+
+/// ```rust
+/// # use analog_literals::analog_literal;
+/// let v: mint::Vector3<usize> = analog_literal! {
+///       +------+
+///      /      /|
+///     +------+ |
+///     |      | +
+///     |      |/
+///     +------+
+/// }
+/// .into();
+/// assert_eq!((v.x, v.y, v.z), (3, 2, 1));
+/// ```
+impl From<Cuboid> for ::mint::Vector3<usize> {
+    fn from(cuboid: Cuboid) -> Self {
+        ::mint::Vector3 {
+            x: cuboid.w,
+            y: cuboid.h,
+            z: cuboid.l,
+        }
+    }
+}
+
+/// Just like with [`Rectangle`], this one never fails either.
+///
+/// ```rust
+/// # use analog_literals::Cuboid;
+/// let cube = Cuboid::from(mint::Vector3 { x: 3, y: 2, z: 1 });
+/// assert_eq!(cube.volume(), 6);
+/// ```
+impl From<::mint::Vector3<usize>> for Cuboid {
+    fn from(v: ::mint::Vector3<usize>) -> Self {
+        Cuboid {
+            w: v.x,
+            h: v.y,
+            l: v.z,
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use ::taffy::geometry::Size;
+
+use crate::Rectangle;
+
+impl Rectangle {
+    /// Convert the rectangle into a `taffy::geometry::Size`, turning each
+    /// dimension into whatever sort of length the given function produces.
+    ///
+    /// Taffy has no idea how wide a `--` is supposed to be, so that's up to
+    /// the caller: pass `Dimension::length` to treat each `--` as a point, or
+    /// something like `|n| Dimension::percent(n / 10.0)` to treat it as 10% of
+    /// the parent. Any other `f32` -> length function (e.g:
+    /// `LengthPercentage::length`) works too.
+    ///
+    /// Requires the `taffy` feature.
+    ///
+    /// ```rust
+    /// # use analog_literals::analog_literal;
+    /// use taffy::style::Dimension;
+    ///
+    /// let sidebar = analog_literal! {
+    ///     +------+
+    ///     |      |
+    ///     |      |
+    ///     +------+
+    /// };
+    ///
+    /// let size = sidebar.to_taffy_size(Dimension::length);
+    /// assert_eq!(size.width, Dimension::length(3.0));
+    /// assert_eq!(size.height, Dimension::length(2.0));
+    ///
+    /// let size = sidebar.to_taffy_size(|n| Dimension::percent(n / 10.0));
+    /// assert_eq!(size.width, Dimension::percent(0.3));
+    /// assert_eq!(size.height, Dimension::percent(0.2));
+    /// ```
+    pub fn to_taffy_size<T>(&self, dimension: impl Fn(f32) -> T) -> Size<T> {
+        Size {
+            width: dimension(self.w as f32),
+            height: dimension(self.h as f32),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+//! Intervals, drawn on a shared ruler right in the source code.
+//!
+//! Use the [`analog_intervals!`](crate::analog_intervals) macro to construct
+//! them.
+
+/// A half-open interval `[start, end)`, measured in `--`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Interval {
+    /// Where the interval starts (inclusive)
+    pub start: usize,
+    /// Where the interval ends (exclusive)
+    pub end: usize,
+}
+
+impl Interval {
+    /// Return the length of the interval.
+    pub const fn len(&self) -> usize {
+        self.end.saturating_sub(self.start)
+    }
+
+    /// Check if the interval doesn't cover anything at all (i.e: `[]`).
+    pub const fn is_empty(&self) -> bool {
+        self.start >= self.end
+    }
+
+    /// Return the part of the ruler covered by both intervals, or `None` if
+    /// they don't overlap. Intervals that merely touch don't overlap.
+    pub const fn overlap(&self, other: &Interval) -> Option<Interval> {
+        let start = if self.start > other.start {
+            self.start
+        } else {
+            other.start
+        };
+        let end = if self.end < other.end {
+            self.end
+        } else {
+            other.end
+        };
+
+        if start < end {
+            Some(Interval { start, end })
+        } else {
+            None
+        }
+    }
+
+    /// Check if the two intervals overlap.
+    pub const fn overlaps(&self, other: &Interval) -> bool {
+        self.overlap(other).is_some()
+    }
+
+    /// Return how much space there is between the two intervals, which is `0`
+    /// if they touch or overlap.
+    pub const fn gap(&self, other: &Interval) -> usize {
+        if self.end <= other.start {
+            other.start - self.end
+        } else {
+            self.start.saturating_sub(other.end)
+        }
+    }
+
+    /// Check if `other` lies entirely within the interval.
+    pub const fn contains(&self, other: &Interval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+}
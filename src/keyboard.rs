@@ -0,0 +1,21 @@
+//! Keyboard layouts, drawn right in the source code.
+//!
+//! Keyboard firmware is full of carefully drawn ASCII keyboards, sitting in
+//! comments right above the arrays that _actually_ decide what each key does.
+//! The [`Key`] type in this module cuts out the middle man.
+//!
+//! Use the [`analog_keyboard!`](crate::analog_keyboard) macro to construct
+//! them.
+
+/// A single keycap on a keyboard.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Key {
+    /// Which row the key is on, counting from the top
+    pub row: usize,
+    /// Which key in the row this is, counting from the left
+    pub col: usize,
+    /// The legend printed on the keycap
+    pub label: &'static str,
+    /// How wide the key is, counted in `--`
+    pub width: usize,
+}
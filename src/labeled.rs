@@ -0,0 +1,14 @@
+use crate::Rectangle;
+
+/// A 2D Rectangle with a certain width and height, along with the label
+/// written inside of it.
+///
+/// Produced by the [`analog_labeled!`](crate::analog_labeled) macro.
+#[derive(Debug, Copy, Clone)]
+pub struct LabeledRectangle {
+    /// The rectangle itself
+    pub rect: Rectangle,
+    /// The text of the `/* ... */` comment inside the rectangle, sans the
+    /// comment markers and any surrounding whitespace
+    pub label: &'static str,
+}
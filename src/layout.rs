@@ -0,0 +1,19 @@
+use crate::Rectangle;
+
+/// A [`Rectangle`], along with all the rectangles drawn inside of it.
+///
+/// Produced by the [`analog_layout!`](crate::analog_layout) macro.
+#[derive(Debug, Copy, Clone)]
+pub struct Layout {
+    /// Horizontal offset from the interior of the parent rectangle (counts how
+    /// many `--` of whitespace are to the left of the rectangle)
+    pub x: usize,
+    /// Vertical offset from the interior of the parent rectangle (counts how
+    /// many rows of whitespace are above the rectangle)
+    pub y: usize,
+    /// The rectangle itself
+    pub rect: Rectangle,
+    /// Rectangles drawn directly inside this rectangle, in the order they
+    /// appear in the drawing (top to bottom, left to right)
+    pub children: &'static [Layout],
+}
@@ -10,7 +10,7 @@
 //! - Entirely safe Rust, enforced by `#[deny(unsafe_code)]`!
 //! - Entirely `const` evaluatable - use analog literals in any `const` context!
 //! - `#![no_std]` by default, _without_ relying on `alloc`!
-//! - Dependency Free!
+//! - Dependency Free! (until you opt into one of the fancier features, anyway)
 //! - Open source under a _very_ permissive license!
 //! - Made with love, passion, and a disregard of all things holy!
 //!
@@ -134,6 +134,18 @@
 //! assert_eq!(MINING_RIG.volume(), 336);
 //! ```
 //!
+//! And since everything is `const`, you can even work out how many rigs will fit
+//! into the apartment before the code finishes compiling:
+//!
+//! ```rust
+//! # use analog_literals::{analog_literal, how_many_fit, Cuboid};
+//! # const MINING_RIG: Cuboid = Cuboid { w: 21, h: 1, l: 16 };
+//! const APARTMENT: Cuboid = Cuboid { w: 42, h: 10, l: 32 };
+//! const RIGS_PER_APARTMENT: usize = how_many_fit(&MINING_RIG, &APARTMENT);
+//!
+//! assert_eq!(RIGS_PER_APARTMENT, 40);
+//! ```
+//!
 //! Oh yeah, I can taste those DOGE tendies already!
 //!
 //! # Safety and Syntax Validation
@@ -195,6 +207,45 @@
 //! analog_literals = "*"
 //! ```
 //!
+//! # Feature Flags
+//!
+//! Everything is `#![no_std]` and allocation free out of the box, but some
+//! things just can't be done without a little help:
+//!
+//! - `alloc`: SVG export (via `Rectangle::to_svg` and `Cuboid::to_svg`)
+//! - `std`: [`analog_dbg!`] (implies `alloc`)
+//! - `macros`: every macro that needs to see the drawing as a picture, rather
+//!   than a pile of tokens (e.g: [`analog_layout!`], [`analog_scene!`], and
+//!   [`analog_repr!`]), along with `strict;` and `;;` in [`analog_literal!`].
+//!   These are built on a proc-macro crate of our own, which needs a newer
+//!   Rust (see [below](#minimum-supported-rust-version))
+//! - `derive`: [`#[derive(AnalogDebug)]`](AnalogDebug) (implies `macros`)
+//! - `serde`: `Serialize` and `Deserialize` for every analog literal, plus the
+//!   [`art`] module for serializing them as drawings
+//! - `mint`: conversions between [`Rectangle`]/[`Cuboid`] and
+//!   `mint::Vector2`/`mint::Vector3`
+//! - `glam`: conversions from [`Rectangle`]/[`Cuboid`] into
+//!   `glam::UVec2`/`UVec3`, plus [`Rectangle::as_vec2`] and [`Cuboid::as_vec3`]
+//! - `taffy`: [`Rectangle::to_taffy_size`], for seeding flexbox layouts with
+//!   actual drawings
+//! - `arbitrary`/`proptest`/`rand`: random analog literals for fuzzing,
+//!   property testing, and whatever else needs a random box (see the [`fuzz`]
+//!   module)
+//! - `defmt`: `defmt::Format` for every analog literal, logged compactly as
+//!   `{w=..,h=..,l=..}` (no drawings over RTT, sorry)
+//!
+//! # Minimum Supported Rust Version
+//!
+//! `analog_literal!` (along with everything else that's available out of the
+//! box) requires Rust 1.83 or newer, which is quite the bump from 1.0 (which
+//! would run on pretty much anything that understood `macro_rules!`). 1.83 is
+//! when `const fn`s were finally allowed to take `&mut` references, and the
+//! runtime parser is made of little else.
+//!
+//! The `macros` feature requires Rust 1.88 or newer. Its proc macros work out
+//! where everything in a drawing is using `Span::line` and `Span::column`,
+//! which only became stable in 1.88.
+//!
 //! # Final Comments
 //!
 //! I'm not gonna lie - I was already half-way done implementing 3D literals
@@ -226,20 +277,118 @@
 #![deny(missing_docs)]
 #![cfg_attr(not(test), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(all(feature = "std", not(test)))]
+extern crate std;
+
+#[cfg(feature = "serde")]
+pub mod art;
+pub mod aspect;
+mod cmp;
+pub mod consts;
+mod diff;
+pub mod flowchart;
+#[cfg(any(feature = "arbitrary", feature = "proptest", feature = "rand"))]
+pub mod fuzz;
+pub mod geometry;
+pub mod graph;
+mod interop;
+pub mod interval;
+pub mod keyboard;
+mod labeled;
+mod layout;
+pub mod music;
+mod ops;
+mod packing;
+pub mod parse;
+pub mod polyomino;
+mod positioned;
+pub mod render;
+mod scalar;
+pub mod scan;
+mod shape;
+pub mod stairs;
+pub mod table;
+pub mod typed;
+pub mod unit;
+pub mod venn;
+pub mod version;
+
+pub use cmp::{max_by_area, max_by_volume, ByArea, ByVolume};
+pub use labeled::LabeledRectangle;
+pub use layout::Layout;
+pub use packing::{how_many_fit, how_many_fit_2d};
+pub use positioned::PositionedRectangle;
+pub use scalar::{ScalarCuboid, ScalarRectangle};
+pub use shape::{AnalogShape, Axis};
+
 /// A 1D Line of a particular length.
 ///
 /// I'll be honest, I thought it'd be funnier to have the `analog_literal!`
 /// macro just return a untyped number instead of a structured `Line` struct.
 ///
-/// I'm just including this struct as part of the docs for completeness's sake,
-/// since I know that if I don't, _someone_ is going to bring it up.
+/// That said, _someone_ did bring it up, and they made a good point: a bare
+/// number has no idea it was ever drawn. Putting `line;` in front of a 1D
+/// literal returns a `Line` instead, which can be added, subtracted, compared,
+/// and (thanks to `Deref`) used just about anywhere a `usize` can.
+///
+/// ```rust
+/// # use analog_literals::{analog_literal, Line};
+/// const FUSE: Line = analog_literal! { line; I------I };
+/// const SPARK: Line = analog_literal! { line; I--I };
+///
+/// assert_eq!(FUSE.len(), 3);
+/// assert_eq!(FUSE - SPARK, Line(2));
+/// assert_eq!(FUSE + SPARK, Line(4));
+/// assert!(SPARK < FUSE);
+/// assert_eq!(*FUSE * 2, 6);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Line(pub usize);
 
+impl Line {
+    /// Return the length of the line.
+    pub const fn len(&self) -> usize {
+        self.0
+    }
+
+    /// Check if the line has no length at all (i.e: `II`).
+    pub const fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Drag the line `h` `|`s downwards, turning it into a [`Rectangle`].
+    ///
+    /// Also available as `line * line`, for the algebraically inclined.
+    ///
+    /// ```rust
+    /// # use analog_literals::{analog_literal, Line, Rectangle};
+    /// const WIDTH: Line = Line(analog_literal! { +--------+ });
+    /// const HEIGHT: Line = Line(analog_literal! { +----+ });
+    ///
+    /// assert_eq!(WIDTH.extrude(2), Rectangle { w: 4, h: 2 });
+    /// assert_eq!(WIDTH * HEIGHT, WIDTH.extrude(2));
+    /// ```
+    pub const fn extrude(&self, h: usize) -> Rectangle {
+        Rectangle { w: self.0, h }
+    }
+}
+
 /// A 2D Rectangle with a certain width and height.
 ///
-/// Note that `Rectangle` does not implement `Eq` nor `Ord`, as it would be rude
-/// to assume that two literals are the same just because they are rotationally
-/// transformed.
+/// The width and height are `usize`s unless specified otherwise (e.g:
+/// `Rectangle<u32>`). Every other primitive integer type gets the same
+/// measuring methods via the [`ScalarRectangle`] trait, and [`analog_literal!`]
+/// can be told which one to use.
+///
+/// Two rectangles are only ever `==` if they're drawn exactly the same way
+/// (i.e: same width, same height). It would be rude to assume that two
+/// literals are the same just because one of them has been turned on its side,
+/// so that's opt-in via [`Rectangle::congruent`]. There's still no `Ord`
+/// though, since nobody can agree on whether a tall rectangle is bigger than a
+/// wide one.
 ///
 /// # Example
 ///
@@ -263,12 +412,16 @@ pub struct Line(pub usize);
 /// assert_eq!(aspect_ratio.w, 4);
 /// assert_eq!(aspect_ratio.h, 3);
 /// ```
-#[derive(Debug, Copy, Clone)]
-pub struct Rectangle {
+///
+/// To compare two drawn aspect ratios, regardless of how big they were drawn,
+/// see [`Rectangle::aspect`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rectangle<T = usize> {
     /// Width of the literal (counts how many `--` wide the analog literal is)
-    pub w: usize,
+    pub w: T,
     /// Height of the literal (counts how many `|` tall the analog literal is)
-    pub h: usize,
+    pub h: T,
 }
 
 impl Rectangle {
@@ -276,13 +429,377 @@ impl Rectangle {
     ///
     /// This is a highly complex operation, and has been aggressively optimized
     /// for maximum performance. Gaze upon the implementation... if you dare.
+    ///
+    /// Overflows just like any other multiplication would. See
+    /// [`Rectangle::checked_area`] and [`Rectangle::saturating_area`] for
+    /// rectangles of truly astronomical proportions.
     pub const fn area(&self) -> usize {
         self.w * self.h
     }
+
+    /// Return the area of the rectangle, or `None` if it doesn't fit in a
+    /// `usize`.
+    ///
+    /// Unlikely to matter on a 64-bit machine, but on a 16-bit microcontroller
+    /// even a modest 256x256 rectangle is one `--` too many.
+    ///
+    /// ```rust
+    /// # use analog_literals::Rectangle;
+    /// assert_eq!(Rectangle { w: 3, h: 2 }.checked_area(), Some(6));
+    /// assert_eq!(Rectangle { w: usize::MAX, h: 2 }.checked_area(), None);
+    /// ```
+    pub const fn checked_area(&self) -> Option<usize> {
+        self.w.checked_mul(self.h)
+    }
+
+    /// Return the area of the rectangle, or `usize::MAX` if it doesn't fit in
+    /// a `usize`.
+    ///
+    /// ```rust
+    /// # use analog_literals::Rectangle;
+    /// assert_eq!(Rectangle { w: 3, h: 2 }.saturating_area(), 6);
+    /// assert_eq!(Rectangle { w: usize::MAX, h: 2 }.saturating_area(), usize::MAX);
+    /// ```
+    pub const fn saturating_area(&self) -> usize {
+        self.w.saturating_mul(self.h)
+    }
+
+    /// Check if two rectangles are the same shape, even if one of them has
+    /// been rotated or flipped over.
+    ///
+    /// ```rust
+    /// # use analog_literals::analog_literal;
+    /// let wide = analog_literal! {
+    ///     +------+
+    ///     |      |
+    ///     +------+
+    /// };
+    /// let tall = analog_literal! {
+    ///     +--+
+    ///     |  |
+    ///     |  |
+    ///     |  |
+    ///     +--+
+    /// };
+    ///
+    /// assert!(wide != tall);
+    /// assert!(wide.congruent(&tall));
+    /// ```
+    pub const fn congruent(&self, other: &Rectangle) -> bool {
+        (self.w == other.w && self.h == other.h) || (self.w == other.h && self.h == other.w)
+    }
+
+    /// Return the perimeter of the rectangle (i.e: how much fence it'll take).
+    ///
+    /// ```rust
+    /// # use analog_literals::analog_literal;
+    /// let garden = analog_literal! {
+    ///     +--------+
+    ///     |        |
+    ///     |        |
+    ///     +--------+
+    /// };
+    ///
+    /// assert_eq!(garden.perimeter(), 12);
+    /// ```
+    pub const fn perimeter(&self) -> usize {
+        2 * (self.w + self.h)
+    }
+
+    /// Return the square of the distance between two opposite corners of the
+    /// rectangle.
+    ///
+    /// Squared, so that everything stays a nice round integer. Compare it
+    /// against some other squared length, and nobody has to know.
+    ///
+    /// ```rust
+    /// # use analog_literals::Rectangle;
+    /// let tv = Rectangle { w: 16, h: 12 };
+    /// assert_eq!(tv.diagonal_squared(), 20 * 20);
+    /// ```
+    pub const fn diagonal_squared(&self) -> usize {
+        self.w * self.w + self.h * self.h
+    }
+
+    /// Check if the rectangle is a square.
+    ///
+    /// ```rust
+    /// # use analog_literals::analog_literal;
+    /// let square = analog_literal! {
+    ///     +----+
+    ///     |    |
+    ///     |    |
+    ///     +----+
+    /// };
+    /// let not_square = analog_literal! {
+    ///     +----+
+    ///     |    |
+    ///     +----+
+    /// };
+    ///
+    /// assert!(square.is_square());
+    /// assert!(!not_square.is_square());
+    /// ```
+    pub const fn is_square(&self) -> bool {
+        self.w == self.h
+    }
+
+    /// Return the rectangle's aspect ratio (i.e: `w:h`).
+    ///
+    /// Handy for checking whether two rectangles are the same shape, just at
+    /// different sizes, without breaking out the gcd math by hand.
+    ///
+    /// ```rust
+    /// # use analog_literals::{analog_literal, aspect::AspectRatio};
+    /// let thumbnail = analog_literal! {
+    ///     +--------+
+    ///     |        |
+    ///     |        |
+    ///     |        |
+    ///     +--------+
+    /// };
+    ///
+    /// assert_eq!(thumbnail.aspect(), AspectRatio::new(4, 3));
+    /// assert_eq!(thumbnail.scaled(160).aspect(), AspectRatio::new(640, 480));
+    /// ```
+    ///
+    /// Unlike the rest of the measuring methods, this one is `usize`-only
+    /// (there's no such thing as a `-16:9` screen), so rectangles made out of
+    /// other scalars will have to be converted first.
+    pub const fn aspect(&self) -> aspect::AspectRatio {
+        aspect::AspectRatio::new(self.w, self.h)
+    }
+
+    /// Check if `other` fits inside the rectangle, as-is (i.e: without being
+    /// rotated).
+    ///
+    /// Rectangles always contain themselves.
+    ///
+    /// ```rust
+    /// # use analog_literals::analog_literal;
+    /// let frame = analog_literal! {
+    ///     +--------+
+    ///     |        |
+    ///     |        |
+    ///     +--------+
+    /// };
+    /// let photo = analog_literal! {
+    ///     +------+
+    ///     |      |
+    ///     +------+
+    /// };
+    /// let panorama = analog_literal! {
+    ///     +------------+
+    ///     |            |
+    ///     +------------+
+    /// };
+    ///
+    /// assert!(frame.contains(&photo));
+    /// assert!(frame.contains(&frame));
+    /// assert!(!frame.contains(&panorama));
+    /// ```
+    pub const fn contains(&self, other: &Rectangle) -> bool {
+        other.w <= self.w && other.h <= self.h
+    }
+
+    /// Check if the rectangle fits inside `container`, turning it on its side
+    /// if need be.
+    ///
+    /// Unlike [`Rectangle::contains`], this doesn't care which way around
+    /// the rectangle started out.
+    ///
+    /// ```rust
+    /// # use analog_literals::{analog_literal, Rectangle};
+    /// const DOORWAY: Rectangle = analog_literal! {
+    ///     +----+
+    ///     |    |
+    ///     |    |
+    ///     |    |
+    ///     |    |
+    ///     +----+
+    /// };
+    /// const COUCH: Rectangle = analog_literal! {
+    ///     +--------+
+    ///     |        |
+    ///     +--------+
+    /// };
+    /// const PIANO: Rectangle = analog_literal! {
+    ///     +------+
+    ///     |      |
+    ///     |      |
+    ///     |      |
+    ///     +------+
+    /// };
+    ///
+    /// const COUCH_FITS: bool = COUCH.fits_inside(&DOORWAY);
+    /// assert!(COUCH_FITS);
+    /// assert!(!DOORWAY.contains(&COUCH));
+    /// assert!(!PIANO.fits_inside(&DOORWAY));
+    /// ```
+    pub const fn fits_inside(&self, container: &Rectangle) -> bool {
+        container.contains(self) || container.contains(&self.transpose())
+    }
+
+    /// Return the rectangle, blown up `n` times along each axis.
+    ///
+    /// Great for drawing things at a nice, readable scale, and then sorting out
+    /// the real dimensions later. Also available as `rect * n` (and `rect / n`
+    /// for going the other way, rounding down).
+    ///
+    /// ```rust
+    /// # use analog_literals::{analog_literal, Rectangle};
+    /// // 1 `--` = 100mm
+    /// const DESK: Rectangle = analog_literal! {
+    ///     +------------+
+    ///     |            |
+    ///     |            |
+    ///     +------------+
+    /// }
+    /// .scaled(100);
+    ///
+    /// assert_eq!(DESK, Rectangle { w: 600, h: 200 });
+    /// assert_eq!(DESK / 100, Rectangle { w: 6, h: 2 });
+    /// ```
+    pub const fn scaled(&self, n: usize) -> Rectangle {
+        Rectangle {
+            w: self.w * n,
+            h: self.h * n,
+        }
+    }
+
+    /// Slice the rectangle in two with a horizontal cut, `at` `|`s down from
+    /// the top, returning the `(top, bottom)` halves.
+    ///
+    /// Returns `None` if the cut would miss the rectangle entirely.
+    ///
+    /// ```rust
+    /// # use analog_literals::{analog_literal, Rectangle};
+    /// const TERMINAL: Rectangle = analog_literal! {
+    ///     +--------------------+
+    ///     |                    |
+    ///     |                    |
+    ///     |                    |
+    ///     |                    |
+    ///     +--------------------+
+    /// };
+    ///
+    /// let (editor, status_bar) = TERMINAL.split_horizontal(3).unwrap();
+    /// let (files, code) = editor.split_vertical(3).unwrap();
+    ///
+    /// assert_eq!(status_bar, Rectangle { w: 10, h: 1 });
+    /// assert_eq!(files, Rectangle { w: 3, h: 3 });
+    /// assert_eq!(code, Rectangle { w: 7, h: 3 });
+    /// assert_eq!(TERMINAL.split_horizontal(5), None);
+    /// ```
+    pub const fn split_horizontal(&self, at: usize) -> Option<(Rectangle, Rectangle)> {
+        match self.h.checked_sub(at) {
+            Some(rest) => Some((
+                Rectangle { w: self.w, h: at },
+                Rectangle { w: self.w, h: rest },
+            )),
+            None => None,
+        }
+    }
+
+    /// Slice the rectangle in two with a vertical cut, `at` `--`s in from the
+    /// left, returning the `(left, right)` halves.
+    ///
+    /// Returns `None` if the cut would miss the rectangle entirely. See
+    /// [`Rectangle::split_horizontal`] for an example.
+    pub const fn split_vertical(&self, at: usize) -> Option<(Rectangle, Rectangle)> {
+        match self.w.checked_sub(at) {
+            Some(rest) => Some((
+                Rectangle { w: at, h: self.h },
+                Rectangle { w: rest, h: self.h },
+            )),
+            None => None,
+        }
+    }
+
+    /// Iterate over the `(x, y)` coordinates of every cell inside the
+    /// rectangle, one row at a time (i.e: left to right, top to bottom).
+    ///
+    /// ```rust
+    /// # use analog_literals::analog_literal;
+    /// let grid = analog_literal! {
+    ///     +----+
+    ///     |    |
+    ///     +----+
+    /// };
+    ///
+    /// assert!(grid.cells().eq([(0, 0), (1, 0)]));
+    /// assert_eq!(grid.transpose().cells().count(), grid.area());
+    /// ```
+    pub fn cells(&self) -> impl Iterator<Item = (usize, usize)> {
+        let w = self.w;
+        (0..self.h).flat_map(move |y| (0..w).map(move |x| (x, y)))
+    }
+
+    /// Drag the rectangle `l` `/`s backwards, turning it into a [`Cuboid`].
+    ///
+    /// Also available as `rect * line`. Perfect for when drawing out the whole
+    /// cuboid is more effort than it's worth.
+    ///
+    /// ```rust
+    /// # use analog_literals::{analog_literal, Cuboid, Line};
+    /// let front = analog_literal! {
+    ///     +------+
+    ///     |      |
+    ///     +------+
+    /// };
+    ///
+    /// assert_eq!(front.extrude(4), Cuboid { w: 3, h: 1, l: 4 });
+    /// assert_eq!(front * Line(analog_literal! { +--------+ }), front.extrude(4));
+    /// assert_eq!(front.extrude(4).front(), front);
+    /// ```
+    pub const fn extrude(&self, l: usize) -> Cuboid {
+        Cuboid {
+            w: self.w,
+            h: self.h,
+            l,
+        }
+    }
+}
+
+impl<T: Copy> Rectangle<T> {
+    /// Return the same rectangle, turned on its side (i.e: with its width and
+    /// height swapped).
+    ///
+    /// ```rust
+    /// # use analog_literals::analog_literal;
+    /// let landscape = analog_literal! {
+    ///     +------+
+    ///     |      |
+    ///     +------+
+    /// };
+    /// let portrait = analog_literal! {
+    ///     +--+
+    ///     |  |
+    ///     |  |
+    ///     |  |
+    ///     +--+
+    /// };
+    ///
+    /// assert_eq!(landscape.transpose(), portrait);
+    /// assert_eq!(landscape.transpose().transpose(), landscape);
+    /// ```
+    pub const fn transpose(&self) -> Rectangle<T> {
+        Rectangle {
+            w: self.h,
+            h: self.w,
+        }
+    }
 }
 
 /// A 3D Cuboid with a certain width, height, and length.
 ///
+/// Just like with [`Rectangle`], the dimensions are `usize`s unless specified
+/// otherwise (in which case [`ScalarCuboid`] has the measuring methods).
+///
+/// Just like with [`Rectangle`], two cuboids are only `==` if they have the
+/// exact same width, height, and length. Cuboids which have merely been tipped
+/// over can be compared using [`Cuboid::congruent`].
+///
 /// # Example
 ///
 /// Say you're trying to solve the [packing problem](https://en.wikipedia.org/wiki/Bin_packing_problem)
@@ -321,106 +838,388 @@ impl Rectangle {
 /// assert_eq!(MINING_RIG.h, 1);
 /// assert_eq!(MINING_RIG.l, 16);
 /// ```
-#[derive(Debug, Copy, Clone)]
-pub struct Cuboid {
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cuboid<T = usize> {
     /// Width of the literal (counts how many `--` wide the analog literal is)
-    pub w: usize,
+    pub w: T,
     /// Height of the literal (counts how many `|` tall the analog literal is)
-    pub h: usize,
+    pub h: T,
     /// Length of the literal (counts how many `/` deep the analog literal is)
-    pub l: usize,
+    pub l: T,
 }
 
 impl Cuboid {
-    /// Return the volume of the cuboid.
+    /// Assemble a cuboid out of drawings of its [top](Cuboid::top),
+    /// [side](Cuboid::side), and [front](Cuboid::front).
+    ///
+    /// Sometimes a single 3D drawing just won't cut it, and a trusty old
+    /// [multiview projection](https://en.wikipedia.org/wiki/Multiview_orthographic_projection)
+    /// is the way to go.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the faces don't belong to the same cuboid (e.g: the top is
+    /// wider than the front). When used in a `const`, that's a compile error.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use analog_literals::{analog_literal, Cuboid};
+    /// const FRIDGE: Cuboid = Cuboid::from_faces(
+    ///     analog_literal! {
+    ///         +------+
+    ///         |      |
+    ///         |      |
+    ///         +------+
+    ///     },
+    ///     analog_literal! {
+    ///         +----+
+    ///         |    |
+    ///         |    |
+    ///         |    |
+    ///         |    |
+    ///         |    |
+    ///         +----+
+    ///     },
+    ///     analog_literal! {
+    ///         +------+
+    ///         |      |
+    ///         |      |
+    ///         |      |
+    ///         |      |
+    ///         |      |
+    ///         +------+
+    ///     },
+    /// );
     ///
-    /// Note that `Cuboid` does not implement `Eq` nor `Ord`, as it would be
-    /// rude to assume that two literals are the same just because they are
-    /// rotationally transformed.
+    /// assert_eq!(FRIDGE, Cuboid { w: 3, h: 5, l: 2 });
+    /// ```
+    ///
+    /// Faces that don't line up are caught at compile time:
+    ///
+    /// ```compile_fail
+    /// # use analog_literals::{Cuboid, Rectangle};
+    /// const WONKY: Cuboid = Cuboid::from_faces(
+    ///     Rectangle { w: 3, h: 2 },
+    ///     Rectangle { w: 2, h: 5 },
+    ///     Rectangle { w: 4, h: 5 },
+    /// );
+    /// ```
+    pub const fn from_faces(top: Rectangle, side: Rectangle, front: Rectangle) -> Cuboid {
+        if top.w != front.w {
+            panic!("top and front faces have different widths");
+        }
+        if top.h != side.w {
+            panic!("top and side faces have different lengths");
+        }
+        if side.h != front.h {
+            panic!("side and front faces have different heights");
+        }
+        Cuboid {
+            w: front.w,
+            h: front.h,
+            l: top.h,
+        }
+    }
+
+    /// Return the volume of the cuboid.
     ///
     /// Just like [`Rectangle::area()`], the underlying implementation of this
     /// method is highly complex and aggressively optimized. You could take a
     /// look, but you'll be hard pressed to make heads or tails of it. You've
     /// been warned.
+    ///
+    /// Overflows just like any other multiplication would. See
+    /// [`Cuboid::checked_volume`] and [`Cuboid::saturating_volume`] for when
+    /// that's a concern.
     pub const fn volume(&self) -> usize {
         self.w * self.h * self.l
     }
 
-    /// Returns a [`Rectangle`] with the same dimensions as the top of the
-    /// Cubiod.
+    /// Return the volume of the cuboid, or `None` if it doesn't fit in a
+    /// `usize`.
     ///
-    /// # Example
+    /// ```rust
+    /// # use analog_literals::Cuboid;
+    /// assert_eq!(Cuboid { w: 3, h: 2, l: 4 }.checked_volume(), Some(24));
+    /// assert_eq!(Cuboid { w: usize::MAX, h: 1, l: 2 }.checked_volume(), None);
+    /// ```
+    pub const fn checked_volume(&self) -> Option<usize> {
+        match self.w.checked_mul(self.h) {
+            Some(area) => area.checked_mul(self.l),
+            None => None,
+        }
+    }
+
+    /// Return the volume of the cuboid, or `usize::MAX` if it doesn't fit in a
+    /// `usize`.
     ///
     /// ```rust
-    /// # use analog_literals::{analog_literal, Cuboid};
+    /// # use analog_literals::Cuboid;
+    /// assert_eq!(Cuboid { w: 3, h: 2, l: 4 }.saturating_volume(), 24);
+    /// assert_eq!(Cuboid { w: usize::MAX, h: 1, l: 2 }.saturating_volume(), usize::MAX);
+    /// ```
+    pub const fn saturating_volume(&self) -> usize {
+        self.w.saturating_mul(self.h).saturating_mul(self.l)
+    }
+
+    /// Check if two cuboids are the same shape, even if one of them has been
+    /// rotated or flipped over.
     ///
-    /// pub const CUBE_5_BY_2_BY_4: Cuboid = analog_literal! {
-    ///         +----------+
-    ///        /          /|
-    ///       /          / |
-    ///      /          /  +
-    ///     /          /  /
-    ///    +----------+  /
-    ///    |          | /
-    ///    |          |/
-    ///    +----------+
+    /// ```rust
+    /// # use analog_literals::analog_literal;
+    /// let standing = analog_literal! {
+    ///       +--+
+    ///      /  /|
+    ///     +--+ |
+    ///     |  | +
+    ///     |  |/
+    ///     +--+
     /// };
-    /// let top = analog_literal! {
-    ///     +----------+
-    ///     |          |
-    ///     |          |
-    ///     |          |
-    ///     |          |
-    ///     +----------+
+    /// let lying_down = analog_literal! {
+    ///        +--+
+    ///       /  /|
+    ///      /  / +
+    ///     +--+ /
+    ///     |  |/
+    ///     +--+
     /// };
     ///
-    /// assert_eq!(CUBE_5_BY_2_BY_4.top().w, top.w);
-    /// assert_eq!(CUBE_5_BY_2_BY_4.top().h, top.h);
+    /// assert!(standing != lying_down);
+    /// assert!(standing.congruent(&lying_down));
     /// ```
-    pub const fn top(&self) -> Rectangle {
-        Rectangle {
-            w: self.w,
-            h: self.l,
-        }
+    pub const fn congruent(&self, other: &Cuboid) -> bool {
+        let [a, b, c] = self.sorted_dims();
+        let [x, y, z] = other.sorted_dims();
+        a == x && b == y && c == z
     }
 
-    /// Returns a [`Rectangle`] with the same dimensions as the side of the
-    /// Cubiod.
-    ///
-    /// # Example
+    /// Return the total area of all six faces of the cuboid (i.e: how much
+    /// wrapping paper it'll take).
     ///
     /// ```rust
     /// # use analog_literals::{analog_literal, Cuboid};
-    ///
-    /// pub const CUBE_5_BY_2_BY_4: Cuboid = analog_literal! {
-    ///         +----------+
-    ///        /          /|
-    ///       /          / |
-    ///      /          /  +
-    ///     /          /  /
-    ///    +----------+  /
-    ///    |          | /
-    ///    |          |/
-    ///    +----------+
-    /// };
-    /// let side = analog_literal! {
-    ///     +--------+
-    ///     |        |
-    ///     |        |
-    ///     +--------+
+    /// const GIFT: Cuboid = analog_literal! {
+    ///       +------+
+    ///      /      /|
+    ///     +------+ |
+    ///     |      | +
+    ///     |      |/
+    ///     +------+
     /// };
     ///
-    /// assert_eq!(CUBE_5_BY_2_BY_4.side().w, side.w);
-    /// assert_eq!(CUBE_5_BY_2_BY_4.side().h, side.h);
+    /// assert_eq!(GIFT.surface_area(), 2 * (3 * 2 + 3 * 1 + 2 * 1));
     /// ```
-    pub const fn side(&self) -> Rectangle {
-        Rectangle {
-            w: self.l,
-            h: self.h,
-        }
+    pub const fn surface_area(&self) -> usize {
+        2 * (self.w * self.h + self.w * self.l + self.h * self.l)
     }
 
-    /// Returns a [`Rectangle`] with the same dimensions as the front of the
+    /// Return the combined length of all twelve edges of the cuboid (i.e: how
+    /// much ribbon it'll take to outline).
+    ///
+    /// ```rust
+    /// # use analog_literals::Cuboid;
+    /// assert_eq!(Cuboid { w: 3, h: 2, l: 1 }.total_edge_length(), 24);
+    /// ```
+    pub const fn total_edge_length(&self) -> usize {
+        4 * (self.w + self.h + self.l)
+    }
+
+    /// Return the square of the distance between two opposite corners of the
+    /// cuboid.
+    ///
+    /// Squared, since square roots don't exactly come naturally to integers.
+    /// Handy for checking if a pool cue fits in the box without any floating
+    /// point shenanigans.
+    ///
+    /// ```rust
+    /// # use analog_literals::Cuboid;
+    /// let cue = 7;
+    /// let shipping_box = Cuboid { w: 6, h: 2, l: 3 };
+    ///
+    /// assert_eq!(shipping_box.space_diagonal_squared(), 49);
+    /// assert!(cue * cue <= shipping_box.space_diagonal_squared());
+    /// ```
+    pub const fn space_diagonal_squared(&self) -> usize {
+        self.w * self.w + self.h * self.h + self.l * self.l
+    }
+
+    /// Check if the cuboid fits inside `container`, in any of its
+    /// [orientations](Cuboid::orientations).
+    ///
+    /// ```rust
+    /// # use analog_literals::{analog_literal, Cuboid};
+    /// const CLOSET: Cuboid = Cuboid { w: 4, h: 10, l: 3 };
+    /// const MINING_RIG: Cuboid = analog_literal! {
+    ///           +----------+
+    ///          /          /|
+    ///         /          / +
+    ///        /          / /
+    ///       +----------+ /
+    ///       |          |/
+    ///       +----------+
+    /// };
+    ///
+    /// // doesn't fit lying down, but stand it up on its end and...
+    /// assert!(MINING_RIG.w > CLOSET.w);
+    /// const FITS: bool = MINING_RIG.fits_inside(&CLOSET);
+    /// assert!(FITS);
+    /// ```
+    pub const fn fits_inside(&self, container: &Cuboid) -> bool {
+        let [a, b, c] = self.sorted_dims();
+        let [x, y, z] = container.sorted_dims();
+        a <= x && b <= y && c <= z
+    }
+
+    /// Iterate over the `(x, y, z)` coordinates of every voxel inside the
+    /// cuboid, one layer at a time (i.e: front to back, with each layer
+    /// going in the same order as [`Rectangle::cells`]).
+    ///
+    /// ```rust
+    /// # use analog_literals::analog_literal;
+    /// let crate_ = analog_literal! {
+    ///        +----+
+    ///       /    /|
+    ///      /    / +
+    ///     +----+ /
+    ///     |    |/
+    ///     +----+
+    /// };
+    ///
+    /// let voxels: Vec<_> = crate_.voxels().collect();
+    /// assert_eq!(voxels.len(), crate_.volume());
+    /// assert_eq!(voxels[..3], [(0, 0, 0), (1, 0, 0), (0, 0, 1)]);
+    /// ```
+    pub fn voxels(&self) -> impl Iterator<Item = (usize, usize, usize)> {
+        let front = self.front();
+        (0..self.l).flat_map(move |z| front.cells().map(move |(x, y)| (x, y, z)))
+    }
+
+    /// Return the cuboid, blown up `n` times along each axis.
+    ///
+    /// Just like [`Rectangle::scaled`], this is also available as `cuboid * n`
+    /// (and `cuboid / n`, rounding down).
+    ///
+    /// ```rust
+    /// # use analog_literals::{analog_literal, Cuboid};
+    /// let model = analog_literal! {
+    ///       +----+
+    ///      /    /|
+    ///     +----+ +
+    ///     |    |/
+    ///     +----+
+    /// };
+    ///
+    /// assert_eq!(model.scaled(10), Cuboid { w: 20, h: 10, l: 10 });
+    /// assert_eq!(model * 10, model.scaled(10));
+    /// ```
+    pub const fn scaled(&self, n: usize) -> Cuboid {
+        Cuboid {
+            w: self.w * n,
+            h: self.h * n,
+            l: self.l * n,
+        }
+    }
+
+    /// Return the cuboid's dimensions, smallest first.
+    const fn sorted_dims(&self) -> [usize; 3] {
+        let (mut a, mut b, mut c) = (self.w, self.h, self.l);
+        if a > b {
+            let t = a;
+            a = b;
+            b = t;
+        }
+        if b > c {
+            let t = b;
+            b = c;
+            c = t;
+        }
+        if a > b {
+            let t = a;
+            a = b;
+            b = t;
+        }
+        [a, b, c]
+    }
+}
+
+impl<T: Copy> Cuboid<T> {
+    /// Returns a [`Rectangle`] with the same dimensions as the top of the
+    /// Cubiod.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use analog_literals::{analog_literal, Cuboid};
+    ///
+    /// pub const CUBE_5_BY_2_BY_4: Cuboid = analog_literal! {
+    ///         +----------+
+    ///        /          /|
+    ///       /          / |
+    ///      /          /  +
+    ///     /          /  /
+    ///    +----------+  /
+    ///    |          | /
+    ///    |          |/
+    ///    +----------+
+    /// };
+    /// let top = analog_literal! {
+    ///     +----------+
+    ///     |          |
+    ///     |          |
+    ///     |          |
+    ///     |          |
+    ///     +----------+
+    /// };
+    ///
+    /// assert_eq!(CUBE_5_BY_2_BY_4.top().w, top.w);
+    /// assert_eq!(CUBE_5_BY_2_BY_4.top().h, top.h);
+    /// ```
+    pub const fn top(&self) -> Rectangle<T> {
+        Rectangle {
+            w: self.w,
+            h: self.l,
+        }
+    }
+
+    /// Returns a [`Rectangle`] with the same dimensions as the side of the
+    /// Cubiod.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use analog_literals::{analog_literal, Cuboid};
+    ///
+    /// pub const CUBE_5_BY_2_BY_4: Cuboid = analog_literal! {
+    ///         +----------+
+    ///        /          /|
+    ///       /          / |
+    ///      /          /  +
+    ///     /          /  /
+    ///    +----------+  /
+    ///    |          | /
+    ///    |          |/
+    ///    +----------+
+    /// };
+    /// let side = analog_literal! {
+    ///     +--------+
+    ///     |        |
+    ///     |        |
+    ///     +--------+
+    /// };
+    ///
+    /// assert_eq!(CUBE_5_BY_2_BY_4.side().w, side.w);
+    /// assert_eq!(CUBE_5_BY_2_BY_4.side().h, side.h);
+    /// ```
+    pub const fn side(&self) -> Rectangle<T> {
+        Rectangle {
+            w: self.l,
+            h: self.h,
+        }
+    }
+
+    /// Returns a [`Rectangle`] with the same dimensions as the front of the
     /// Cubiod.
     ///
     /// # Example
@@ -449,37 +1248,1791 @@ impl Cuboid {
     /// assert_eq!(CUBE_5_BY_2_BY_4.front().w, front.w);
     /// assert_eq!(CUBE_5_BY_2_BY_4.front().h, front.h);
     /// ```
-    pub const fn front(&self) -> Rectangle {
+    pub const fn front(&self) -> Rectangle<T> {
         Rectangle {
             w: self.w,
             h: self.h,
         }
     }
+
+    /// Return every way the cuboid can be set down on a table (i.e: all six
+    /// permutations of its width, height, and length).
+    ///
+    /// The cuboid's current orientation always comes first. Cuboids with a
+    /// couple of matching sides will show up more than once, since there's
+    /// no telling identical boxes apart.
+    ///
+    /// ```rust
+    /// # use analog_literals::Cuboid;
+    /// const BRICK: Cuboid = Cuboid { w: 3, h: 2, l: 1 };
+    /// const ORIENTATIONS: [Cuboid; 6] = BRICK.orientations();
+    ///
+    /// assert_eq!(ORIENTATIONS[0], BRICK);
+    /// assert!(ORIENTATIONS.contains(&Cuboid { w: 1, h: 3, l: 2 }));
+    /// assert!(ORIENTATIONS.iter().all(|o| o.congruent(&BRICK)));
+    /// ```
+    pub const fn orientations(&self) -> [Cuboid<T>; 6] {
+        let Cuboid { w, h, l } = *self;
+        [
+            Cuboid { w, h, l },
+            Cuboid { w, h: l, l: h },
+            Cuboid { w: h, h: w, l },
+            Cuboid { w: h, h: l, l: w },
+            Cuboid { w: l, h: w, l: h },
+            Cuboid { w: l, h, l: w },
+        ]
+    }
+
+    /// Returns a [`Rectangle`] with the same dimensions as the bottom of the
+    /// Cubiod.
+    ///
+    /// Cuboids being cuboids, this is always the same as [`Cuboid::top`].
+    /// Still, sometimes it's nice to say what you mean.
+    pub const fn bottom(&self) -> Rectangle<T> {
+        self.top()
+    }
+
+    /// Returns a [`Rectangle`] with the same dimensions as the back of the
+    /// Cubiod.
+    ///
+    /// Always the same as [`Cuboid::front`].
+    pub const fn back(&self) -> Rectangle<T> {
+        self.front()
+    }
+
+    /// Returns a [`Rectangle`] with the same dimensions as the left side of
+    /// the Cubiod.
+    ///
+    /// Always the same as [`Cuboid::side`] (which, for the record, is the
+    /// right side - the one you can actually see in the drawing).
+    pub const fn left(&self) -> Rectangle<T> {
+        self.side()
+    }
+
+    /// Return how far the cuboid extends along the given axis.
+    pub const fn extent(&self, axis: Axis) -> T {
+        match axis {
+            Axis::X => self.w,
+            Axis::Y => self.h,
+            Axis::Z => self.l,
+        }
+    }
+
+    /// Returns a [`Rectangle`] with the same dimensions as the face of the
+    /// cuboid you'd be looking at when staring down the given axis.
+    ///
+    /// That's the [`side`](Cuboid::side) for [`Axis::X`], the
+    /// [`top`](Cuboid::top) for [`Axis::Y`], and the [`front`](Cuboid::front)
+    /// for [`Axis::Z`], which comes in handy when there's no telling ahead of
+    /// time which face is needed.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use analog_literals::{analog_literal, Axis};
+    /// let shoebox = analog_literal! {
+    ///        +--------+
+    ///       /        /|
+    ///      /        / |
+    ///     +--------+  +
+    ///     |        | /
+    ///     |        |/
+    ///     +--------+
+    /// };
+    ///
+    /// // which face of the shoebox should it sit on, to be as tall as possible?
+    /// let tallest = Axis::ALL
+    ///     .iter()
+    ///     .copied()
+    ///     .min_by_key(|&axis| shoebox.face(axis).area())
+    ///     .unwrap();
+    ///
+    /// assert_eq!(tallest, Axis::X);
+    /// assert_eq!(shoebox.extent(tallest), 4);
+    /// ```
+    pub const fn face(&self, axis: Axis) -> Rectangle<T> {
+        match axis {
+            Axis::X => self.side(),
+            Axis::Y => self.top(),
+            Axis::Z => self.front(),
+        }
+    }
+}
+
+#[doc(hidden)]
+pub mod __private {
+    /// Pins down the element type of an `analog_matrix!`, while letting the
+    /// compiler count its rows and columns.
+    pub const fn matrix<const C: usize, const R: usize>(m: [[i64; C]; R]) -> [[i64; C]; R] {
+        m
+    }
+
+    /// Measures a struct field, given a closure that borrows it (so that
+    /// `analog_repr!` doesn't need to spell out the field's type).
+    #[cfg(feature = "macros")]
+    pub const fn size_of_field<T, F>(_: fn(&T) -> &F) -> usize {
+        core::mem::size_of::<F>()
+    }
+
+    pub use crate::diff::Diff;
+
+    #[cfg(feature = "macros")]
+    pub use analog_literals_macros::{
+        analog_chord, analog_flowchart, analog_graph, analog_intervals, analog_keyboard,
+        analog_layout, analog_literal_from_file, analog_literal_join, analog_literal_strict,
+        analog_piano, analog_polyomino, analog_positioned, analog_repr, analog_scene,
+        analog_stairs, analog_table, analog_venn,
+    };
+
+    // `analog_literal!` hands `strict;` and `;;` off to proc macros, so without
+    // them, the best it can do is explain what's missing
+    #[cfg(not(feature = "macros"))]
+    pub use crate::{
+        __analog_literal_join_needs_macros as analog_literal_join,
+        __analog_literal_strict_needs_macros as analog_literal_strict,
+    };
+
+    #[cfg(feature = "std")]
+    pub use std::eprintln;
+
+    /// Autoref-based specialization, so that `analog_dbg!` and
+    /// `#[derive(AnalogDebug)]` can draw analog literals while still accepting
+    /// any old `Debug` value.
+    pub mod autoref {
+        use core::fmt;
+
+        use crate::{Cuboid, Line, Rectangle};
+
+        pub struct Wrap<'a, T>(pub &'a T);
+
+        pub struct Drawing<'a>(Option<&'a dyn fmt::Display>);
+
+        impl fmt::Display for Drawing<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self.0 {
+                    Some(shape) => write!(f, "\n{}", shape),
+                    None => Ok(()),
+                }
+            }
+        }
+
+        pub trait Drawable: fmt::Display {}
+        impl Drawable for Line {}
+        impl Drawable for Rectangle {}
+        impl Drawable for Cuboid {}
+        impl<T: Drawable + ?Sized> Drawable for &T {}
+
+        pub trait Drawn {
+            fn drawing(&self) -> Drawing<'_>;
+        }
+
+        impl<T: Drawable> Drawn for &Wrap<'_, T> {
+            fn drawing(&self) -> Drawing<'_> {
+                Drawing(Some(self.0))
+            }
+        }
+
+        pub trait NotDrawn {
+            fn drawing(&self) -> Drawing<'_>;
+        }
+
+        impl<T> NotDrawn for Wrap<'_, T> {
+            fn drawing(&self) -> Drawing<'_> {
+                Drawing(None)
+            }
+        }
+
+        /// A struct field, which gets drawn if it's an analog literal.
+        pub struct Field<'a> {
+            value: &'a dyn fmt::Debug,
+            drawing: Drawing<'a>,
+        }
+
+        impl<'a> Field<'a> {
+            /// Pair up a field with its drawing (if it has one).
+            pub fn new(value: &'a dyn fmt::Debug, drawing: Drawing<'a>) -> Field<'a> {
+                Field { value, drawing }
+            }
+        }
+
+        /// Like [`fmt::Formatter::debug_struct`], except that drawings get to
+        /// start on their own line in `{:#?}` output, instead of dangling off
+        /// the end of a `name: `.
+        pub fn debug_struct(
+            f: &mut fmt::Formatter<'_>,
+            name: &str,
+            fields: &[(&str, Field<'_>)],
+        ) -> fmt::Result {
+            if !f.alternate() || fields.is_empty() {
+                let mut s = f.debug_struct(name);
+                for (name, field) in fields {
+                    s.field(name, field);
+                }
+                return s.finish();
+            }
+
+            writeln!(f, "{} {{", name)?;
+            for (name, field) in fields {
+                let out = &mut Indent(f);
+                match field.drawing.0 {
+                    Some(shape) => {
+                        fmt::Write::write_fmt(out, format_args!("    {}:\n{}", name, shape))?
+                    }
+                    None => fmt::Write::write_fmt(
+                        out,
+                        format_args!("    {}: {:#?}", name, field.value),
+                    )?,
+                }
+                f.write_str(",\n")?;
+            }
+            f.write_str("}")
+        }
+
+        /// Indents every line after the first by 4 spaces, for nesting things
+        /// inside a struct.
+        struct Indent<'a, 'b>(&'a mut fmt::Formatter<'b>);
+
+        impl fmt::Write for Indent<'_, '_> {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                for (i, line) in s.split('\n').enumerate() {
+                    if i != 0 {
+                        self.0.write_str("\n    ")?;
+                    }
+                    self.0.write_str(line)?;
+                }
+                Ok(())
+            }
+        }
+
+        /// Replaces newlines with spaces, for squishing drawings onto a single
+        /// line.
+        struct Squish<'a, 'b>(&'a mut fmt::Formatter<'b>);
+
+        impl fmt::Write for Squish<'_, '_> {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                for (i, line) in s.split('\n').enumerate() {
+                    if i != 0 {
+                        self.0.write_str(" ")?;
+                    }
+                    self.0.write_str(line)?;
+                }
+                Ok(())
+            }
+        }
+
+        impl fmt::Debug for Field<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                match self.drawing.0 {
+                    Some(shape) if f.alternate() => write!(f, "{}", shape),
+                    Some(shape) => fmt::Write::write_fmt(&mut Squish(f), format_args!("{}", shape)),
+                    None => self.value.fmt(f),
+                }
+            }
+        }
+    }
+}
+
+/// The star of the show: a macro to embed analog literals into otherwise boring
+/// Rust source code.
+///
+/// Check out the crate-level documentation for more info.
+///
+/// # Picking a Scalar
+///
+/// Rectangles and cuboids are made out of `usize`s by default. To draw them
+/// using some other integer type instead, put the type in front of the
+/// drawing, followed by a `;`. Bring [`ScalarRectangle`] and [`ScalarCuboid`]
+/// into scope to measure them:
+///
+/// ```rust
+/// # use analog_literals::{analog_literal, Cuboid, Rectangle};
+/// use analog_literals::{ScalarCuboid, ScalarRectangle};
+///
+/// const TILE: Rectangle<u32> = analog_literal! { u32;
+///     +----+
+///     |    |
+///     +----+
+/// };
+/// assert_eq!(TILE.area(), 2u32);
+///
+/// let crate_: Cuboid<i16> = analog_literal! { i16;
+///       +--+
+///      /  /|
+///     +--+ +
+///     |  |/
+///     +--+
+/// };
+/// assert_eq!(crate_.volume(), 1i16);
+///
+/// // lines work too
+/// let fuse = analog_literal! { u8; +------+ };
+/// assert_eq!(fuse, 3u8);
+/// ```
+///
+/// Measuring other scalars in a `const` context means doing the math on the
+/// fields directly (e.g: `TILE.w * TILE.h`); see
+/// [`ScalarRectangle`](ScalarRectangle#const-measuring) for why.
+///
+/// # Picking a Unit
+///
+/// Similarly, putting `in <unit>;` in front of the drawing tags the literal
+/// with a unit of measurement (see the [`unit`] module for details):
+///
+/// ```rust
+/// # use analog_literals::{analog_literal, Rectangle, ScalarRectangle};
+/// use analog_literals::unit::{Px, Tagged};
+///
+/// let icon: Tagged<Rectangle<u16>, Px> = analog_literal! { in Px; u16;
+///     +----+
+///     |    |
+///     +----+
+/// };
+/// assert_eq!(icon.untagged().area(), 2);
+/// ```
+///
+/// 1D literals can also be wrapped up in a [`Line`], by putting `line;` in
+/// front of them:
+///
+/// ```rust
+/// # use analog_literals::{analog_literal, Line};
+/// let fuse: Line = analog_literal! { line; I------I };
+/// assert_eq!(fuse.len(), 3);
+/// ```
+///
+/// # Strict Mode
+///
+/// Since `analog_literal!` only ever counts `--`s and `|`s, it won't bat an
+/// eye at a literal that's been bumped out of shape:
+///
+/// ```rust
+/// # use analog_literals::analog_literal;
+/// let lopsided = analog_literal! {
+///     +----+
+///       |    |
+///     +----+
+/// };
+/// assert_eq!(lopsided.area(), 2);
+/// ```
+///
+/// For those of us who take the WYSIWYG promise seriously, putting `strict;`
+/// in front of the drawing makes sure every row is lined up exactly the way
+/// it would be [rendered](crate::render), turning sloppy artwork into a
+/// compile error (and pointing out the offending character to boot). Strict
+/// mode requires the `macros` feature.
+///
+/// ```compile_fail
+/// # use analog_literals::analog_literal;
+/// let lopsided = analog_literal! { strict;
+///     +----+
+///       |    |
+///     +----+
+/// };
+/// ```
+///
+/// Strict mode can be combined with any of the other prefixes, and works
+/// regardless of how far the drawing is indented:
+///
+/// ```rust
+/// # use analog_literals::{analog_literal, Cuboid, ScalarCuboid};
+/// # #[cfg(feature = "macros")] {
+/// const CRATE: Cuboid<u8> = analog_literal! { strict; u8;
+///         +--+
+///        /  /|
+///       +--+ +
+///       |  |/
+///       +--+
+/// };
+/// assert_eq!(CRATE.volume(), 1);
+/// # }
+/// ```
+///
+/// # Comments
+///
+/// Comments of any kind (even doc comments) can go anywhere inside a literal,
+/// whether that's in the middle of a line, inside a rectangle, or on the faces
+/// of a cuboid:
+///
+/// ```rust
+/// # use analog_literals::analog_literal;
+/// let fuse = analog_literal! { I--/** lit here */------I };
+/// assert_eq!(fuse, 4);
+///
+/// let gift = analog_literal! {
+///       +------+          /// top
+///      / /**/ /|          /// right
+///     +------+ +
+///     | /**/ |/           /// front
+///     +------+
+/// };
+/// assert_eq!(gift.volume(), 3);
+/// ```
+///
+/// # Nothing At All
+///
+/// Sometimes the right amount of space is none. Rectangles can be drawn with
+/// no height (by stacking their top and bottom edges), or no width (by
+/// squeezing their sides together), and cuboids can be drawn with no height
+/// (by leaving out their front and right-hand faces entirely):
+///
+/// ```rust
+/// # use analog_literals::{analog_literal, Cuboid, Rectangle};
+/// let crack = analog_literal! {
+///     +------+
+///     +------+
+/// };
+/// assert_eq!(crack, Rectangle { w: 3, h: 0 });
+///
+/// let gap = analog_literal! {
+///     ++
+///     ||
+///     ||
+///     ++
+/// };
+/// assert_eq!(gap, Rectangle { w: 0, h: 2 });
+///
+/// let sheet_of_paper = analog_literal! {
+///        +------+
+///       /      /
+///      /      /
+///     +------+
+/// };
+/// assert_eq!(sheet_of_paper, Cuboid { w: 3, h: 0, l: 2 });
+/// ```
+///
+/// Sadly, cuboids with no width or length are out of reach. A cuboid with no
+/// length looks exactly like a rectangle, and one with no width would need a
+/// `//` in the drawing, which Rust is quite insistent is a comment.
+///
+/// Strings are a little more forgiving: [`Cuboid::parse`] reads a rectangle as
+/// a cuboid with no length, and doesn't mind a space between the `+ +` (and
+/// `/ /`) of a cuboid with no width. That's also how they get drawn, so every
+/// cuboid makes it through a round trip via `to_string` in one piece.
+///
+/// # Families
+///
+/// Related literals look best side by side (or rather, one on top of the
+/// other). Any number of literals can be drawn in a single invocation by
+/// separating them with `;;`, which returns all of them as a tuple (this
+/// requires the `macros` feature):
+///
+/// ```rust
+/// # use analog_literals::{analog_literal, Cuboid, ScalarCuboid};
+/// # #[cfg(feature = "macros")] {
+/// const SHIPPING_BOXES: (Cuboid<u32>, Cuboid<u32>, Cuboid<u32>) = analog_literal! { u32;
+///       +--+
+///      /  /|
+///     +--+ +
+///     |  |/
+///     +--+
+///     ;;
+///       +----+
+///      /    /|
+///     +----+ |
+///     |    | +
+///     |    |/
+///     +----+
+///     ;;
+///        +------+
+///       /      /|
+///      /      / |
+///     +------+  |
+///     |      |  +
+///     |      | /
+///     |      |/
+///     +------+
+/// };
+///
+/// let (small, medium, large) = SHIPPING_BOXES;
+/// assert_eq!(small.volume(), 1);
+/// assert_eq!(medium.volume(), 4);
+/// assert_eq!(large.volume(), 18);
+/// # }
+/// ```
+///
+/// The literals don't need to be the same shape, or even have the same number
+/// of dimensions. Any prefix in front of the first literal (e.g: `u32;` or
+/// `strict;`) applies to all of them.
+///
+/// ```rust
+/// # use analog_literals::{analog_literal, Rectangle};
+/// # #[cfg(feature = "macros")] {
+/// let (fuse, stamp) = analog_literal! {
+///     I----I
+///     ;;
+///     +--+
+///     |  |
+///     +--+
+/// };
+/// assert_eq!(fuse, 2);
+/// assert_eq!(stamp, Rectangle { w: 1, h: 1 });
+/// # }
+/// ```
+#[macro_export]
+macro_rules! analog_literal {
+    (I $($tail:tt)+) => {
+        $crate::__analog_literal!(@1D [] ({ 0 }) ; $($tail)+)
+    };
+
+    (II) => {
+        0
+    };
+
+    (II ; ; $($rest:tt)+) => {
+        $crate::__private::analog_literal_join! { $crate (0) [] $($rest)+ }
+    };
+
+    (+ $($tail:tt)+) => {
+        $crate::__analog_literal! {
+            @2D_TOP [usize] (
+                { 0 },
+                { 0 },
+            )
+
+            ; $($tail)+
+        }
+    };
+
+    (in $u:ty ; $($tail:tt)+) => {
+        $crate::unit::Tagged::<_, $u>::new($crate::analog_literal!($($tail)+))
+    };
+
+    (strict ; $($tail:tt)+) => {
+        $crate::__private::analog_literal_strict! { $crate $($tail)+ }
+    };
+
+    (line ; $($tail:tt)+) => {
+        $crate::Line($crate::analog_literal!($($tail)+))
+    };
+
+    ($t:ty ; I $($tail:tt)+) => {
+        $crate::__analog_literal!(@1D [$t] ({ (0 as $t) }) ; $($tail)+)
+    };
+
+    ($t:ty ; II) => {
+        (0 as $t)
+    };
+
+    ($t:ty ; II ; ; $($rest:tt)+) => {
+        $crate::__private::analog_literal_join! { $crate ((0 as $t)) [$t] $($rest)+ }
+    };
+
+    ($t:ty ; + $($tail:tt)+) => {
+        $crate::__analog_literal! {
+            @2D_TOP [$t] (
+                { (0 as $t) },
+                { 0 },
+            )
+
+            ; $($tail)+
+        }
+    };
+}
+
+/// Extract a whole tree of [`Rectangle`]s from a drawing of rectangles nested
+/// inside other rectangles.
+///
+/// The top-level rectangle in the drawing becomes the root of the returned
+/// [`Layout`], with any rectangles drawn inside it showing up as its
+/// `children` (and any rectangles drawn inside _those_ as their `children`,
+/// and so on). Children record their offset from the top-left corner of
+/// their parent's interior, measured in the same `--` and `|` units as the
+/// rectangles themselves.
+///
+/// Nested rectangles must not share walls with their parents (or with each
+/// other), and all rectangles must be drawn inside a single outermost
+/// rectangle. As always, comments can be sprinkled anywhere for flavor.
+///
+/// Requires the `macros` feature.
+///
+/// # Example
+///
+/// At long last, WYSIWYG GUIs in Rust:
+///
+/// ```rust
+/// # use analog_literals::{analog_layout, Layout};
+/// const MODAL_POPUP: Layout = analog_layout! {
+///     +----------------------------------------------------------+
+///     |                                                          |
+///     |       /*----------------------------------------*/       |
+///     |       /* Do you accept the terms and conditions */       |
+///     |       /* and agree to sell your eternal soul to */       |
+///     |       /*        the dark lord beelzebub?        */       |
+///     |       /*----------------------------------------*/       |
+///     |                                                          |
+///     |  +----------+                          +--------------+  |
+///     |  |/* Yes  */|                          |/* Also Yes */|  |
+///     |  +----------+                          +--------------+  |
+///     +----------------------------------------------------------+
+/// };
+///
+/// let [yes, also_yes] = MODAL_POPUP.children else { unreachable!() };
+///
+/// assert_eq!((yes.x, yes.y), (1, 7));
+/// assert_eq!((yes.rect.w, yes.rect.h), (5, 1));
+///
+/// assert_eq!((also_yes.x, also_yes.y), (20, 7));
+/// assert_eq!((also_yes.rect.w, also_yes.rect.h), (7, 1));
+/// ```
+///
+/// Since there's no such thing as half a `--`, a child that's been indented an
+/// odd number of columns is a compile error.
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! analog_layout {
+    ($($tt:tt)*) => {
+        $crate::__private::analog_layout! { $crate $($tt)* }
+    };
+}
+
+/// Like a 2D [`analog_literal!`], except the `/* comment */` written inside
+/// the rectangle is captured as well, producing a [`LabeledRectangle`].
+///
+/// The rectangle must contain exactly one comment, which (sans the `/*` and
+/// `*/` markers and any surrounding whitespace) becomes the label.
+///
+/// Since comments are stripped out long before macros get a look at their
+/// input, this macro has to go back and re-read the source code of the
+/// invocation. As such, it only works on literals written out by hand, and
+/// not on literals cooked up by other macros.
+///
+/// Requires the `macros` feature.
+///
+/// # Example
+///
+/// No more maintaining a separate string constant for every caption:
+///
+/// ```rust
+/// # use analog_literals::{analog_labeled, LabeledRectangle};
+/// const SUBMIT: LabeledRectangle = analog_labeled! {
+///     +--------------------+
+///     |                    |
+///     |   /* Submit */     |
+///     |                    |
+///     +--------------------+
+/// };
+///
+/// assert_eq!(SUBMIT.label, "Submit");
+/// assert_eq!((SUBMIT.rect.w, SUBMIT.rect.h), (10, 3));
+/// ```
+///
+/// # Renamed Dependencies
+///
+/// Since this macro re-reads its own invocation, it can't be wrapped up in a
+/// `macro_rules!` that tells it where to find `analog_literals`, so it assumes
+/// `::analog_literals`. If the crate goes by some other name, put `crate =
+/// <path>;` in front of the drawing:
+///
+/// ```rust
+/// # extern crate analog_literals as al;
+/// const OK: al::LabeledRectangle = al::analog_labeled! { crate = al;
+///     +--------+
+///     |/* OK */|
+///     +--------+
+/// };
+///
+/// assert_eq!(OK.label, "OK");
+/// ```
+#[cfg(feature = "macros")]
+pub use analog_literals_macros::analog_labeled;
+
+/// Like a 2D [`analog_literal!`], except the rectangle's position in the
+/// drawing is preserved as well, producing a [`PositionedRectangle`].
+///
+/// Positions are measured from an origin, marked by a lone `+` on the first
+/// row of the drawing: `x` counts the `--` of whitespace between the `+` and
+/// the rectangle's left edge, while `y` counts how many rows down from the `+`
+/// the rectangle's top edge is. After all, alignment carries real visual
+/// information, and it would be a shame to throw it away.
+///
+/// Everything is measured relative to the drawing itself, so re-indenting the
+/// whole thing (e.g: courtesy of rustfmt) doesn't move the rectangle an inch.
+/// Leave out the `+`, and the rectangle is its own origin (which makes for a
+/// rather boring `(0, 0)`).
+///
+/// Requires the `macros` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # use analog_literals::{analog_positioned, PositionedRectangle};
+/// const OK_BUTTON: PositionedRectangle = analog_positioned! {
+///     +
+///
+///                 +--------+
+///                 |/* OK */|
+///                 +--------+
+/// };
+///
+/// assert_eq!((OK_BUTTON.x, OK_BUTTON.y), (6, 2));
+/// assert_eq!((OK_BUTTON.w, OK_BUTTON.h), (4, 1));
+/// ```
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! analog_positioned {
+    ($($tt:tt)*) => {
+        $crate::__private::analog_positioned! { $crate $($tt)* }
+    };
+}
+
+/// Sketch out a whole scene of rectangles in one go, producing an array of
+/// [`PositionedRectangle`]s.
+///
+/// Each rectangle's position is determined by where it's drawn, measured the
+/// same way as [`analog_positioned!`]: from a lone `+` on the first row if
+/// there is one, or else from the top-left corner of the scene as a whole (i.e:
+/// the topmost row, and the leftmost column, that have anything drawn on
+/// them). Either way, re-indenting the scene leaves every position as-is.
+///
+/// Rectangles are returned in reading order (top to bottom, left to right),
+/// and must be drawn apart from one another. For rectangles drawn _inside_
+/// other rectangles, check out [`analog_layout!`].
+///
+/// Requires the `macros` feature.
+///
+/// # Example
+///
+/// Level designers, rejoice:
+///
+/// ```rust
+/// # use analog_literals::{analog_scene, PositionedRectangle};
+/// const LEVEL_1: [PositionedRectangle; 4] = analog_scene! {
+///                                 +----+
+///                 +----+          |    |
+///     +--+        |    |          |    |
+///     |  |        |    |          |    |
+///     +--+        +----+          +----+
+///
+///   +------------------------------------+
+///   +------------------------------------+
+/// };
+///
+/// let [big_crate, small_crate, player, floor] = LEVEL_1;
+///
+/// assert_eq!((big_crate.x, big_crate.y), (15, 0));
+/// assert_eq!((small_crate.x, small_crate.y), (7, 1));
+/// assert_eq!((player.x, player.y), (1, 2));
+/// assert_eq!((floor.x, floor.y, floor.w, floor.h), (0, 6, 18, 0));
+/// ```
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! analog_scene {
+    ($($tt:tt)*) => {
+        $crate::__private::analog_scene! { $crate $($tt)* }
+    };
+}
+
+/// Draw a [`Flowchart`](flowchart::Flowchart) out of boxes and arrows.
+///
+/// There are two kinds of boxes:
+///
+/// - Regular `|`-walled rectangles are [processes](flowchart::NodeKind::Process)
+/// - `<` and `>`-walled rectangles are [decisions](flowchart::NodeKind::Decision)
+///
+/// Whatever's written inside a box becomes its label (so long as it's made up
+/// of valid Rust tokens, and isn't a comment).
+///
+/// Arrows are drawn using `-` and `|`, with `+` for corners, and one of `>`,
+/// `<`, `v`, or `^` for a head. Arrows have to start and end right up against
+/// the edges of a box, and may be labeled by writing a word somewhere along
+/// their shaft. Several arrows can share a single shaft, so long as they all
+/// start at the same box. Lines that don't lead anywhere are compile errors.
+///
+/// Requires the `macros` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # use analog_literals::analog_flowchart;
+/// let chart = analog_flowchart! {
+///     +-----------+
+///     |   start   |
+///     +-----------+
+///           |
+///           v
+///     +-----------+         +---------+
+///     < is it on? >---no--->| flip it |
+///     +-----------+         +---------+
+///           |                    |
+///          yes                   |
+///           v                    |
+///     +-----------+              |
+///     |   done    |<-------------+
+///     +-----------+
+/// };
+///
+/// let mut node = 0;
+/// let mut switch = false;
+/// while chart.nodes[node].label != "done" {
+///     node = match chart.nodes[node].label {
+///         "is it on?" => chart.next(node, Some(if switch { "yes" } else { "no" })),
+///         "flip it" => {
+///             switch = !switch;
+///             chart.next(node, None)
+///         }
+///         _ => chart.next(node, None),
+///     }
+///     .unwrap();
+/// }
+///
+/// assert!(switch);
+/// ```
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! analog_flowchart {
+    ($($tt:tt)*) => {
+        $crate::__private::analog_flowchart! { $crate $($tt)* }
+    };
+}
+
+/// Turn a diagram of labeled boxes connected by arrows into a
+/// [`Graph`](graph::Graph).
+///
+/// Boxes are regular `|`-walled rectangles, with whatever's written inside
+/// them becoming their label. Arrows are drawn using `-` and `|`, with `+` for
+/// corners, and one of `>`, `<`, `v`, or `^` for a head.
+///
+/// Every arrow has to start and end right up against the edge of a box. An
+/// arrow that doesn't quite make it (or a line without a head) is a compile
+/// error, so the diagram is guaranteed to be telling the truth.
+///
+/// Requires the `macros` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # use analog_literals::{analog_graph, graph::Graph};
+/// const DEPS: Graph<4, 4> = analog_graph! {
+///     +-----+     +------+     +-------+
+///     | cli |---->| core |<----| tests |
+///     +-----+     +------+     +-------+
+///        |           ^
+///        |           |
+///        v           |
+///     +--------+     |
+///     | render |-----+
+///     +--------+
+/// };
+///
+/// assert_eq!(DEPS.nodes, ["cli", "core", "tests", "render"]);
+/// assert_eq!(DEPS.edges, [(0, 1), (2, 1), (3, 1), (0, 3)]);
+///
+/// let cli = DEPS.find("cli").unwrap();
+/// assert_eq!(DEPS.successors(cli).count(), 2);
+/// ```
+///
+/// Forgetting to hook something up is an error:
+///
+/// ```compile_fail
+/// # use analog_literals::analog_graph;
+/// let deps = analog_graph! {
+///     +-----+     +------+
+///     | cli |---->| core |---->
+///     +-----+     +------+
+/// };
+/// ```
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! analog_graph {
+    ($($tt:tt)*) => {
+        $crate::__private::analog_graph! { $crate $($tt)* }
+    };
+}
+
+/// Turn a good old fashioned ASCII table into a [`Table`](table::Table) of
+/// strings.
+///
+/// The table must start with a `+---+---+` border, which decides where all
+/// the columns are. The first row is the header row, and must be followed by a
+/// separator (using either `-` or `=`). Every row after that is a row of the
+/// table, with any further separators being purely decorative.
+///
+/// Every `|` and `+` has to line up with the columns of the top border, and
+/// every row has to be exactly as wide as the table. In other words: if it
+/// doesn't look like a table, it doesn't compile.
+///
+/// Each cell holds whatever text was written in it (sans any surrounding
+/// whitespace). Seeing as the table still has to be made up of valid Rust
+/// tokens, don't go using any unbalanced quotes or brackets.
+///
+/// Requires the `macros` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # use analog_literals::{analog_table, table::Table};
+/// const COMMANDS: Table<2, 3> = analog_table! {
+///     +---------+----------------------+
+///     | command | what it does         |
+///     +=========+======================+
+///     | build   | builds the thing     |
+///     | test    | tests the thing      |
+///     | clean   | deletes 5 GB of junk |
+///     +---------+----------------------+
+/// };
+///
+/// assert_eq!(COMMANDS.headers, ["command", "what it does"]);
+/// assert_eq!(COMMANDS.rows[1], ["test", "tests the thing"]);
+/// assert_eq!(COMMANDS.column("what it does"), Some(1));
+/// ```
+///
+/// Misaligned columns are compile errors:
+///
+/// ```compile_fail
+/// # use analog_literals::analog_table;
+/// let commands = analog_table! {
+///     +---------+------------------+
+///     | command | what it does     |
+///     +=========+==================+
+///     | build  | builds the thing  |
+///     +---------+------------------+
+/// };
+/// ```
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! analog_table {
+    ($($tt:tt)*) => {
+        $crate::__private::analog_table! { $crate $($tt)* }
+    };
+}
+
+/// Turn a drawing of a keyboard into an array of [`Key`](keyboard::Key)s.
+///
+/// Each keycap is a rectangle, with its legend written inside. Neighbouring
+/// keys can share walls, and rows of keys can be staggered however you like.
+/// Keys are assigned to rows based on where their top edge is, and are
+/// returned in reading order (top to bottom, left to right).
+///
+/// Legends still have to be made up of valid Rust tokens, so your `'` and `\`
+/// keys will have to settle for being spelled out.
+///
+/// Requires the `macros` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # use analog_literals::{analog_keyboard, keyboard::Key};
+/// const LAYOUT: [Key; 8] = analog_keyboard! {
+///     +----+----+----+
+///     | Q  | W  | E  |
+///     +-+--+-+--+-+--+-+
+///       | A  | S  | D  |
+///     +-+----+-+--+-+--+
+///     | Shift  | Z  |
+///     +--------+----+
+/// };
+///
+/// let shift = LAYOUT[6];
+/// assert_eq!((shift.row, shift.col, shift.label, shift.width), (2, 0, "Shift", 4));
+///
+/// let d = LAYOUT[5];
+/// assert_eq!((d.row, d.col, d.label, d.width), (1, 2, "D", 2));
+/// ```
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! analog_keyboard {
+    ($($tt:tt)*) => {
+        $crate::__private::analog_keyboard! { $crate $($tt)* }
+    };
+}
+
+/// Read a guitar chord off of a chord box, producing a
+/// [`Chord`](music::Chord).
+///
+/// Chord boxes are drawn the way guitarists are used to reading them: six
+/// vertical strings (low E on the left), with the nut along the top and a
+/// `+--+` line under each fret. Fingers are marked on the strings using any of
+/// `*`, `o`, or `@`. If more than one finger ends up on the same string, the
+/// one furthest down the neck wins, just like on a real guitar.
+///
+/// Strings that aren't fretted have to be marked above the nut, with either
+/// an `o` (open) or an `x` (muted).
+///
+/// To play further up the neck, label the first fret with its number (e.g:
+/// `5fr`), by writing it to the right of the box.
+///
+/// Requires the `macros` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # use analog_literals::{analog_chord, music::Chord};
+/// const C_MAJOR: Chord = analog_chord! {
+///     x        o     o
+///     +==+==+==+==+==+
+///     |  |  |  |  *  |
+///     +--+--+--+--+--+
+///     |  |  *  |  |  |
+///     +--+--+--+--+--+
+///     |  *  |  |  |  |
+///     +--+--+--+--+--+
+/// };
+///
+/// assert_eq!(C_MAJOR.frets, [None, Some(3), Some(2), Some(0), Some(1), Some(0)]);
+///
+/// const A_MAJOR_BARRE: Chord = analog_chord! {
+///     +--+--+--+--+--+
+///     *  *  *  *  *  *  5fr
+///     +--+--+--+--+--+
+///     |  |  |  *  |  |
+///     +--+--+--+--+--+
+///     |  *  *  |  |  |
+///     +--+--+--+--+--+
+/// };
+///
+/// assert_eq!(A_MAJOR_BARRE.frets, [Some(5), Some(7), Some(7), Some(6), Some(5), Some(5)]);
+/// ```
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! analog_chord {
+    ($($tt:tt)*) => {
+        $crate::__private::analog_chord! { $crate $($tt)* }
+    };
+}
+
+/// Pick out keys on a drawing of a piano, producing a set of
+/// [`PianoKeys`](music::PianoKeys).
+///
+/// The drawing must start on a C, and end with a `+---+---+` row underneath
+/// the white keys. Black keys hang down from the top of the drawing, ending in
+/// a `+-+`, and must straddle the wall between two white keys (C-D, D-E, F-G,
+/// G-A, or A-B, as per tradition). Keys are marked by putting a `*`, `o`, or
+/// `@` anywhere on them. The drawing can be as many octaves long as you'd
+/// like, so long as you don't go past the top of the MIDI range.
+///
+/// Requires the `macros` feature.
+///
+/// # Example
+///
+/// Finally, a unit test where you can _see_ the chord being tested:
+///
+/// ```rust
+/// # use analog_literals::{analog_piano, music::PianoKeys};
+/// const C_MINOR: PianoKeys = analog_piano! {
+///     |  | |  | |  |  | |  | |  | |  |
+///     |  | |  |*|  |  | |  | |  | |  |
+///     |  +-+  +-+  |  +-+  +-+  +-+  |
+///     | * |    |   |   | *  |    |   |
+///     +---+----+---+---+----+----+---+
+/// };
+///
+/// assert_eq!(C_MINOR.semitones().collect::<Vec<_>>(), [0, 3, 7]);
+/// assert_eq!(C_MINOR.midi_notes(4).collect::<Vec<_>>(), [60, 63, 67]);
+/// ```
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! analog_piano {
+    ($($tt:tt)*) => {
+        $crate::__private::analog_piano! { $crate $($tt)* }
+    };
+}
+
+/// Draw a falling block game piece (or any other
+/// [`Polyomino`](polyomino::Polyomino) up to 8x8 cells big) using `#` for
+/// filled in cells and `.` for empty ones.
+///
+/// Spaces between cells are ignored, so feel free to space things out to get
+/// the proportions right. Every row has to have the same number of cells, and
+/// any rows or columns of nothing but `.` are trimmed off.
+///
+/// Requires the `macros` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # use analog_literals::{analog_polyomino, polyomino::Polyomino};
+/// const T: Polyomino = analog_polyomino! {
+///     . . . .
+///     . # # #
+///     . . # .
+/// };
+///
+/// assert_eq!((T.bounds.w, T.bounds.h), (3, 2));
+/// assert_eq!(T.size(), 4);
+///
+/// const T_CW: Polyomino = analog_polyomino! {
+///     . . # .
+///     . # # .
+///     . . # .
+/// };
+///
+/// assert_eq!(T.rotate_cw(), T_CW);
+/// assert_eq!(T.rotations()[3].rotate_cw(), T);
+/// ```
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! analog_polyomino {
+    ($($tt:tt)*) => {
+        $crate::__private::analog_polyomino! { $crate $($tt)* }
+    };
+}
+
+/// Draw a two-set [`Venn`](venn::Venn) diagram, with the size of each region
+/// written right inside it.
+///
+/// Since `\` isn't a valid Rust token, the circles are drawn using `(` and `)`.
+/// Every row of the diagram has to cut through both circles, either as
+/// `( ( ) )` (where the circles overlap) or as `( ) ( )` (where they don't).
+/// Anything written in the gap between two `( ) ( )` circles is outside both
+/// of them, which is a compile error.
+///
+/// The size of each region is the sum of all the numbers written inside it,
+/// plus one for every `*` of shading. Any other words are treated as labels,
+/// and don't count towards anything.
+///
+/// Requires the `macros` feature.
+///
+/// # Example
+///
+/// Finally, analytics code where the docs can't lie:
+///
+/// ```rust
+/// # use analog_literals::{analog_venn, venn::Venn};
+/// const ACCOUNTS: Venn = analog_venn! {
+///       (  users  ) (  admins  )
+///      (          ( )           )
+///     (    1_200   ( 3 )    7    )
+///      (          ( )           )
+///       (         ) (           )
+/// };
+///
+/// assert_eq!(ACCOUNTS.left(), 1_203);
+/// assert_eq!(ACCOUNTS.intersection(), 3);
+/// assert_eq!(ACCOUNTS.union(), 1_210);
+/// ```
+///
+/// Or if counting is too much work, just shade things in:
+///
+/// ```rust
+/// # use analog_literals::{analog_venn, venn::Venn};
+/// const SHADED: Venn = analog_venn! {
+///      (      (    )      )
+///     (  ***  ( ** )   *   )
+///      (      (    )      )
+/// };
+///
+/// assert_eq!((SHADED.left_only, SHADED.both, SHADED.right_only), (3, 2, 1));
+/// ```
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! analog_venn {
+    ($($tt:tt)*) => {
+        $crate::__private::analog_venn! { $crate $($tt)* }
+    };
+}
+
+/// Draw a pair of [`Interval`](interval::Interval)s on a shared ruler, and let
+/// the drawing answer the age-old question of "do these two overlap?".
+///
+/// Each interval gets its own row, drawn as a `[`, some `--`, and a `]`. The
+/// ruler starts at the leftmost `[`, and each interval starts however many
+/// `--` of whitespace there are between the ruler's start and its own `[`. It's
+/// as long as the number of `--` inside it. Intervals that just barely touch
+/// are drawn with the `[` of one right underneath the last `-` of the other.
+///
+/// Since everything is measured relative to the drawing itself, re-indenting
+/// the whole thing (e.g: courtesy of rustfmt) doesn't change a thing.
+///
+/// Requires the `macros` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # use analog_literals::{analog_intervals, interval::Interval};
+/// let (standup, lunch) = analog_intervals! {
+///     [----------]
+///             [--------]
+/// };
+///
+/// assert_eq!(standup, Interval { start: 0, end: 5 });
+/// assert_eq!(lunch, Interval { start: 4, end: 8 });
+/// assert_eq!(standup.overlap(&lunch), Some(Interval { start: 4, end: 5 }));
+///
+/// let (heap, stack) = analog_intervals! {
+///     [------]
+///                 [----]
+/// };
+///
+/// assert!(!heap.overlaps(&stack));
+/// assert_eq!(heap.gap(&stack), 3);
+/// ```
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! analog_intervals {
+    ($($tt:tt)*) => {
+        $crate::__private::analog_intervals! { $crate $($tt)* }
+    };
+}
+
+/// Draw a flight of [`Stairs`](stairs::Stairs), and have the compiler make
+/// sure every step is the same size.
+///
+/// The staircase is traced starting from the `+` at the very bottom, going up
+/// a riser (a column of `|`), along a tread (a row of `--`), up the next
+/// riser, and so on, with a `+` at every corner. Stairs can go up to the left
+/// or to the right, but not both.
+///
+/// Since nobody wants to trip on the way up, a staircase with mismatched
+/// steps (or anything else drawn next to it) is a compile error.
+///
+/// Requires the `macros` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # use analog_literals::{analog_stairs, stairs::Stairs};
+/// const FRONT_PORCH: Stairs = analog_stairs! {
+///                   +------+
+///                   |
+///                   |
+///            +------+
+///            |
+///            |
+///     +------+
+///     |
+///     |
+///     +
+/// };
+///
+/// assert_eq!((FRONT_PORCH.steps, FRONT_PORCH.rise, FRONT_PORCH.run), (3, 2, 3));
+/// assert_eq!((FRONT_PORCH.total_w, FRONT_PORCH.total_h), (9, 6));
+/// ```
+///
+/// ```rust,compile_fail
+/// # use analog_literals::analog_stairs;
+/// let lawsuit = analog_stairs! {
+///                   +------+
+///                   |
+///            +------+
+///            |
+///            |
+///     +------+
+///     |
+///     |
+///     +
+/// };
+/// ```
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! analog_stairs {
+    ($($tt:tt)*) => {
+        $crate::__private::analog_stairs! { $crate $($tt)* }
+    };
+}
+
+/// Keep a struct's layout diagram honest by making the compiler check it.
+///
+/// The diagram is a row (or several rows) of boxes, one per field, each
+/// containing the field's name. Every `--` along a box's edge is one byte,
+/// and fields are laid out in reading order (top to bottom, left to right).
+/// Padding can be drawn using a box containing a lone `_`.
+///
+/// For every named field, a pair of `OFFSET_<FIELD>` and `SIZE_<FIELD>`
+/// constants is emitted, along with `const` assertions that the real
+/// [`offset_of!`](core::mem::offset_of) and size of the field match the
+/// drawing. The
+/// total size of the drawing is also checked against
+/// [`size_of`](core::mem::size_of) the type, so forgetting to draw a field
+/// (or drawing one too many) is a compile error.
+///
+/// The constants are private by default. Stick a visibility in front of the
+/// type name (e.g: `pub Header => ...`) to change that.
+///
+/// Requires the `macros` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # use analog_literals::analog_repr;
+/// #[repr(C)]
+/// struct Header {
+///     tag: u32,
+///     len: u16,
+///     crc: u64,
+/// }
+///
+/// analog_repr! {
+///     pub Header =>
+///     +--------+----+----+----------------+
+///     |  tag   |len | _  |      crc       |
+///     +--------+----+----+----------------+
+/// }
+///
+/// assert_eq!(OFFSET_CRC, 8);
+/// assert_eq!(SIZE_LEN, 2);
+/// ```
+///
+/// Forget about the padding, and the compiler will be sure to remind you:
+///
+/// ```compile_fail
+/// # use analog_literals::analog_repr;
+/// # #[repr(C)]
+/// # struct Header {
+/// #     tag: u32,
+/// #     len: u16,
+/// #     crc: u64,
+/// # }
+/// analog_repr! {
+///     Header =>
+///     +--------+----+----------------+
+///     |  tag   |len |      crc       |
+///     +--------+----+----------------+
+/// }
+/// ```
+///
+/// The same goes for drawing a field too small, even when padding makes up the
+/// difference:
+///
+/// ```compile_fail
+/// # use analog_literals::analog_repr;
+/// #[repr(C)]
+/// struct Header {
+///     tag: u32,
+///     len: u32,
+/// }
+///
+/// analog_repr! {
+///     Header =>
+///     +----+----+--------+
+///     |tag | _  |  len   |
+///     +----+----+--------+
+/// }
+/// ```
+///
+/// Every box has to hold a field name (or a `_`), and there can't be anything
+/// else lying around the diagram:
+///
+/// ```compile_fail
+/// # use analog_literals::analog_repr;
+/// # #[repr(C)]
+/// # struct Header {
+/// #     tag: u32,
+/// # }
+/// analog_repr! {
+///     Header =>
+///     +--------+
+///     | 2 + 2  |
+///     +--------+
+/// }
+/// ```
+///
+/// Since the boxes have to be wide enough to fit the field's name, this works
+/// best for structs that were named by someone with a lot of `u64`s and very
+/// little patience for vowels.
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! analog_repr {
+    ($($tt:tt)*) => {
+        $crate::__private::analog_repr! { $crate $($tt)* }
+    };
+}
+
+/// Like [`analog_literal!`], except the drawing lives in a separate file.
+///
+/// Some drawings are just too majestic to be crammed into the middle of a
+/// source file, and some artists would rather not have to learn Rust to edit
+/// them. Just like with [`include_str!`], the path is relative to the file the
+/// macro is called from, and the drawing goes through exactly the same
+/// validation as if it had been written inline (comments and all).
+///
+/// Since the file still has to be made out of valid Rust tokens, drawings
+/// can't contain any stray `\`, `'`, or unbalanced brackets.
+///
+/// Requires the `macros` feature.
+///
+/// # Example
+///
+/// Given a `diagrams/rig.txt` (right next to `src/`) containing the mining rig
+/// from the [crate-level docs](crate), called from `src/lib.rs`:
+///
+/// ```rust
+/// # use analog_literals::{analog_literal_from_file, Cuboid};
+/// const MINING_RIG: Cuboid = analog_literal_from_file!("../diagrams/rig.txt");
+///
+/// assert_eq!(MINING_RIG.volume(), 336);
+/// ```
+///
+/// Missing files (and bad drawings) are compile errors, just like they would
+/// be with [`include_str!`]:
+///
+/// ```compile_fail
+/// # use analog_literals::{analog_literal_from_file, Cuboid};
+/// const MINING_RIG: Cuboid = analog_literal_from_file!("diagrams/lambo.txt");
+/// ```
+#[cfg(feature = "macros")]
+#[macro_export]
+macro_rules! analog_literal_from_file {
+    ($($tt:tt)*) => {
+        $crate::__private::analog_literal_from_file! { $crate $($tt)* }
+    };
+}
+
+/// Like `#[derive(Debug)]`, except any analog literals get drawn, not just
+/// printed.
+///
+/// Every field that's a [`Line`], [`Rectangle`], or [`Cuboid`] (or a reference
+/// to one) gets drawn in place of its usual `Debug` output. Every other field
+/// is printed exactly like `#[derive(Debug)]` would print it, so it's fine to
+/// mix and match.
+///
+/// Pretty-printing (`{:#?}`) draws each literal on its own lines, while
+/// regular old `{:?}` squishes each drawing onto a single line, which is
+/// admittedly more of a gesture than a drawing.
+///
+/// Works on structs with named fields, tuple structs, and unit structs. Any
+/// type parameters need to implement `Debug`, same as with `#[derive(Debug)]`.
+/// Enums and unions are right out:
+///
+/// ```compile_fail
+/// use analog_literals::AnalogDebug;
+///
+/// #[derive(AnalogDebug)]
+/// enum Shape {
+///     Nothing,
+/// }
+/// ```
+///
+/// If `analog_literals` has been renamed, point the derive at it using
+/// `#[analog_debug(crate = <path>)]`.
+///
+/// Requires the `derive` feature.
+///
+/// # Example
+///
+/// ```rust
+/// use analog_literals::{analog_literal, AnalogDebug, Rectangle};
+///
+/// #[derive(AnalogDebug)]
+/// struct Room {
+///     name: &'static str,
+///     floor: Rectangle,
+/// }
+///
+/// let closet = Room {
+///     name: "closet",
+///     floor: analog_literal! {
+///         +----+
+///         |    |
+///         +----+
+///     },
+/// };
+///
+/// assert_eq!(
+///     format!("{:?}", closet),
+///     r#"Room { name: "closet", floor: +----+ |    | +----+ }"#
+/// );
+/// assert!(format!("{:#?}", closet).contains("floor:\n    +----+\n"));
+/// ```
+///
+/// Where `{:#?}` prints:
+///
+/// ```text
+/// Room {
+///     name: "closet",
+///     floor:
+///     +----+
+///     |    |
+///     +----+,
+/// }
+/// ```
+#[cfg(feature = "derive")]
+pub use analog_literals_macros::AnalogDebug;
+
+/// Like [`analog_literal!`], except the dimensions of the resulting literal
+/// are baked right into its type.
+///
+/// - 1D literals produce a [`typed::Line<L>`]
+/// - 2D literals produce a [`typed::Rect<W, H>`]
+/// - 3D literals produce a [`typed::Cuboid<W, H, L>`]
+///
+/// This makes it possible to size arrays using nothing but a drawing, and to
+/// get a good old fashioned type error whenever two drawings that _should_ be
+/// the same size aren't.
+///
+/// # Example
+///
+/// ```rust
+/// # use analog_literals::{analog_literal_typed, typed::Rect};
+/// fn blit<const W: usize, const H: usize>(_sprite: Rect<W, H>) -> [[u8; W]; H] {
+///     [[0xff; W]; H]
+/// }
+///
+/// let sprite = blit(analog_literal_typed! {
+///     +------+
+///     |      |
+///     |      |
+///     +------+
+/// });
+///
+/// assert_eq!(sprite, [[0xff; 3]; 2]);
+/// ```
+#[macro_export]
+macro_rules! analog_literal_typed {
+    ($($tail:tt)+) => {
+        $crate::__analog_literal!(@classify [@typed] { $($tail)+ } ; $($tail)+)
+    };
+}
+
+/// Declare an array with the same dimensions as an analog literal.
+///
+/// Works just like the regular `[expr; N]` array syntax, except instead of
+/// repeating the dimensions of the array numerically, you just draw them.
+///
+/// - 1D literals expand to `[expr; L]`
+/// - 2D literals expand to `[[expr; W]; H]`
+/// - 3D literals expand to `[[[expr; W]; H]; L]`
+///
+/// # Example
+///
+/// ```rust
+/// # use analog_literals::analog_array;
+/// let mut framebuffer = analog_array![b' '; +--------+
+///                                           |        |
+///                                           |        |
+///                                           +--------+];
+///
+/// framebuffer[1][3] = b'#';
+///
+/// assert_eq!(framebuffer.len(), 2);
+/// assert_eq!(framebuffer[0].len(), 4);
+/// ```
+///
+/// Just like regular arrays, `expr` has to be `Copy` (or a constant).
+#[macro_export]
+macro_rules! analog_array {
+    ($init:expr ; $($tail:tt)+) => {
+        $crate::__analog_literal!(@classify [@array ($init)] { $($tail)+ } ; $($tail)+)
+    };
+}
+
+/// Lay out a matrix exactly as a mathematician would write it down, producing
+/// a `[[i64; C]; R]`.
+///
+/// Each row of the matrix is drawn as a series of `|`-separated cells, each
+/// containing a single integer literal. Rows can optionally be separated with
+/// `+---+---+` style dividers, for those who really like to color inside the
+/// lines.
+///
+/// Every row must have the same number of cells. Jagged matrices are met with
+/// a compile error (albeit a somewhat cryptic one).
+///
+/// # Example
+///
+/// ```rust
+/// # use analog_literals::analog_matrix;
+/// const SOBEL_X: [[i64; 3]; 3] = analog_matrix! {
+///     +----+----+----+
+///     | -1 |  0 |  1 |
+///     +----+----+----+
+///     | -2 |  0 |  2 |
+///     +----+----+----+
+///     | -1 |  0 |  1 |
+///     +----+----+----+
+/// };
+///
+/// const IDENTITY: [[i64; 2]; 2] = analog_matrix! {
+///     | 1 | 0 |
+///     | 0 | 1 |
+/// };
+///
+/// assert_eq!(SOBEL_X[1], [-2, 0, 2]);
+/// assert_eq!(IDENTITY, [[1, 0], [0, 1]]);
+/// ```
+///
+/// ```rust,compile_fail
+/// # use analog_literals::analog_matrix;
+/// let jagged = analog_matrix! {
+///     | 1 | 0 | 0 |
+///     | 0 | 1 |
+/// };
+/// ```
+#[macro_export]
+macro_rules! analog_matrix {
+    ($($tail:tt)+) => {
+        $crate::__analog_literal!(@matrix [] ; $($tail)+)
+    };
+}
+
+/// Draw a [`Version`](version::Version) number as three stacked 1D literals:
+/// one for the major version, one for the minor version, and one for the
+/// patch version.
+///
+/// Zeros are drawn as `II`, same as always.
+///
+/// # Example
+///
+/// Bumping the major version has never been more satisfying:
+///
+/// ```rust
+/// # use analog_literals::{analog_version, version::Version};
+/// const OLDEST: Version = analog_version! {
+///     I--I
+///     I--------------------------------------------------------------------------------------------------I
+///     II
+/// };
+///
+/// const CURRENT: Version = analog_version! {
+///     I--I
+///     I--------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------I
+///     II
+/// };
+///
+/// assert_eq!(OLDEST, Version { major: 1, minor: 49, patch: 0 });
+/// assert!(CURRENT.at_least(&OLDEST));
+/// assert!(!OLDEST.at_least(&CURRENT));
+/// ```
+#[macro_export]
+macro_rules! analog_version {
+    ($($tail:tt)+) => {
+        $crate::__analog_literal!(@version [] ; $($tail)+)
+    };
 }
 
-/// The star of the show: a macro to embed analog literals into otherwise boring
-/// Rust source code.
+/// Like [`dbg!`], except analog literals get drawn, not just printed.
 ///
-/// Check out the crate-level documentation for more info.
+/// Debugging geometry by squinting at `Cuboid { w: 21, h: 1, l: 16 }` is
+/// exactly the sort of indignity this crate exists to abolish. Whenever the
+/// value being debugged is a [`Line`], [`Rectangle`], or [`Cuboid`], it gets
+/// drawn out right underneath the usual `[file:line:col] expr = value` output.
+///
+/// Any other `Debug` value is printed exactly like `dbg!` would, so there's no
+/// need to switch back and forth between the two.
+///
+/// Requires the `std` feature.
+///
+/// # Example
+///
+/// ```rust
+/// # use analog_literals::{analog_dbg, analog_literal};
+/// let rect = analog_dbg!(analog_literal! {
+///     +----+
+///     |    |
+///     +----+
+/// });
+/// let area = analog_dbg!(rect.area());
+/// assert_eq!(area, 2);
+/// ```
+///
+/// Which prints something like:
+///
+/// ```text
+/// [src/main.rs:2:12] analog_literal! { +----+ | | +----+ } = Rectangle {
+///     w: 2,
+///     h: 1,
+/// }
+/// +----+
+/// |    |
+/// +----+
+/// [src/main.rs:7:12] rect.area() = 2
+/// ```
+#[cfg(feature = "std")]
 #[macro_export]
-macro_rules! analog_literal {
-    (I $($tail:tt)+) => {
-        0 + $crate::__analog_literal!(@1D $($tail)+)
+macro_rules! analog_dbg {
+    () => {
+        $crate::__private::eprintln!("[{}:{}:{}]", file!(), line!(), column!())
+    };
+    ($val:expr $(,)?) => {
+        // `match` extends the lifetime of any temporaries, just like `dbg!`
+        match $val {
+            tmp => {
+                #[allow(unused_imports)]
+                use $crate::__private::autoref::{Drawn as _, NotDrawn as _};
+                $crate::__private::eprintln!(
+                    "[{}:{}:{}] {} = {:#?}{}",
+                    file!(),
+                    line!(),
+                    column!(),
+                    stringify!($val),
+                    &tmp,
+                    (&&$crate::__private::autoref::Wrap(&tmp)).drawing(),
+                );
+                tmp
+            }
+        }
+    };
+    ($($val:expr),+ $(,)?) => {
+        ($($crate::analog_dbg!($val)),+,)
     };
+}
 
-    (II) => {
-        0
+/// Like [`assert_eq!`], except for analog literals.
+///
+/// Works with any pair of [`Line`]s, [`Rectangle`]s, or [`Cuboid`]s. When the
+/// two literals don't match, the panic message spells out exactly which
+/// dimensions are off, and then draws both literals side-by-side, so you can
+/// _see_ what went wrong.
+///
+/// # Example
+///
+/// ```rust,should_panic
+/// # use analog_literals::{analog_literal, assert_analog_eq};
+/// let door = analog_literal! {
+///     +----+
+///     |    |
+///     |    |
+///     +----+
+/// };
+/// let frame = analog_literal! {
+///     +------+
+///     |      |
+///     |      |
+///     +------+
+/// };
+///
+/// assert_analog_eq!(door, frame, "the door doesn't fit!");
+/// ```
+///
+/// Which panics with:
+///
+/// ```text
+/// the door doesn't fit!: assertion `left == right` failed
+///   left: Rectangle { w: 2, h: 2 }
+///  right: Rectangle { w: 3, h: 2 }
+///   diff: w: 2 != 3
+///
+/// +----+    +------+
+/// |    |    |      |
+/// |    |    |      |
+/// +----+    +------+
+/// ```
+#[macro_export]
+macro_rules! assert_analog_eq {
+    ($left:expr, $right:expr $(,)?) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                let diff = $crate::__private::Diff::new(*left, *right);
+                if !diff.is_eq() {
+                    ::core::panic!("{}", diff);
+                }
+            }
+        }
+    };
+    ($left:expr, $right:expr, $($arg:tt)+) => {
+        match (&$left, &$right) {
+            (left, right) => {
+                let diff = $crate::__private::Diff::new(*left, *right);
+                if !diff.is_eq() {
+                    ::core::panic!("{}: {}", ::core::format_args!($($arg)+), diff);
+                }
+            }
+        }
     };
+}
 
-    (+ $($tail:tt)+) => {
-        $crate::__analog_literal! {
-            @2D_TOP (
-                { 0 },
-                { 0 },
-            )
+#[cfg(not(feature = "macros"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __analog_literal_join_needs_macros {
+    ($($tt:tt)*) => {
+        ::core::compile_error! { "drawing several literals at once needs the `macros` feature" }
+    };
+}
 
-            ; $($tail)+
-        }
+#[cfg(not(feature = "macros"))]
+#[macro_export]
+#[doc(hidden)]
+macro_rules! __analog_literal_strict_needs_macros {
+    ($($tt:tt)*) => {
+        ::core::compile_error! { "`strict;` mode needs the `macros` feature" }
     };
 }
 
@@ -491,7 +3044,7 @@ macro_rules! __analog_literal {
     //========================================================================//
 
     (I $($tail:tt)+) => {
-        0 + $crate::__analog_literal!(@1D $($tail)+)
+        $crate::__analog_literal!(@1D [] ({ 0 }) ; $($tail)+)
     };
 
     // edge case
@@ -501,7 +3054,7 @@ macro_rules! __analog_literal {
 
     (+ $($tail:tt)+) => {
         $crate::__analog_literal! {
-            @2D_TOP (
+            @2D_TOP [usize] (
                 { 0 },
                 { 0 },
             )
@@ -522,16 +3075,191 @@ macro_rules! __analog_literal {
         } as usize] = [];
     };
 
+    // Figures out what kind of literal `$lit` is by peeking at the token
+    // following its top edge, and hands it off to `$cb` along with a `line`,
+    // `rectangle`, or `cuboid` tag. Callers pass the literal twice: once to
+    // forward along, and once to be picked apart.
+    (@classify [$($cb:tt)+] { $($lit:tt)+ } ; I $($rest:tt)+) => {
+        $crate::__analog_literal!($($cb)+ line ; $($lit)+)
+    };
+
+    (@classify [$($cb:tt)+] { $($lit:tt)+ } ; II) => {
+        $crate::__analog_literal!($($cb)+ line ; $($lit)+)
+    };
+
+    // a doc comment right after the top edge would hide what comes next
+    (
+        @classify [$($cb:tt)+] { $($lit:tt)+ } ;
+        + $(- $dash:tt)* + # $(!)? [doc = $doc:literal] $($rest:tt)*
+    ) => {
+        $crate::__analog_literal!(@classify [$($cb)+] { $($lit)+ } ; + $(- $dash)* + $($rest)*)
+    };
+
+    (@classify [$($cb:tt)+] { $($lit:tt)+ } ; + $(- -)* + / $($rest:tt)+) => {
+        $crate::__analog_literal!($($cb)+ cuboid ; $($lit)+)
+    };
+
+    (@classify [$($cb:tt)+] { $($lit:tt)+ } ; + $(- -)* +) => {
+        $crate::__analog_literal!($($cb)+ line ; $($lit)+)
+    };
+
+    (@classify [$($cb:tt)+] { $($lit:tt)+ } ; + $(- -)* + $($rest:tt)+) => {
+        $crate::__analog_literal!($($cb)+ rectangle ; $($lit)+)
+    };
+
+    //========================================================================//
+    //                                 Typed                                  //
+    //========================================================================//
+
+    (@typed line ; $($lit:tt)+) => {{
+        const LEN: usize = $crate::analog_literal!($($lit)+);
+        $crate::typed::Line::<{ LEN }>
+    }};
+
+    (@typed rectangle ; $($lit:tt)+) => {{
+        const RECT: $crate::Rectangle = $crate::analog_literal!($($lit)+);
+        $crate::typed::Rect::<{ RECT.w }, { RECT.h }>
+    }};
+
+    (@typed cuboid ; $($lit:tt)+) => {{
+        const CUBOID: $crate::Cuboid = $crate::analog_literal!($($lit)+);
+        $crate::typed::Cuboid::<{ CUBOID.w }, { CUBOID.h }, { CUBOID.l }>
+    }};
+
+    //========================================================================//
+    //                                 Arrays                                 //
+    //========================================================================//
+
+    // the literal gets re-parsed for each dimension, as using a local `const`
+    // would shadow any similarly named items referenced by `$init`
+
+    (@array ($init:expr) line ; $($lit:tt)+) => {
+        [$init; $crate::analog_literal!($($lit)+)]
+    };
+
+    (@array ($init:expr) rectangle ; $($lit:tt)+) => {
+        [
+            [$init; $crate::analog_literal!($($lit)+).w];
+            $crate::analog_literal!($($lit)+).h
+        ]
+    };
+
+    (@array ($init:expr) cuboid ; $($lit:tt)+) => {
+        [
+            [
+                [$init; $crate::analog_literal!($($lit)+).w];
+                $crate::analog_literal!($($lit)+).h
+            ];
+            $crate::analog_literal!($($lit)+).l
+        ]
+    };
+
+    //========================================================================//
+    //                                 Matrix                                 //
+    //========================================================================//
+
+    (@matrix [$($rows:tt)*] ;) => {
+        $crate::__private::matrix([$($rows)*])
+    };
+
+    // skip over a segment of a `+---+---+` divider, leaving the trailing `+`
+    (@matrix [$($rows:tt)*] ; + $(-)* + $($tail:tt)*) => {
+        $crate::__analog_literal!(@matrix [$($rows)*] ; + $($tail)*)
+    };
+
+    (@matrix [$($rows:tt)*] ; + $($tail:tt)*) => {
+        $crate::__analog_literal!(@matrix [$($rows)*] ; $($tail)*)
+    };
+
+    (@matrix [$($rows:tt)*] ; | $($tail:tt)+) => {
+        $crate::__analog_literal!(@matrix_row [$($rows)*] [] ; $($tail)+)
+    };
+
+    (@matrix_row [$($rows:tt)*] [$($row:tt)*] ; $cell:literal | $($tail:tt)*) => {
+        $crate::__analog_literal!(@matrix_row [$($rows)*] [$($row)* $cell,] ; $($tail)*)
+    };
+
+    // anything other than a cell marks the end of the current row
+    (@matrix_row [$($rows:tt)*] [$($row:tt)*] ; $($tail:tt)*) => {
+        $crate::__analog_literal!(@matrix [$($rows)* [$($row)*],] ; $($tail)*)
+    };
+
+    //========================================================================//
+    //                                Version                                 //
+    //========================================================================//
+
+    (@version [{ $($major:tt)+ } { $($minor:tt)+ } { $($patch:tt)+ }] ;) => {
+        $crate::version::Version {
+            major: $($major)+,
+            minor: $($minor)+,
+            patch: $($patch)+,
+        }
+    };
+
+    (@version [$($parts:tt)*] ; II $($tail:tt)*) => {
+        $crate::__analog_literal!(@version [$($parts)* { 0 }] ; $($tail)*)
+    };
+
+    (@version [$($parts:tt)*] ; I $(- $dash:tt)* I $($tail:tt)*) => {
+        $crate::__analog_literal! {
+            @version [$($parts)* { $crate::analog_literal!(u64; I $(- $dash)* I) }] ; $($tail)*
+        }
+    };
+
+    //========================================================================//
+    //                           Multiple Literals                            //
+    //========================================================================//
+
+    // Every literal ends on either a `+` or an `I`, and `;;` can't show up
+    // anywhere inside one, so spotting the end of the first literal in a
+    // bunch is easy. Parsing the rest is left to `analog_literal_join!`, which
+    // splits them up and bundles everything into a tuple.
+
+    (@$state:tt $t:tt $acc:tt ; + ; ; $($rest:tt)*) => {
+        $crate::__private::analog_literal_join! {
+            $crate ($crate::__analog_literal!(@$state $t $acc ; +)) $t $($rest)*
+        }
+    };
+
+    (@$state:tt $t:tt $acc:tt ; I ; ; $($rest:tt)*) => {
+        $crate::__private::analog_literal_join! {
+            $crate ($crate::__analog_literal!(@$state $t $acc ; I)) $t $($rest)*
+        }
+    };
+
+    //========================================================================//
+    //                              Doc Comments                              //
+    //========================================================================//
+
+    // Regular comments vanish before the macro ever sees them, but doc comments
+    // (e.g: `/** this */` or `/// this`) stick around as `#[doc = "..."]`
+    // attributes, and would otherwise trip up the parser. Plucking them out of
+    // the first few tokens of every state is enough to catch them wherever
+    // they might be, since nothing below consumes more than three tokens at a
+    // time.
+
+    (@$state:tt $t:tt $acc:tt ; # $(!)? [doc = $doc:literal] $($tail:tt)*) => {
+        $crate::__analog_literal!(@$state $t $acc ; $($tail)*)
+    };
+
+    (@$state:tt $t:tt $acc:tt ; $a:tt # $(!)? [doc = $doc:literal] $($tail:tt)*) => {
+        $crate::__analog_literal!(@$state $t $acc ; $a $($tail)*)
+    };
+
+    (@$state:tt $t:tt $acc:tt ; $a:tt $b:tt # $(!)? [doc = $doc:literal] $($tail:tt)*) => {
+        $crate::__analog_literal!(@$state $t $acc ; $a $b $($tail)*)
+    };
+
     //========================================================================//
     //                                   1D                                   //
     //========================================================================//
 
-    (@1D -- $($tail:tt)+) => {
-        1 + $crate::__analog_literal!(@1D $($tail)+)
+    (@1D $t:tt ({ $($n:tt)+ }) ; -- $($tail:tt)+) => {
+        $crate::__analog_literal!(@1D $t ({ $($n)+ + 1 }) ; $($tail)+)
     };
 
-    (@1D I) => {
-        0
+    (@1D $t:tt ({ $($n:tt)+ }) ; I) => {
+        $($n)+
     };
 
     //========================================================================//
@@ -539,7 +3267,7 @@ macro_rules! __analog_literal {
     //========================================================================//
 
     (
-        @2D_TOP (
+        @2D_TOP [$($t:tt)+] (
             { $($w:tt)+ },
             { $($h:tt)+ },
         )
@@ -547,7 +3275,7 @@ macro_rules! __analog_literal {
         ; -- $($tail:tt)+
     ) => {
         $crate::__analog_literal! {
-            @2D_TOP (
+            @2D_TOP [$($t)+] (
                 { $($w)+ + 1 },
                 { $($h)+ },
             )
@@ -557,7 +3285,7 @@ macro_rules! __analog_literal {
     };
 
     (
-        @2D_TOP (
+        @2D_TOP [$($t:tt)+] (
             { $($w:tt)+ },
             { $($h:tt)+ },
         )
@@ -568,7 +3296,7 @@ macro_rules! __analog_literal {
     };
 
     (
-        @2D_TOP (
+        @2D_TOP [$($t:tt)+] (
             { $($w:tt)+ },
             { $($h:tt)+ },
         )
@@ -576,7 +3304,7 @@ macro_rules! __analog_literal {
         ; + $($tail:tt)+
     ) => {
         $crate::__analog_literal! {
-            @2D_MID (
+            @2D_MID [$($t)+] (
                 { $($w)+ },
                 { $($h)+ },
             )
@@ -586,7 +3314,7 @@ macro_rules! __analog_literal {
     };
 
     (
-        @2D_MID (
+        @2D_MID [$($t:tt)+] (
             { $($w:tt)+ },
             { $($h:tt)+ },
         )
@@ -594,7 +3322,26 @@ macro_rules! __analog_literal {
         ; | | $($tail:tt)+
     ) => {
         $crate::__analog_literal! {
-            @2D_MID (
+            @2D_MID [$($t)+] (
+                { $($w)+ },
+                { 1 + $($h)+ },
+            )
+
+            ; $($tail)+
+        }
+    };
+
+    // `||` is a single token, so rectangles with no width need their own rule
+    (
+        @2D_MID [$($t:tt)+] (
+            { $($w:tt)+ },
+            { $($h:tt)+ },
+        )
+
+        ; || $($tail:tt)+
+    ) => {
+        $crate::__analog_literal! {
+            @2D_MID [$($t)+] (
                 { $($w)+ },
                 { 1 + $($h)+ },
             )
@@ -604,7 +3351,7 @@ macro_rules! __analog_literal {
     };
 
     (
-        @2D_MID (
+        @2D_MID [$($t:tt)+] (
             { $($w:tt)+ },
             { $($h:tt)+ },
         )
@@ -612,7 +3359,7 @@ macro_rules! __analog_literal {
         ; + $($tail:tt)+
     ) => {
         $crate::__analog_literal! {
-            @2D_BOTTOM (
+            @2D_BOTTOM [$($t)+] (
                 { $($w)+ },
                 { $($h)+ },
                 { 0 },
@@ -623,7 +3370,7 @@ macro_rules! __analog_literal {
     };
 
     (
-        @2D_BOTTOM (
+        @2D_BOTTOM [$($t:tt)+] (
             { $($w:tt)+ },
             { $($h:tt)+ },
             { $($bottom_w:tt)+ },
@@ -632,7 +3379,7 @@ macro_rules! __analog_literal {
         ; -- $($tail:tt)+
     ) => {
         $crate::__analog_literal! {
-            @2D_BOTTOM (
+            @2D_BOTTOM [$($t)+] (
                 { $($w)+ },
                 { $($h)+ },
                 { $($bottom_w)+ + 1 },
@@ -643,7 +3390,7 @@ macro_rules! __analog_literal {
     };
 
     (
-        @2D_BOTTOM (
+        @2D_BOTTOM [$($t:tt)+] (
             { $($w:tt)+ },
             { $($h:tt)+ },
             { $($bottom_w:tt)+ },
@@ -652,7 +3399,7 @@ macro_rules! __analog_literal {
         ; +
     ) => {{
         $crate::__analog_literal!(@const_assert { $($w)+ } == { $($bottom_w)+ });
-        $crate::Rectangle {
+        $crate::Rectangle::<$($t)+> {
             w: $($w)+,
             h: $($h)+,
         }
@@ -663,7 +3410,7 @@ macro_rules! __analog_literal {
     //========================================================================//
 
     (
-        @2D_MID (
+        @2D_MID [$($t:tt)+] (
             { $($w:tt)+ },
             { $($h:tt)+ },
         )
@@ -671,7 +3418,7 @@ macro_rules! __analog_literal {
         ; / / | $($tail:tt)+
     ) => {
         $crate::__analog_literal! {
-            @3D_TOP_l_h (
+            @3D_TOP_l_h [$($t)+] (
                 { $($w)+ },
                 { 1 },
                 { 1 },
@@ -687,7 +3434,7 @@ macro_rules! __analog_literal {
     };
 
     (
-        @3D_TOP_l_h (
+        @3D_TOP_l_h [$($t:tt)+] (
             { $($w:tt)+ },
             { $($h:tt)+ },
             { $($l:tt)+ },
@@ -701,7 +3448,7 @@ macro_rules! __analog_literal {
         ; / / | $($tail:tt)+
     ) => {
         $crate::__analog_literal! {
-            @3D_TOP_l_h (
+            @3D_TOP_l_h [$($t)+] (
                 { $($w)+ },
                 { 1 + $($h)+ },
                 { 1 + $($l)+ },
@@ -717,7 +3464,7 @@ macro_rules! __analog_literal {
     };
 
     (
-        @3D_TOP_l_h (
+        @3D_TOP_l_h [$($t:tt)+] (
             { $($w:tt)+ },
             { $($h:tt)+ },
             { $($l:tt)+ },
@@ -731,7 +3478,7 @@ macro_rules! __analog_literal {
         ; / / + $($tail:tt)+
     ) => {
         $crate::__analog_literal! {
-            @3D_TOP_l_BOTTOM_l (
+            @3D_TOP_l_BOTTOM_l [$($t)+] (
                 { $($w)+ },
                 { $($h)+ },
                 { 1 + $($l)+ },
@@ -747,7 +3494,7 @@ macro_rules! __analog_literal {
     };
 
     (
-        @3D_TOP_l_BOTTOM_l (
+        @3D_TOP_l_BOTTOM_l [$($t:tt)+] (
             { $($w:tt)+ },
             { $($h:tt)+ },
             { $($l:tt)+ },
@@ -761,7 +3508,7 @@ macro_rules! __analog_literal {
         ; / / / $($tail:tt)+
     ) => {
         $crate::__analog_literal! {
-            @3D_TOP_l_BOTTOM_l (
+            @3D_TOP_l_BOTTOM_l [$($t)+] (
                 { $($w)+ },
                 { $($h)+ },
                 { 1 + $($l)+ },
@@ -777,7 +3524,7 @@ macro_rules! __analog_literal {
     };
 
     (
-        @3D_TOP_l_BOTTOM_l (
+        @3D_TOP_l_BOTTOM_l [$($t:tt)+] (
             { $($w:tt)+ },
             { $($h:tt)+ },
             { $($l:tt)+ },
@@ -791,7 +3538,7 @@ macro_rules! __analog_literal {
         ; + $($tail:tt)+
     ) => {
         $crate::__analog_literal! {
-            @3D_MID_w (
+            @3D_MID_w [$($t)+] (
                 { $($w)+ },
                 { $($h)+ },
                 { $($l)+ },
@@ -807,7 +3554,7 @@ macro_rules! __analog_literal {
     };
 
     (
-        @3D_TOP_l_h (
+        @3D_TOP_l_h [$($t:tt)+] (
             { $($w:tt)+ },
             { $($h:tt)+ },
             { $($l:tt)+ },
@@ -821,7 +3568,7 @@ macro_rules! __analog_literal {
         ; + $($tail:tt)+
     ) => {
         $crate::__analog_literal! {
-            @3D_MID_w (
+            @3D_MID_w [$($t)+] (
                 { $($w)+ },
                 { $($h)+ },
                 { $($l)+ },
@@ -837,7 +3584,7 @@ macro_rules! __analog_literal {
     };
 
     (
-        @3D_MID_w (
+        @3D_MID_w [$($t:tt)+] (
             { $($w:tt)+ },
             { $($h:tt)+ },
             { $($l:tt)+ },
@@ -851,7 +3598,7 @@ macro_rules! __analog_literal {
         ; -- $($tail:tt)+
     ) => {
         $crate::__analog_literal! {
-            @3D_MID_w (
+            @3D_MID_w [$($t)+] (
                 { $($w)+ },
                 { $($h)+ },
                 { $($l)+ },
@@ -867,7 +3614,7 @@ macro_rules! __analog_literal {
     };
 
     (
-        @3D_MID_w (
+        @3D_MID_w [$($t:tt)+] (
             { $($w:tt)+ },
             { $($h:tt)+ },
             { $($l:tt)+ },
@@ -881,7 +3628,7 @@ macro_rules! __analog_literal {
         ; + + $($tail:tt)+
     ) => {
         $crate::__analog_literal! {
-            @3D_BOTTOM_h (
+            @3D_BOTTOM_h [$($t)+] (
                 { $($w)+ },
                 { $($h)+ },
                 { $($l)+ },
@@ -897,7 +3644,7 @@ macro_rules! __analog_literal {
     };
 
     (
-        @3D_MID_w (
+        @3D_MID_w [$($t:tt)+] (
             { $($w:tt)+ },
             { $($h:tt)+ },
             { $($l:tt)+ },
@@ -911,7 +3658,7 @@ macro_rules! __analog_literal {
         ; + | $($tail:tt)+
     ) => {
         $crate::__analog_literal! {
-            @3D_BOTTOM_h (
+            @3D_BOTTOM_h [$($t)+] (
                 { $($w)+ },
                 { 1 + $($h)+ },
                 { $($l)+ },
@@ -927,7 +3674,7 @@ macro_rules! __analog_literal {
     };
 
     (
-        @3D_MID_w (
+        @3D_MID_w [$($t:tt)+] (
             { $($w:tt)+ },
             { $($h:tt)+ },
             { $($l:tt)+ },
@@ -941,7 +3688,7 @@ macro_rules! __analog_literal {
         ; + / $($tail:tt)+
     ) => {
         $crate::__analog_literal! {
-            @3D_BOTTOM_h (
+            @3D_BOTTOM_h [$($t)+] (
                 { $($w)+ },
                 { $($h)+ },
                 { $($l)+ },
@@ -957,7 +3704,7 @@ macro_rules! __analog_literal {
     };
 
     (
-        @3D_BOTTOM_h (
+        @3D_BOTTOM_h [$($t:tt)+] (
             { $($w:tt)+ },
             { $($h:tt)+ },
             { $($l:tt)+ },
@@ -971,7 +3718,7 @@ macro_rules! __analog_literal {
         ; | | + $($tail:tt)+
     ) => {
         $crate::__analog_literal! {
-            @3D_BOTTOM_h (
+            @3D_BOTTOM_h [$($t)+] (
                 { $($w)+ },
                 { $($h)+ },
                 { $($l)+ },
@@ -987,7 +3734,7 @@ macro_rules! __analog_literal {
     };
 
     (
-        @3D_BOTTOM_h (
+        @3D_BOTTOM_h [$($t:tt)+] (
             { $($w:tt)+ },
             { $($h:tt)+ },
             { $($l:tt)+ },
@@ -1001,7 +3748,7 @@ macro_rules! __analog_literal {
         ; | | / $($tail:tt)+
     ) => {
         $crate::__analog_literal! {
-            @3D_BOTTOM_h (
+            @3D_BOTTOM_h [$($t)+] (
                 { $($w)+ },
                 { $($h)+ },
                 { $($l)+ },
@@ -1017,7 +3764,7 @@ macro_rules! __analog_literal {
     };
 
     (
-        @3D_BOTTOM_h (
+        @3D_BOTTOM_h [$($t:tt)+] (
             { $($w:tt)+ },
             { $($h:tt)+ },
             { $($l:tt)+ },
@@ -1031,7 +3778,7 @@ macro_rules! __analog_literal {
         ; | | | $($tail:tt)+
     ) => {
         $crate::__analog_literal! {
-            @3D_BOTTOM_h (
+            @3D_BOTTOM_h [$($t)+] (
                 { $($w)+ },
                 { 1 + $($h)+ },
                 { $($l)+ },
@@ -1047,7 +3794,7 @@ macro_rules! __analog_literal {
     };
 
     (
-        @3D_BOTTOM_h (
+        @3D_BOTTOM_h [$($t:tt)+] (
             { $($w:tt)+ },
             { $($h:tt)+ },
             { $($l:tt)+ },
@@ -1061,7 +3808,7 @@ macro_rules! __analog_literal {
         ; + $($tail:tt)+
     ) => {
         $crate::__analog_literal! {
-            @3D_BOTTOM (
+            @3D_BOTTOM [$($t)+] (
                 { $($w)+ },
                 { $($h)+ },
                 { $($l)+ },
@@ -1077,29 +3824,135 @@ macro_rules! __analog_literal {
     };
 
     (
-        @3D_BOTTOM (
+        @3D_BOTTOM [$($t:tt)+] (
+            { $($w:tt)+ },
+            { $($h:tt)+ },
+            { $($l:tt)+ },
+
+            { $($mid_w:tt)+ },
+            { $($bottom_w:tt)+ },
+            { $($bottom_h:tt)+ },
+            { $($bottom_l:tt)+ },
+        )
+
+        ; -- $($tail:tt)+
+    ) => {
+        $crate::__analog_literal! {
+            @3D_BOTTOM [$($t)+] (
+                { $($w)+ },
+                { $($h)+ },
+                { $($l)+ },
+
+                { $($mid_w)+ },
+                { 1 + $($bottom_w)+ },
+                { $($bottom_h)+ },
+                { $($bottom_l)+ },
+            )
+
+            ; $($tail)+
+        }
+    };
+
+    (
+        @3D_BOTTOM [$($t:tt)+] (
+            { $($w:tt)+ },
+            { $($h:tt)+ },
+            { $($l:tt)+ },
+
+            { $($mid_w:tt)+ },
+            { $($bottom_w:tt)+ },
+            { $($bottom_h:tt)+ },
+            { $($bottom_l:tt)+ },
+        )
+
+        ; +
+    ) => {
+        {
+            $crate::__analog_literal!(@const_assert { $($w)+ } == { $($mid_w)+ });
+            $crate::__analog_literal!(@const_assert { $($w)+ } == { $($bottom_w)+ });
+            $crate::__analog_literal!(@const_assert { $($h)+ } == { $($bottom_h)+ });
+            $crate::__analog_literal!(@const_assert { $($l)+ } == { $($bottom_l)+ });
+
+            $crate::Cuboid::<$($t)+> {
+                w: $($w)+,
+                l: $($l)+,
+                h: $($h)+,
+            }
+        }
+    };
+
+    // a cuboid with no height is nothing but its top face, with the front
+    // face squashed down into a single edge (and no right-hand face at all)
+
+    (
+        @2D_MID [$($t:tt)+] (
+            { $($w:tt)+ },
+            { $($h:tt)+ },
+        )
+
+        ; / / $($tail:tt)+
+    ) => {
+        $crate::__analog_literal! {
+            @3D_FLAT [$($t)+] (
+                { $($w)+ },
+                { 1 },
+            )
+
+            ; $($tail)+
+        }
+    };
+
+    (
+        @3D_FLAT [$($t:tt)+] (
+            { $($w:tt)+ },
+            { $($l:tt)+ },
+        )
+
+        ; / / $($tail:tt)+
+    ) => {
+        $crate::__analog_literal! {
+            @3D_FLAT [$($t)+] (
+                { $($w)+ },
+                { 1 + $($l)+ },
+            )
+
+            ; $($tail)+
+        }
+    };
+
+    (
+        @3D_FLAT [$($t:tt)+] (
+            { $($w:tt)+ },
+            { $($l:tt)+ },
+        )
+
+        ; + $($tail:tt)+
+    ) => {
+        $crate::__analog_literal! {
+            @3D_FLAT_BOTTOM [$($t)+] (
+                { $($w)+ },
+                { $($l)+ },
+                { 0 },
+            )
+
+            ; $($tail)+
+        }
+    };
+
+    (
+        @3D_FLAT_BOTTOM [$($t:tt)+] (
             { $($w:tt)+ },
-            { $($h:tt)+ },
             { $($l:tt)+ },
-
-            { $($mid_w:tt)+ },
             { $($bottom_w:tt)+ },
-            { $($bottom_h:tt)+ },
-            { $($bottom_l:tt)+ },
         )
 
         ; -- $($tail:tt)+
     ) => {
         $crate::__analog_literal! {
-            @3D_BOTTOM (
+            @3D_FLAT_BOTTOM [$($t)+] (
                 { $($w)+ },
-                { $($h)+ },
                 { $($l)+ },
-
-                { $($mid_w)+ },
                 { 1 + $($bottom_w)+ },
-                { $($bottom_h)+ },
-                { $($bottom_l)+ },
             )
 
             ; $($tail)+
@@ -1107,32 +3960,21 @@ macro_rules! __analog_literal {
     };
 
     (
-        @3D_BOTTOM (
+        @3D_FLAT_BOTTOM [$($t:tt)+] (
             { $($w:tt)+ },
-            { $($h:tt)+ },
             { $($l:tt)+ },
-
-            { $($mid_w:tt)+ },
             { $($bottom_w:tt)+ },
-            { $($bottom_h:tt)+ },
-            { $($bottom_l:tt)+ },
         )
 
         ; +
-    ) => {
-        {
-            $crate::__analog_literal!(@const_assert { $($w)+ } == { $($mid_w)+ });
-            $crate::__analog_literal!(@const_assert { $($w)+ } == { $($bottom_w)+ });
-            $crate::__analog_literal!(@const_assert { $($h)+ } == { $($bottom_h)+ });
-            $crate::__analog_literal!(@const_assert { $($l)+ } == { $($bottom_l)+ });
-
-            $crate::Cuboid {
-                w: $($w)+,
-                l: $($l)+,
-                h: $($h)+,
-            }
+    ) => {{
+        $crate::__analog_literal!(@const_assert { $($w)+ } == { $($bottom_w)+ });
+        $crate::Cuboid::<$($t)+> {
+            w: $($w)+,
+            h: 0,
+            l: $($l)+,
         }
-    };
+    }};
 
     // (
     //     $val,
@@ -1259,6 +4101,884 @@ mod test {
         eprintln!("{:?}", CHONKER);
     }
 
+    #[test]
+    fn parse() {
+        let cube: Cuboid = r"
+                 +----------+
+                /          /|
+               /          / |
+              /          /  +     // comments
+             /          /  /      /* work too */
+            +----------+  /
+            |          | /
+            |          |/
+            +----------+
+        "
+        .parse()
+        .unwrap();
+        assert_eq!(cube.volume(), CUBE_5_BY_2_BY_4.volume());
+
+        let rect: Rectangle = stringify!(
+            +----+
+            |    |
+            |    |
+            |    |
+            +----+
+        )
+        .parse()
+        .unwrap();
+        assert_eq!((rect.w, rect.h), (RECT_2_BY_3.w, RECT_2_BY_3.h));
+
+        assert_eq!(Line::parse("I------I").unwrap().0, 3);
+        assert_eq!(Line::parse("++").unwrap().0, 0);
+
+        let err = Rectangle::parse("+----+\n|    |\n+--+").unwrap_err();
+        assert_eq!(
+            (err.row, err.col, err.kind),
+            (3, 1, parse::ParseErrorKind::Mismatched)
+        );
+        let err = Rectangle::parse("+--+\n| x |\n+--+").unwrap_err();
+        assert_eq!(
+            (err.row, err.col, err.kind),
+            (2, 3, parse::ParseErrorKind::UnexpectedChar)
+        );
+        let err = Rectangle::parse("+--+").unwrap_err();
+        assert_eq!(err.kind, parse::ParseErrorKind::WrongShape);
+        let err = Cuboid::parse("+--+\n/ / |\n").unwrap_err();
+        assert_eq!(err.kind, parse::ParseErrorKind::UnexpectedEnd);
+    }
+
+    #[test]
+    fn left_leaning() {
+        use render::{Perspective, RenderOptions};
+
+        let left = RenderOptions::new().perspective(Perspective::Left);
+        for cuboid in [CUBE_5_BY_2_BY_4, CUBE_4_BY_5_BY_1, CHONKER] {
+            let mut drawn = String::new();
+            cuboid.render_with(&mut drawn, &left).unwrap();
+            assert_eq!(drawn.parse(), Ok(cuboid));
+
+            let found = scan::scan(&drawn).collect::<Vec<_>>();
+            assert_eq!(found.len(), 1);
+            assert_eq!((found[0].start, found[0].end), (0, drawn.len()));
+            assert_eq!(found[0].shape, parse::Shape::Cuboid(cuboid));
+        }
+    }
+
+    #[test]
+    fn render_to_slice() {
+        use render::{GlyphSet, Perspective, RenderOptions};
+
+        for opts in [
+            RenderOptions::new(),
+            RenderOptions::new().glyphs(GlyphSet::Unicode),
+            RenderOptions::new().fill('🦀'),
+            RenderOptions::new()
+                .glyphs(GlyphSet::Unicode)
+                .perspective(Perspective::Left)
+                .fill('░')
+                .scale(2),
+        ] {
+            let size = CHONKER.rendered_size_with(&opts);
+            let mut buf = vec![0; size.max_len_with(&opts)];
+            let drawn = CHONKER.render_to_slice_with(&mut buf, &opts).unwrap();
+            let mut expected = String::new();
+            CHONKER.render_with(&mut expected, &opts).unwrap();
+            assert_eq!(drawn, expected);
+
+            let size = RECT_2_BY_3.rendered_size_with(&opts);
+            let mut buf = vec![0; size.max_len_with(&opts)];
+            assert!(RECT_2_BY_3.render_to_slice_with(&mut buf, &opts).is_ok());
+
+            let size = Line(3).rendered_size_with(&opts);
+            let mut buf = vec![0; size.max_len_with(&opts)];
+            assert!(Line(3).render_to_slice_with(&mut buf, &opts).is_ok());
+        }
+
+        // what gets measured is what gets drawn
+        let shapes: [parse::Shape; 4] = [
+            Line(3).into(),
+            RECT_2_BY_3.into(),
+            CUBE_4_BY_5_BY_1.into(),
+            CHONKER.into(),
+        ];
+        for shape in shapes {
+            let (drawn, size) = match shape {
+                parse::Shape::Line(line) => (line.to_string(), line.rendered_size()),
+                parse::Shape::Rectangle(rect) => (rect.to_string(), rect.rendered_size()),
+                parse::Shape::Cuboid(cuboid) => (cuboid.to_string(), cuboid.rendered_size()),
+            };
+            assert_eq!(drawn.lines().count(), size.rows);
+            assert_eq!(drawn.lines().map(str::len).max(), Some(size.cols));
+            assert!(drawn.len() <= size.max_len());
+
+            // box-drawing characters take up more bytes, but not more columns
+            let fancy = format!("{:#}", shape);
+            assert!(drawn
+                .lines()
+                .map(str::len)
+                .eq(fancy.lines().map(|line| line.chars().count())));
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        // drawn in, drawn out, and back in again
+        for n in 0..4 {
+            assert_eq!(Line(n).to_string().parse(), Ok(Line(n)));
+            for m in 0..4 {
+                let rect = Rectangle { w: n, h: m };
+                assert_eq!(rect.to_string().parse(), Ok(rect));
+                for k in 0..4 {
+                    let cuboid = Cuboid { w: n, h: m, l: k };
+                    assert_eq!(cuboid.to_string().parse(), Ok(cuboid));
+                }
+            }
+        }
+        assert_eq!(CHONKER.to_string().parse(), Ok(CHONKER));
+
+        // what goes in must come out
+        let shapes: [parse::Shape; 5] = [
+            Line(3).into(),
+            RECT_2_BY_3.into(),
+            CUBE_5_BY_2_BY_4.into(),
+            CUBE_4_BY_5_BY_1.into(),
+            CHONKER.into(),
+        ];
+        for shape in shapes {
+            assert_eq!(shape.to_string().parse::<parse::Shape>(), Ok(shape));
+        }
+    }
+
+    #[test]
+    fn zero_sized() {
+        use render::{Perspective, RenderOptions};
+
+        let shapes: [parse::Shape; 4] = [
+            analog_literal! { +----+ +----+ }.into(),
+            analog_literal! { ++ || || || ++ }.into(),
+            analog_literal! { ++ ++ }.into(),
+            analog_literal! {
+                  +----+
+                 /    /
+                +----+
+            }
+            .into(),
+        ];
+        for shape in shapes {
+            assert_eq!(shape.to_string().parse::<parse::Shape>(), Ok(shape));
+        }
+
+        for w in 0..3 {
+            for h in 0..3 {
+                for l in 0..3 {
+                    let cuboid = Cuboid { w, h, l };
+                    assert_eq!(cuboid.to_string().parse(), Ok(cuboid));
+
+                    let mut left = String::new();
+                    cuboid
+                        .render_with(
+                            &mut left,
+                            &RenderOptions::new().perspective(Perspective::Left),
+                        )
+                        .unwrap();
+                    assert_eq!(left.parse(), Ok(cuboid));
+                }
+            }
+        }
+        assert_eq!(
+            Cuboid { w: 0, h: 1, l: 1 }.to_string(),
+            "  + +\n / /|\n+ + +\n| |/\n+ +"
+        );
+        assert_eq!(
+            Cuboid { w: 2, h: 1, l: 0 }.to_string(),
+            Rectangle { w: 2, h: 1 }.to_string()
+        );
+
+        let flat = Cuboid { w: 1, h: 0, l: 2 };
+        let mut left = String::new();
+        flat.render_with(
+            &mut left,
+            &RenderOptions::new().perspective(Perspective::Left),
+        )
+        .unwrap();
+        assert_eq!(left, "+--+\n \\  \\\n  \\  \\\n   +--+");
+        assert_eq!(left.parse(), Ok(flat));
+    }
+
+    #[test]
+    fn scan() {
+        let text = r"
+Some C++ trivia from World War II, and a cube:
+
+         +------+
+        /      /|        +--+  I----I
+       +------+ |        |  |
+       | /**/ | +        +--+
+       |      |/
+       +------+     ----+--+----
+";
+        let found = scan::scan(text).collect::<Vec<_>>();
+        let shapes = found.iter().map(|f| f.shape).collect::<Vec<_>>();
+        assert!(matches!(
+            shapes[..],
+            [
+                parse::Shape::Cuboid(Cuboid { w: 3, h: 2, l: 1 }),
+                parse::Shape::Rectangle(Rectangle { w: 1, h: 1 }),
+                parse::Shape::Line(Line(2)),
+            ]
+        ));
+        assert!(text[found[0].start..found[0].end].starts_with("+------+\n"));
+        assert!(text[found[0].start..found[0].end].ends_with("+------+"));
+        assert_eq!(&text[found[2].start..found[2].end], "I----I");
+    }
+
+    #[test]
+    fn parse_comments() {
+        assert_eq!(
+            Line::parse("I--/* /* nested */ still a comment */--I")
+                .unwrap()
+                .0,
+            2
+        );
+        assert_eq!(Line::parse("/**/I/**/--/**/I/**/").unwrap().0, 1);
+        assert!(Rectangle::parse("+--+\n|  |\n+--+ // trailing\n/* after */").is_ok());
+
+        let err = |s: &str| {
+            let err = parse::parse(s).unwrap_err();
+            (err.row, err.col, err.kind)
+        };
+        let unterminated = parse::ParseErrorKind::UnterminatedComment;
+        // errors point at the `/*` that was never closed...
+        assert_eq!(err("+--+\n|  |  /* oops\n+--+"), (2, 7, unterminated));
+        // ...which, when nested, is the outermost one
+        assert_eq!(err("I--/* /* nested */--I"), (1, 4, unterminated));
+        assert_eq!(err("I--I /* /* */"), (1, 6, unterminated));
+    }
+
+    #[test]
+    fn parse_errors() {
+        use parse::ParseErrorKind::*;
+
+        let err = |s: &str| {
+            let err = parse::parse(s).unwrap_err();
+            (err.row, err.col, err.kind)
+        };
+
+        assert_eq!(err(""), (1, 1, UnexpectedEnd));
+        assert_eq!(err("I--I\u{2500}"), (1, 5, UnexpectedChar));
+        assert_eq!(err("+--+\n|  |\n+--+--"), (3, 5, UnexpectedToken));
+
+        // odd dashes get pointed out at the first `-` of the offending edge
+        assert_eq!(err("I---I"), (1, 2, OddDashes));
+        assert_eq!(err("+---+\n|   |\n+---+"), (1, 2, OddDashes));
+        assert_eq!(err("+--+\n|  |\n+---+"), (3, 2, OddDashes));
+        assert_eq!(
+            err("  +---+\n /  /|\n+--+ +\n|  |/\n+--+"),
+            (1, 4, OddDashes)
+        );
+        assert_eq!(
+            err("  +--+\n /  /|\n+---+ +\n|  |/\n+--+"),
+            (3, 2, OddDashes)
+        );
+        assert_eq!(
+            err("  +--+\n /  /|\n+--+ +\n|  |/\n+---+"),
+            (5, 2, OddDashes)
+        );
+        assert_eq!(
+            err("+---+\n|\\  \\\n+ +--+\n \\|  |\n  +--+"),
+            (1, 2, OddDashes)
+        );
+        assert_eq!(
+            err("+--+\n|\\  \\\n+ +---+\n \\|  |\n  +--+"),
+            (3, 4, OddDashes)
+        );
+        assert_eq!(
+            err("+--+\n|\\  \\\n+ +--+\n \\|  |\n  +---+"),
+            (5, 4, OddDashes)
+        );
+
+        // right-leaning cuboids
+        assert_eq!(
+            err("  +--+\n /  /|\n+--+ |\n|  |/\n+--+"),
+            (5, 1, Mismatched)
+        );
+        assert_eq!(
+            err("  +--+\n /  /|\n+--+ +\n|  | +\n+--+"),
+            (5, 1, Mismatched)
+        );
+        // left-leaning cuboids
+        assert_eq!(
+            err("+--+\n|\\  \\\n+ +--+\n \\|  |\n  +----+"),
+            (5, 3, Mismatched)
+        );
+        assert_eq!(
+            err("+--+\n|\\  \\\n+ +--+\n \\|  |\n \\|  |\n  +--+"),
+            (6, 3, Mismatched)
+        );
+        // flat cuboids, leaning either way
+        assert_eq!(err("  +--+\n /  /\n+----+"), (3, 1, Mismatched));
+        assert_eq!(err("+--+\n \\  \\\n  +----+"), (3, 3, Mismatched));
+
+        assert_eq!(
+            Cuboid::parse("+--+\n \\  \\\n  +--+"),
+            Ok(Cuboid { w: 1, h: 0, l: 1 })
+        );
+        assert_eq!(
+            parse::ParseError {
+                row: 3,
+                col: 1,
+                kind: Mismatched
+            }
+            .to_string(),
+            "3:1: opposite edges must be the same length"
+        );
+    }
+
+    #[test]
+    fn scan_edge_cases() {
+        fn shapes(text: &str) -> Vec<(parse::Shape, &str)> {
+            scan::scan(text)
+                .map(|found| (found.shape, &text[found.start..found.end]))
+                .collect()
+        }
+
+        // zero-length lines are a little too easy to stumble across
+        assert!(shapes("C++ and WWII: ++ II").is_empty());
+        // dashes have to be drawn right next to one another
+        assert!(shapes("+- - -+ and I- -I").is_empty());
+        // ...and have to come in pairs
+        assert!(shapes("+---+").is_empty());
+        assert!(shapes("+--+\n|  |\n+---+").is_empty());
+        // columns are counted in bytes, so the `é` pushes the top edge over
+        assert!(shapes("é +--+\n  |  |\n  +--+").is_empty());
+
+        let text = "+----+\n| hi |\n+----+";
+        assert_eq!(
+            shapes(text),
+            [(parse::Shape::Rectangle(Rectangle { w: 2, h: 1 }), text)]
+        );
+
+        let text = "+--+\n|\\  \\\n+ +--+\n \\|  |\n  +--+";
+        assert_eq!(
+            shapes(text),
+            [(parse::Shape::Cuboid(Cuboid { w: 1, h: 1, l: 1 }), text)]
+        );
+    }
+
+    #[test]
+    fn render_options() {
+        use render::{GlyphSet, Perspective, RenderOptions};
+
+        let rect = |opts: RenderOptions| {
+            let mut out = String::new();
+            Rectangle { w: 2, h: 1 }
+                .render_with(&mut out, &opts)
+                .unwrap();
+            out
+        };
+        let cube = |opts: RenderOptions| {
+            let mut out = String::new();
+            Cuboid { w: 1, h: 1, l: 1 }
+                .render_with(&mut out, &opts)
+                .unwrap();
+            out
+        };
+
+        assert_eq!(
+            rect(RenderOptions::new().scale(2)),
+            "+--------+\n|        |\n|        |\n+--------+"
+        );
+        assert_eq!(
+            cube(RenderOptions::new().fill('#')),
+            "  +--+\n /##/|\n+--+#+\n|##|/\n+--+"
+        );
+        assert_eq!(
+            rect(RenderOptions::new().glyphs(GlyphSet::Unicode)),
+            "┌────┐\n│    │\n└────┘"
+        );
+        assert_eq!(
+            cube(RenderOptions::new().perspective(Perspective::Left)),
+            "+--+\n|\\  \\\n+ +--+\n \\|  |\n  +--+"
+        );
+        // rectangles don't have a perspective to speak of
+        assert_eq!(
+            rect(RenderOptions::new().perspective(Perspective::Left)),
+            Rectangle { w: 2, h: 1 }.to_string()
+        );
+        // scaling by 0 squashes everything down to nothing at all
+        assert_eq!(cube(RenderOptions::new().scale(0)), "++\n++");
+
+        let mut buf = [0; 10];
+        assert!(Rectangle { w: 2, h: 1 }.render_to_slice(&mut buf).is_err());
+    }
+
+    #[test]
+    fn rasterize() {
+        use render::{Perspective, RenderOptions};
+
+        assert_eq!(Rectangle { w: 2, h: 1 }.rasterized_size(1), (3, 2));
+        assert_eq!(Cuboid { w: 1, h: 1, l: 1 }.rasterized_size(2), (5, 5));
+        assert_eq!(Line(0).rasterized_size(3), (1, 1));
+
+        let mut buf = [0; 9];
+        Rectangle { w: 2, h: 2 }.rasterize(&mut buf, 3, 1);
+        assert_eq!(buf, [0xff, 0xff, 0xff, 0xff, 0, 0xff, 0xff, 0xff, 0xff]);
+
+        // anything that doesn't fit is clipped, rather than wrapping around
+        let mut buf = [0; 4];
+        Rectangle { w: 2, h: 2 }.rasterize(&mut buf, 2, 1);
+        assert_eq!(buf, [0xff, 0xff, 0xff, 0]);
+
+        let mut buf = [0; 4];
+        Rectangle { w: 0, h: 0 }.rasterize(&mut buf, 2, 1);
+        assert_eq!(buf, [0xff, 0, 0, 0]);
+
+        // leaning left is a mirror image of leaning right
+        let cube = Cuboid { w: 1, h: 1, l: 1 };
+        let (w, h) = cube.rasterized_size(1);
+        let (mut right, mut left) = (vec![0; w * h], vec![0; w * h]);
+        cube.rasterize(&mut right, w, 1);
+        cube.rasterize_with(
+            &mut left,
+            w,
+            &RenderOptions::new().perspective(Perspective::Left),
+        );
+        for (right, left) in right.chunks(w).zip(left.chunks(w)) {
+            assert!(right.iter().eq(left.iter().rev()));
+        }
+        assert_ne!(right, left);
+    }
+
+    #[cfg(feature = "macros")]
+    #[test]
+    fn intervals() {
+        use interval::Interval;
+
+        // touching isn't overlapping
+        let (a, b) = analog_intervals! {
+            [----]
+                [--]
+        };
+        assert_eq!(
+            (a, b),
+            (Interval { start: 0, end: 2 }, Interval { start: 2, end: 3 })
+        );
+        assert_eq!(a.overlap(&b), None);
+        assert_eq!((a.gap(&b), b.gap(&a)), (0, 0));
+
+        // the ruler starts at the leftmost `[`, whichever row it's on
+        let (a, b) = analog_intervals! {
+                    [--]
+            [------------]
+        };
+        assert_eq!(
+            (a, b),
+            (Interval { start: 4, end: 5 }, Interval { start: 0, end: 6 })
+        );
+        assert!(b.contains(&a) && !a.contains(&b));
+        assert_eq!(a.overlap(&b), Some(a));
+
+        let (empty, other) = analog_intervals! {
+                []
+            [------]
+        };
+        assert!(empty.is_empty());
+        assert_eq!(empty.len(), 0);
+        // backwards intervals are just as empty, rather than overflowing
+        let backwards = Interval { start: 3, end: 1 };
+        assert!(backwards.is_empty());
+        assert_eq!(backwards.len(), 0);
+        assert!(!empty.overlaps(&other));
+        assert!(other.contains(&empty));
+        assert_eq!(
+            Interval { start: 5, end: 7 }.gap(&Interval { start: 0, end: 2 }),
+            3
+        );
+    }
+
+    #[test]
+    fn versions() {
+        use version::Version;
+
+        const ZERO: Version = analog_version! {
+            II
+            II
+            II
+        };
+        assert_eq!(ZERO, Version::default());
+
+        let versions = [
+            Version {
+                major: 0,
+                minor: 9,
+                patch: 9,
+            },
+            Version {
+                major: 1,
+                minor: 0,
+                patch: 0,
+            },
+            Version {
+                major: 1,
+                minor: 0,
+                patch: 1,
+            },
+            Version {
+                major: 1,
+                minor: 2,
+                patch: 0,
+            },
+        ];
+        for a in &versions {
+            for b in &versions {
+                assert_eq!(a.at_least(b), a >= b, "{:?} vs {:?}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn packing() {
+        let closet = Cuboid { w: 3, h: 5, l: 4 };
+
+        assert_eq!(how_many_fit(&closet, &closet), 1);
+        assert_eq!(how_many_fit(&Cuboid { w: 4, h: 1, l: 1 }, &closet), 15);
+        assert_eq!(how_many_fit(&Cuboid { w: 6, h: 1, l: 1 }, &closet), 0);
+        assert_eq!(
+            how_many_fit(&Cuboid { w: 0, h: 1, l: 1 }, &closet),
+            usize::MAX
+        );
+        assert_eq!(
+            how_many_fit(&CUBE_5_BY_2_BY_4, &Cuboid { w: 0, h: 0, l: 0 }),
+            0
+        );
+
+        // turning the tile sideways is the only way it fits
+        let tile = Rectangle { w: 1, h: 3 };
+        assert_eq!(how_many_fit_2d(&tile, &Rectangle { w: 6, h: 2 }), 4);
+        assert_eq!(how_many_fit_2d(&tile, &Rectangle { w: 2, h: 2 }), 0);
+        assert_eq!(
+            how_many_fit_2d(&Rectangle { w: 0, h: 3 }, &tile),
+            usize::MAX
+        );
+    }
+
+    #[test]
+    fn aspect() {
+        use aspect::AspectRatio;
+        use std::collections::HashSet;
+
+        assert_eq!(RECT_2_BY_3.aspect(), AspectRatio::new(4, 6));
+        assert_eq!(RECT_2_BY_3.scaled(3).aspect(), RECT_2_BY_3.aspect());
+        assert_ne!(RECT_2_BY_3.aspect(), AspectRatio::new(3, 2));
+
+        // a degenerate rectangle is just as degenerate at any size
+        assert_eq!(AspectRatio::new(0, 5), AspectRatio::new(0, 1));
+        assert_ne!(AspectRatio::new(0, 5), AspectRatio::new(5, 0));
+        let none = AspectRatio::new(0, 0).reduced();
+        assert_eq!((none.w, none.h), (0, 0));
+
+        let ratios = [(16, 9), (32, 18), (4, 3), (0, 7), (0, 1)]
+            .iter()
+            .map(|&(w, h)| AspectRatio::new(w, h))
+            .collect::<HashSet<_>>();
+        assert_eq!(ratios.len(), 3);
+    }
+
+    #[test]
+    fn eq_and_hash() {
+        use std::collections::HashSet;
+
+        let rects = [
+            Rectangle { w: 2, h: 3 },
+            Rectangle { w: 3, h: 2 },
+            RECT_2_BY_3,
+        ];
+        assert_eq!(rects.iter().collect::<HashSet<_>>().len(), 2);
+        assert!(rects.iter().all(|r| r.congruent(&RECT_2_BY_3)));
+        assert!(!RECT_2_BY_3.congruent(&Rectangle { w: 2, h: 2 }));
+
+        let cubes = [
+            Cuboid { w: 5, h: 2, l: 4 },
+            Cuboid { w: 4, h: 5, l: 2 },
+            Cuboid { w: 2, h: 4, l: 5 },
+            CUBE_5_BY_2_BY_4,
+        ];
+        assert_eq!(cubes.iter().collect::<HashSet<_>>().len(), 3);
+        assert!(cubes.iter().all(|c| c.congruent(&CUBE_5_BY_2_BY_4)));
+        assert!(!CUBE_5_BY_2_BY_4.congruent(&CUBE_4_BY_5_BY_1));
+        assert!(!Cuboid { w: 1, h: 1, l: 2 }.congruent(&Cuboid { w: 1, h: 2, l: 2 }));
+    }
+
+    #[test]
+    fn units() {
+        use std::collections::HashSet;
+        use unit::{Tagged, Unit};
+
+        // no derives in sight
+        struct Inch;
+        impl Unit for Inch {
+            const NAME: &'static str = "in";
+        }
+
+        let a: Tagged<Rectangle, Inch> = analog_literal! { in Inch; +--+ |  | +--+ };
+        let b = a;
+        assert!(a == b);
+        assert_eq!([a, b].iter().collect::<HashSet<_>>().len(), 1);
+        assert_eq!(format!("{:?}", a), "in(Rectangle { w: 1, h: 1 })");
+    }
+
+    #[test]
+    fn positioned_overflow() {
+        // hangs off the right-hand side of the grid
+        let edge = PositionedRectangle {
+            x: usize::MAX - 1,
+            y: 0,
+            w: 4,
+            h: 1,
+        };
+        let start = PositionedRectangle { x: 0, ..edge };
+
+        assert!(edge.contains_point(usize::MAX - 1, 0));
+        assert!(!edge.contains_point(usize::MAX, 0));
+        assert_eq!(
+            edge.intersection(&edge),
+            Some(PositionedRectangle { w: 1, ..edge })
+        );
+        assert!(!edge.intersects(&start));
+        assert_eq!(
+            edge.union_bounds(&start),
+            PositionedRectangle {
+                w: usize::MAX,
+                ..start
+            }
+        );
+    }
+
+    #[test]
+    fn analog_shapes() {
+        fn describe<S: AnalogShape>(shape: &S) -> (usize, [usize; 3], usize) {
+            let extents = Axis::ALL.map(|axis| shape.extent(axis));
+            (S::DIMENSIONS, extents, shape.measure())
+        }
+
+        let at = PositionedRectangle {
+            x: 4,
+            y: 1,
+            w: 2,
+            h: 3,
+        };
+        let labeled = LabeledRectangle {
+            rect: RECT_2_BY_3,
+            label: "OK",
+        };
+        let interval = interval::Interval { start: 2, end: 5 };
+
+        assert_eq!(describe(&at), describe(&RECT_2_BY_3));
+        assert_eq!(describe(&labeled), describe(&RECT_2_BY_3));
+        assert_eq!(describe(&interval), describe(&Line(3)));
+    }
+
+    #[test]
+    fn scalars() {
+        const BIG: Rectangle<u8> = analog_literal! { u8;
+            +--------------------------------+
+            |                                |
+            +--------------------------------+
+        };
+        assert_eq!(BIG.area(), 16u8);
+        assert_eq!(Rectangle { w: BIG.w, h: 16 }.checked_area(), None);
+        assert_eq!(Rectangle { w: BIG.w, h: 16 }.saturating_area(), u8::MAX);
+        assert!(BIG.congruent(&Rectangle { w: 1, h: 16 }));
+
+        let cube: Cuboid<i64> = analog_literal! { i64;
+              +----+
+             /    /|
+            +----+ |
+            |    | +
+            |    |/
+            +----+
+        };
+        assert_eq!(cube.volume(), 4);
+        assert_eq!(cube.top(), Rectangle { w: 2, h: 1 });
+        assert!(cube.congruent(&Cuboid { w: 1, h: 2, l: 2 }));
+
+        assert_eq!(analog_literal! { u16; I----I }, 2u16);
+    }
+
+    #[test]
+    fn assert_analog_eq() {
+        assert_analog_eq!(CUBE_5_BY_2_BY_4, CUBE_5_BY_2_BY_4);
+        assert_analog_eq!(Line(3), Line(analog_literal! { I------I }));
+
+        let msg = std::panic::catch_unwind(|| {
+            assert_analog_eq!(CUBE_5_BY_2_BY_4, CUBE_4_BY_5_BY_1);
+        })
+        .unwrap_err()
+        .downcast::<String>()
+        .unwrap();
+        assert!(msg.contains("  left: Cuboid { w: 5, h: 2, l: 4 }\n"));
+        assert!(msg.contains(" right: Cuboid { w: 4, h: 5, l: 1 }\n"));
+        assert!(msg.contains("diff: w: 5 != 4 h: 2 != 5 l: 4 != 1"));
+        assert!(msg.ends_with(
+            "
+     +----------+      +--------+
+    /          /|     /        /|
+   /          / |    +--------+ |
+  /          /  +    |        | |
+ /          /  /     |        | |
++----------+  /      |        | |
+|          | /       |        | +
+|          |/        |        |/
++----------+         +--------+"
+        ));
+    }
+
+    #[cfg(feature = "derive")]
+    #[test]
+    fn analog_debug() {
+        #[derive(AnalogDebug)]
+        #[analog_debug(crate = crate)]
+        struct Shelf<'a, T, const N: usize = 2>
+        where
+            T: Copy,
+        {
+            items: [T; N],
+            #[allow(dead_code)]
+            pub(crate) shelf: &'a Rectangle,
+            spine: Line,
+        }
+
+        #[derive(AnalogDebug)]
+        #[analog_debug(crate = crate)]
+        struct Crate(Cuboid, u8);
+
+        #[derive(AnalogDebug)]
+        #[analog_debug(crate = crate)]
+        struct Nothing;
+
+        let shelf = Shelf {
+            items: ['a', 'b'],
+            shelf: &Rectangle { w: 2, h: 1 },
+            spine: Line(1),
+        };
+        assert_eq!(
+            format!("{:?}", shelf),
+            "Shelf { items: ['a', 'b'], shelf: +----+ |    | +----+, spine: +--+ }"
+        );
+        assert_eq!(
+            format!("{:#?}", shelf),
+            "Shelf {\n    items: [\n        'a',\n        'b',\n    ],\n    shelf:\n    +----+\n    |    |\n    +----+,\n    spine:\n    +--+,\n}"
+        );
+        assert_eq!(
+            format!("{:#?}", Crate(Cuboid { w: 1, h: 1, l: 1 }, 7)),
+            "Crate(\n      +--+\n     /  /|\n    +--+ +\n    |  |/\n    +--+,\n    7,\n)"
+        );
+        assert_eq!(format!("{:?}", Nothing), "Nothing");
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde() {
+        #[derive(Debug, serde::Serialize, serde::Deserialize)]
+        struct Art {
+            #[serde(with = "crate::art")]
+            line: Line,
+            #[serde(with = "crate::art")]
+            cuboid: Cuboid,
+            #[serde(with = "crate::art")]
+            shape: parse::Shape,
+        }
+
+        let json = serde_json::to_string(&CUBE_5_BY_2_BY_4).unwrap();
+        assert_eq!(json, r#"{"w":5,"h":2,"l":4}"#);
+        let cube: Cuboid = serde_json::from_str(&json).unwrap();
+        assert_eq!((cube.w, cube.h, cube.l), (5, 2, 4));
+
+        let json = serde_json::to_string(&parse::Shape::Line(Line(3))).unwrap();
+        assert_eq!(json, r#"{"Line":3}"#);
+
+        let art = Art {
+            line: Line(2),
+            cuboid: CUBE_4_BY_5_BY_1,
+            shape: parse::Shape::Rectangle(RECT_2_BY_3),
+        };
+        let json = serde_json::to_string(&art).unwrap();
+        assert!(json.starts_with(r#"{"line":"+----+","cuboid":"  +--------+\n /"#));
+        let art: Art = serde_json::from_str(&json).unwrap();
+        assert_eq!(art.line.0, 2);
+        assert_eq!((art.cuboid.w, art.cuboid.h, art.cuboid.l), (4, 5, 1));
+        match art.shape {
+            parse::Shape::Rectangle(rect) => assert_eq!((rect.w, rect.h), (2, 3)),
+            _ => panic!("that's no rectangle"),
+        }
+
+        let err =
+            serde_json::from_str::<Art>(r#"{"line":"+---+","cuboid":"","shape":""}"#).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("1:2: edges must be drawn using pairs"));
+    }
+
+    #[cfg(feature = "macros")]
+    #[test]
+    fn from_file() {
+        const RIG: Cuboid = analog_literal_from_file!("../diagrams/rig.txt");
+        assert_eq!((RIG.w, RIG.h, RIG.l), (21, 1, 16));
+    }
+
+    #[test]
+    fn typed() {
+        let cube: typed::Cuboid<5, 2, 4> = analog_literal_typed! {
+                 +----------+
+                /          /|
+               /          / |
+              /          /  +
+             /          /  /
+            +----------+  /
+            |          | /
+            |          |/
+            +----------+
+        };
+        assert_eq!(cube.erase().volume(), CUBE_5_BY_2_BY_4.volume());
+
+        let rect: typed::Rect<2, 3> = analog_literal_typed! {
+            +----+
+            |    |
+            |    |
+            |    |
+            +----+
+        };
+        assert_eq!(rect.area(), RECT_2_BY_3.area());
+
+        let line: typed::Line<3> = analog_literal_typed! { I------I };
+        assert_eq!(line.array('-'), ['-'; 3]);
+        let _: typed::Line<0> = analog_literal_typed! { II };
+        let _: typed::Line<2> = analog_literal_typed! { +----+ };
+    }
+
+    #[test]
+    fn array() {
+        let buf = analog_array![0u8; +------+];
+        assert_eq!(buf, [0; 3]);
+
+        let buf = analog_array![0u8; +----+
+                                     |    |
+                                     |    |
+                                     |    |
+                                     +----+];
+        assert_eq!(buf, [[0; 2]; 3]);
+
+        let buf = analog_array![(); +--------+
+                                   /        /|
+                                  /        / |
+                                 +--------+  +
+                                 |        | /
+                                 |        |/
+                                 +--------+];
+        assert_eq!(buf, [[[(); 4]; 2]; 2]);
+    }
+
     macro_rules! const_assert {
         ($x:expr $(,)?) => {
             #[allow(unknown_lints)]
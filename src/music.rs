@@ -0,0 +1,65 @@
+//! Music, drawn right in the source code.
+//!
+//! Musicians have been drawing ASCII art for decades, and frankly, they've
+//! gotten pretty good at it. The types in this module let the compiler read
+//! along.
+
+/// A guitar chord, as read off of a chord box.
+///
+/// Use the [`analog_chord!`](crate::analog_chord) macro to construct them.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Chord {
+    /// The fret held down on each string, from the low E string on the left of
+    /// the chord box to the high E string on the right.
+    ///
+    /// Open strings are `Some(0)`, and muted strings are `None`.
+    pub frets: [Option<u8>; 6],
+}
+
+/// A set of keys on a piano, as picked out on a drawing of one.
+///
+/// Keys are identified by how many semitones above the leftmost C in the
+/// drawing they are. Use the [`analog_piano!`](crate::analog_piano) macro to
+/// construct them.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PianoKeys {
+    /// Bit `n` is set if the key `n` semitones above the leftmost C is marked
+    pub mask: u128,
+}
+
+impl PianoKeys {
+    /// Check if the key the given number of semitones above the leftmost C is
+    /// marked.
+    pub const fn contains(&self, semitone: u8) -> bool {
+        semitone < 128 && self.mask & (1 << semitone) != 0
+    }
+
+    /// Return the number of marked keys.
+    pub const fn len(&self) -> usize {
+        self.mask.count_ones() as usize
+    }
+
+    /// Check if no keys are marked at all.
+    pub const fn is_empty(&self) -> bool {
+        self.mask == 0
+    }
+
+    /// Iterate over the marked keys, in semitones above the leftmost C (from
+    /// low to high).
+    pub fn semitones(&self) -> impl Iterator<Item = u8> {
+        let mask = self.mask;
+        (0..128).filter(move |semitone| mask & (1 << semitone) != 0)
+    }
+
+    /// Iterate over the marked keys as MIDI note numbers (from low to high),
+    /// given the octave the leftmost C is in (e.g: middle C is in octave 4).
+    ///
+    /// Any keys that would end up above the range of MIDI notes are skipped.
+    pub fn midi_notes(&self, octave: u8) -> impl Iterator<Item = u8> {
+        let base = 12 * (octave as usize + 1);
+        self.semitones()
+            .map(move |semitone| base + semitone as usize)
+            .filter(|note| *note < 128)
+            .map(|note| note as u8)
+    }
+}
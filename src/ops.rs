@@ -0,0 +1,94 @@
+//! Operator overloads for analog literals.
+//!
+//! Everything in here is just a trait impl, with the interesting bits
+//! documented on the `const fn`s they defer to (e.g: [`Rectangle::scaled`]).
+
+use core::ops::{Add, Deref, Div, Mul, Sub};
+
+use crate::{Cuboid, Line, Rectangle};
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Rectangle<T> {
+    type Output = Rectangle<T>;
+
+    fn mul(self, n: T) -> Rectangle<T> {
+        Rectangle {
+            w: self.w * n,
+            h: self.h * n,
+        }
+    }
+}
+
+impl<T: Copy + Div<Output = T>> Div<T> for Rectangle<T> {
+    type Output = Rectangle<T>;
+
+    fn div(self, n: T) -> Rectangle<T> {
+        Rectangle {
+            w: self.w / n,
+            h: self.h / n,
+        }
+    }
+}
+
+impl<T: Copy + Mul<Output = T>> Mul<T> for Cuboid<T> {
+    type Output = Cuboid<T>;
+
+    fn mul(self, n: T) -> Cuboid<T> {
+        Cuboid {
+            w: self.w * n,
+            h: self.h * n,
+            l: self.l * n,
+        }
+    }
+}
+
+impl<T: Copy + Div<Output = T>> Div<T> for Cuboid<T> {
+    type Output = Cuboid<T>;
+
+    fn div(self, n: T) -> Cuboid<T> {
+        Cuboid {
+            w: self.w / n,
+            h: self.h / n,
+            l: self.l / n,
+        }
+    }
+}
+
+impl Mul<Line> for Line {
+    type Output = Rectangle;
+
+    fn mul(self, h: Line) -> Rectangle {
+        self.extrude(h.0)
+    }
+}
+
+impl Mul<Line> for Rectangle {
+    type Output = Cuboid;
+
+    fn mul(self, l: Line) -> Cuboid {
+        self.extrude(l.0)
+    }
+}
+
+impl Add for Line {
+    type Output = Line;
+
+    fn add(self, other: Line) -> Line {
+        Line(self.0 + other.0)
+    }
+}
+
+impl Sub for Line {
+    type Output = Line;
+
+    fn sub(self, other: Line) -> Line {
+        Line(self.0 - other.0)
+    }
+}
+
+impl Deref for Line {
+    type Target = usize;
+
+    fn deref(&self) -> &usize {
+        &self.0
+    }
+}
@@ -0,0 +1,93 @@
+use crate::{Cuboid, Rectangle};
+
+/// Return how many `item`s can be packed into `container`, by lining them up
+/// in a neat grid.
+///
+/// Every [orientation](Cuboid::orientations) of `item` is tried, and the best
+/// one wins. Note that every item in the grid faces the same way, so cleverer
+/// packings (e.g: standing a few extra items up in the leftover space) aren't
+/// considered. It's not called the packing _problem_ for nothing.
+///
+/// Items with a side of length 0 take up no space at all, and so fit
+/// `usize::MAX` times.
+///
+/// This only works with plain old `usize` cuboids. Cuboids made out of other
+/// scalars will have to be converted first.
+///
+/// # Example
+///
+/// ```rust
+/// # use analog_literals::{analog_literal, how_many_fit, Cuboid};
+/// const MINING_RIG: Cuboid = analog_literal! {
+///       +----+
+///      /    /|
+///     +----+ +
+///     |    |/
+///     +----+
+/// };
+/// const CLOSET: Cuboid = Cuboid { w: 3, h: 5, l: 4 };
+///
+/// const RIGS_PER_CLOSET: usize = how_many_fit(&MINING_RIG, &CLOSET);
+/// assert_eq!(RIGS_PER_CLOSET, 30);
+/// ```
+pub const fn how_many_fit(item: &Cuboid, container: &Cuboid) -> usize {
+    let orientations = item.orientations();
+    let mut best = 0;
+    let mut i = 0;
+    while i < orientations.len() {
+        let item = orientations[i];
+        let count = fit_along(item.w, container.w)
+            .saturating_mul(fit_along(item.h, container.h))
+            .saturating_mul(fit_along(item.l, container.l));
+        if count > best {
+            best = count;
+        }
+        i += 1;
+    }
+    best
+}
+
+/// Return how many `item`s can be tiled across `container`, by lining them up
+/// in a neat grid.
+///
+/// Just like [`how_many_fit`], except for rectangles (and so there's only two
+/// orientations to choose from). Also just like [`how_many_fit`], it only works
+/// with `usize` rectangles.
+///
+/// # Example
+///
+/// ```rust
+/// # use analog_literals::{analog_literal, how_many_fit_2d, Rectangle};
+/// const TILE: Rectangle = analog_literal! {
+///     +----+
+///     |    |
+///     +----+
+/// };
+/// const BATHROOM_FLOOR: Rectangle = analog_literal! {
+///     +----------+
+///     |          |
+///     |          |
+///     |          |
+///     |          |
+///     +----------+
+/// };
+///
+/// assert_eq!(how_many_fit_2d(&TILE, &BATHROOM_FLOOR), 10);
+/// ```
+pub const fn how_many_fit_2d(item: &Rectangle, container: &Rectangle) -> usize {
+    let upright = fit_along(item.w, container.w).saturating_mul(fit_along(item.h, container.h));
+    let sideways = fit_along(item.h, container.w).saturating_mul(fit_along(item.w, container.h));
+    if upright > sideways {
+        upright
+    } else {
+        sideways
+    }
+}
+
+/// Return how many `item`s can be lined up end-to-end along `container`.
+const fn fit_along(item: usize, container: usize) -> usize {
+    match container.checked_div(item) {
+        Some(n) => n,
+        None => usize::MAX,
+    }
+}
@@ -0,0 +1,685 @@
+//! Parsing analog literals out of plain old strings.
+//!
+//! Sometimes, analog literals come from places other than source code (e.g:
+//! config files, user input, or a particularly artistic database). This module
+//! parses exactly the same syntax as [`analog_literal!`](crate::analog_literal)
+//! does, just from a `&str` instead of a stream of tokens.
+//!
+//! That means whitespace is ignored, `//` and `/* */` comments are skipped
+//! (just like in Rust), and everything else has to be one of the characters
+//! that analog literals are made out of: `+`, `-`, `|`, `/`, `\`, and `I`.
+//!
+//! Wait, `\`? Yep! Since `\` isn't a valid Rust token, the macros can only
+//! handle cuboids that lean to the right (i.e: drawn using `/`). Strings don't
+//! have that problem, so cuboids that lean to the left are fair game here:
+//!
+//! ```rust
+//! use analog_literals::Cuboid;
+//!
+//! let cube: Cuboid = r"
+//!     +------+
+//!     |\      \
+//!     | +------+
+//!     + |      |
+//!      \|      |
+//!       +------+
+//! "
+//! .parse()
+//! .unwrap();
+//!
+//! assert_eq!((cube.w, cube.h, cube.l), (3, 2, 1));
+//! ```
+//!
+//! Everything in here is a `const fn`, so literals can be parsed at compile
+//! time too.
+//!
+//! # Example
+//!
+//! ```rust
+//! use analog_literals::{parse, Rectangle};
+//!
+//! let rect: Rectangle = "
+//!     +------+
+//!     |      |
+//!     +------+
+//! "
+//! .parse()
+//! .unwrap();
+//!
+//! assert_eq!((rect.w, rect.h), (3, 1));
+//!
+//! let err = parse::parse("+---+").unwrap_err();
+//! assert_eq!((err.row, err.col), (1, 2));
+//! assert_eq!(err.kind, parse::ParseErrorKind::OddDashes);
+//!
+//! // works in const contexts as well!
+//! const SQUARE: Rectangle = match Rectangle::parse("+----+ |    | |    | +----+") {
+//!     Ok(rect) => rect,
+//!     Err(_) => panic!("that's not a rectangle"),
+//! };
+//! assert_eq!(SQUARE.area(), 4);
+//! ```
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::{Cuboid, Line, Rectangle};
+
+/// Any one of the three kinds of analog literals.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Shape {
+    /// A 1D analog literal
+    Line(Line),
+    /// A 2D analog literal
+    Rectangle(Rectangle),
+    /// A 3D analog literal
+    Cuboid(Cuboid),
+}
+
+impl From<Line> for Shape {
+    fn from(line: Line) -> Shape {
+        Shape::Line(line)
+    }
+}
+
+impl From<Rectangle> for Shape {
+    fn from(rect: Rectangle) -> Shape {
+        Shape::Rectangle(rect)
+    }
+}
+
+impl From<Cuboid> for Shape {
+    fn from(cuboid: Cuboid) -> Shape {
+        Shape::Cuboid(cuboid)
+    }
+}
+
+/// The different ways parsing an analog literal can go wrong.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ParseErrorKind {
+    /// Found a character that isn't part of an analog literal
+    UnexpectedChar,
+    /// Found a perfectly good `+`, `-`, `|`, `/`, `\` or `I`, just not in the
+    /// right spot
+    UnexpectedToken,
+    /// The literal ended before it was finished
+    UnexpectedEnd,
+    /// A `/*` comment was never closed
+    UnterminatedComment,
+    /// An edge was drawn using an odd number of `-`
+    OddDashes,
+    /// Two edges of the literal that should be the same length aren't
+    Mismatched,
+    /// The literal is perfectly fine, but it's not the kind of literal that
+    /// was asked for (e.g: parsing a [`Line`] as a [`Rectangle`])
+    WrongShape,
+}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let msg = match self {
+            ParseErrorKind::UnexpectedChar => "unexpected character",
+            ParseErrorKind::UnexpectedToken => "unexpected token",
+            ParseErrorKind::UnexpectedEnd => "unexpected end of literal",
+            ParseErrorKind::UnterminatedComment => "unterminated block comment",
+            ParseErrorKind::OddDashes => "edges must be drawn using pairs of `--`",
+            ParseErrorKind::Mismatched => "opposite edges must be the same length",
+            ParseErrorKind::WrongShape => "wrong kind of analog literal",
+        };
+        f.write_str(msg)
+    }
+}
+
+/// An error encountered while parsing an analog literal.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ParseError {
+    /// Line the error occurred on (1-indexed)
+    pub row: usize,
+    /// Column the error occurred at, counted in `char`s (1-indexed)
+    pub col: usize,
+    /// What went wrong
+    pub kind: ParseErrorKind,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.row, self.col, self.kind)
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+/// Like `?`, but `const`.
+macro_rules! tri {
+    ($e:expr) => {
+        match $e {
+            Ok(val) => val,
+            Err(e) => return Err(e),
+        }
+    };
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Tok {
+    Plus,
+    Dash,
+    Pipe,
+    Slash,
+    Backslash,
+    I,
+    End,
+}
+
+impl Tok {
+    const fn is(self, other: Tok) -> bool {
+        self as u8 == other as u8
+    }
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Token {
+    tok: Tok,
+    row: usize,
+    col: usize,
+}
+
+impl Token {
+    const fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError {
+            row: self.row,
+            col: self.col,
+            kind,
+        }
+    }
+
+    /// Complain about this token being in the wrong spot.
+    const fn unexpected(&self) -> ParseError {
+        self.error(if self.tok.is(Tok::End) {
+            ParseErrorKind::UnexpectedEnd
+        } else {
+            ParseErrorKind::UnexpectedToken
+        })
+    }
+}
+
+#[derive(Copy, Clone)]
+struct Lexer<'a> {
+    src: &'a [u8],
+    pos: usize,
+    row: usize,
+    col: usize,
+}
+
+impl Lexer<'_> {
+    const fn new(src: &str) -> Lexer<'_> {
+        Lexer {
+            src: src.as_bytes(),
+            pos: 0,
+            row: 1,
+            col: 1,
+        }
+    }
+
+    const fn at(&self, offset: usize) -> u8 {
+        if self.pos + offset < self.src.len() {
+            self.src[self.pos + offset]
+        } else {
+            0
+        }
+    }
+
+    const fn bump(&mut self) {
+        let b = self.src[self.pos];
+        self.pos += 1;
+        if b == b'\n' {
+            self.row += 1;
+            self.col = 1;
+        } else if b & 0xC0 != 0x80 {
+            // only count the first byte of each UTF-8 encoded `char`
+            self.col += 1;
+        }
+    }
+
+    const fn here(&self, tok: Tok) -> Token {
+        Token {
+            tok,
+            row: self.row,
+            col: self.col,
+        }
+    }
+
+    /// Skip over any whitespace and comments.
+    const fn skip(&mut self) -> Result<(), ParseError> {
+        while self.pos < self.src.len() {
+            match (self.at(0), self.at(1)) {
+                (b' ' | b'\t' | b'\r' | b'\n', _) => self.bump(),
+                (b'/', b'/') => {
+                    while self.pos < self.src.len() && self.at(0) != b'\n' {
+                        self.bump();
+                    }
+                }
+                (b'/', b'*') => {
+                    let start = self.here(Tok::End);
+                    let mut depth = 0;
+                    loop {
+                        match (self.at(0), self.at(1)) {
+                            (0, _) if self.pos >= self.src.len() => {
+                                return Err(start.error(ParseErrorKind::UnterminatedComment))
+                            }
+                            (b'/', b'*') => {
+                                depth += 1;
+                                self.bump();
+                                self.bump();
+                            }
+                            (b'*', b'/') => {
+                                depth -= 1;
+                                self.bump();
+                                self.bump();
+                                if depth == 0 {
+                                    break;
+                                }
+                            }
+                            _ => self.bump(),
+                        }
+                    }
+                }
+                _ => break,
+            }
+        }
+        Ok(())
+    }
+
+    const fn next(&mut self) -> Result<Token, ParseError> {
+        tri!(self.skip());
+        if self.pos >= self.src.len() {
+            return Ok(self.here(Tok::End));
+        }
+
+        let tok = match self.at(0) {
+            b'+' => Tok::Plus,
+            b'-' => Tok::Dash,
+            b'|' => Tok::Pipe,
+            b'/' => Tok::Slash,
+            b'\\' => Tok::Backslash,
+            b'I' => Tok::I,
+            _ => return Err(self.here(Tok::End).error(ParseErrorKind::UnexpectedChar)),
+        };
+        let token = self.here(tok);
+        self.bump();
+        Ok(token)
+    }
+
+    const fn peek(&self) -> Result<Tok, ParseError> {
+        let mut lexer = *self;
+        match lexer.next() {
+            Ok(token) => Ok(token.tok),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Like [`Lexer::peek`], except peeking one token further ahead.
+    const fn peek2(&self) -> Result<Tok, ParseError> {
+        let mut lexer = *self;
+        tri!(lexer.next());
+        lexer.peek()
+    }
+
+    /// Consume the next token, making sure it's the right one.
+    const fn expect(&mut self, tok: Tok) -> Result<Token, ParseError> {
+        let token = tri!(self.next());
+        if token.tok.is(tok) {
+            Ok(token)
+        } else {
+            Err(token.unexpected())
+        }
+    }
+
+    /// Consume a series of `-`, returning how many `--` there were.
+    const fn dashes(&mut self) -> Result<usize, ParseError> {
+        let mut n = 0;
+        let mut first = self.here(Tok::Dash);
+        while tri!(self.peek()).is(Tok::Dash) {
+            let token = tri!(self.next());
+            if n == 0 {
+                first = token;
+            }
+            n += 1;
+        }
+        if n % 2 != 0 {
+            return Err(first.error(ParseErrorKind::OddDashes));
+        }
+        Ok(n / 2)
+    }
+
+    /// Make sure the literal is over.
+    const fn end(&mut self) -> Result<(), ParseError> {
+        match self.expect(Tok::End) {
+            Ok(_) => Ok(()),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+/// Make sure a pair of edges match up.
+const fn check(a: usize, b: usize, at: Token) -> Result<(), ParseError> {
+    if a == b {
+        Ok(())
+    } else {
+        Err(at.error(ParseErrorKind::Mismatched))
+    }
+}
+
+/// Parse any kind of analog literal.
+pub const fn parse(s: &str) -> Result<Shape, ParseError> {
+    match parse_shape(s) {
+        Ok((shape, _)) => Ok(shape),
+        Err(e) => Err(e),
+    }
+}
+
+/// Parse any kind of analog literal, also returning the token that decided
+/// which kind of literal it was (for complaining about it being the wrong
+/// kind).
+const fn parse_shape(s: &str) -> Result<(Shape, Token), ParseError> {
+    let mut lexer = Lexer::new(s);
+    let first = tri!(lexer.next());
+    match first.tok {
+        Tok::I => {
+            let len = tri!(lexer.dashes());
+            tri!(lexer.expect(Tok::I));
+            tri!(lexer.end());
+            Ok((Shape::Line(Line(len)), first))
+        }
+        Tok::Plus => {
+            let w = tri!(lexer.dashes());
+            tri!(lexer.expect(Tok::Plus));
+            let mut ahead = lexer;
+            let decider = tri!(ahead.next());
+            let shape = match decider.tok {
+                Tok::End => Shape::Line(Line(w)),
+                Tok::Slash => Shape::Cuboid(tri!(cuboid(&mut lexer, w))),
+                Tok::Backslash => Shape::Cuboid(tri!(flat_cuboid(&mut lexer, w, Tok::Backslash))),
+                Tok::Pipe | Tok::Plus if tri!(lexer.peek2()).is(Tok::Backslash) => {
+                    Shape::Cuboid(tri!(cuboid_left(&mut lexer, w)))
+                }
+                _ => Shape::Rectangle(tri!(rectangle(&mut lexer, w))),
+            };
+            Ok((shape, decider))
+        }
+        _ => Err(first.unexpected()),
+    }
+}
+
+/// Parse the rest of a rectangle, after its top edge.
+const fn rectangle(lexer: &mut Lexer<'_>, w: usize) -> Result<Rectangle, ParseError> {
+    let mut h = 0;
+    while tri!(lexer.peek()).is(Tok::Pipe) {
+        tri!(lexer.next());
+        tri!(lexer.expect(Tok::Pipe));
+        h += 1;
+    }
+
+    let corner = tri!(lexer.expect(Tok::Plus));
+    let bottom_w = tri!(lexer.dashes());
+    tri!(lexer.expect(Tok::Plus));
+    tri!(lexer.end());
+    tri!(check(w, bottom_w, corner));
+
+    Ok(Rectangle { w, h })
+}
+
+/// Parse the rest of a cuboid, after its top edge.
+const fn cuboid(lexer: &mut Lexer<'_>, w: usize) -> Result<Cuboid, ParseError> {
+    if tri!(is_flat(lexer)) {
+        return flat_cuboid(lexer, w, Tok::Slash);
+    }
+
+    // the top face, along with the right-hand face until it reaches its
+    // back-bottom corner
+    let (mut h, mut l) = (0, 0);
+    let mut bottom_l = 0;
+    let mut cornered = false;
+    loop {
+        tri!(lexer.expect(Tok::Slash));
+        tri!(lexer.expect(Tok::Slash));
+        l += 1;
+        let token = tri!(lexer.next());
+        match token.tok {
+            Tok::Pipe if !cornered => h += 1,
+            Tok::Plus if !cornered => cornered = true,
+            Tok::Slash if cornered => bottom_l += 1,
+            _ => return Err(token.unexpected()),
+        }
+        if tri!(lexer.peek()).is(Tok::Plus) {
+            break;
+        }
+    }
+
+    // the front face's top edge
+    let corner = tri!(lexer.expect(Tok::Plus));
+    let mid_w = tri!(lexer.dashes());
+    tri!(lexer.expect(Tok::Plus));
+    tri!(check(w, mid_w, corner));
+    let token = tri!(lexer.next());
+    let mut bottom_h = 0;
+    match token.tok {
+        Tok::Plus => {}
+        Tok::Pipe => h += 1,
+        Tok::Slash => bottom_l += 1,
+        _ => return Err(token.unexpected()),
+    }
+
+    // the front face, along with whatever's left of the right-hand face
+    while tri!(lexer.peek()).is(Tok::Pipe) {
+        tri!(lexer.next());
+        tri!(lexer.expect(Tok::Pipe));
+        bottom_h += 1;
+        let token = tri!(lexer.next());
+        match token.tok {
+            Tok::Plus => {}
+            Tok::Slash => bottom_l += 1,
+            Tok::Pipe => h += 1,
+            _ => return Err(token.unexpected()),
+        }
+    }
+
+    // the front face's bottom edge
+    let corner = tri!(lexer.expect(Tok::Plus));
+    let bottom_w = tri!(lexer.dashes());
+    tri!(lexer.expect(Tok::Plus));
+    tri!(lexer.end());
+    tri!(check(w, bottom_w, corner));
+    tri!(check(h, bottom_h, corner));
+    tri!(check(l, bottom_l, corner));
+
+    Ok(Cuboid { w, h, l })
+}
+
+/// Check if the cuboid coming up has no height, which leaves its top row as a
+/// lone pair of `/` (i.e: without any right-hand face after it).
+const fn is_flat(lexer: &Lexer<'_>) -> Result<bool, ParseError> {
+    let mut ahead = *lexer;
+    tri!(ahead.next());
+    tri!(ahead.next());
+    Ok(match tri!(ahead.peek()) {
+        Tok::Slash => true,
+        // the front face's top edge (which has no `-` if there's no width)
+        Tok::Plus => matches!(tri!(ahead.peek2()), Tok::Dash | Tok::Plus),
+        _ => false,
+    })
+}
+
+/// Parse the rest of a cuboid with no height, after its top edge.
+///
+/// Flat cuboids look the same either way they lean, give or take which way
+/// the `slash` goes.
+const fn flat_cuboid(lexer: &mut Lexer<'_>, w: usize, slash: Tok) -> Result<Cuboid, ParseError> {
+    let mut l = 0;
+    while tri!(lexer.peek()).is(slash) {
+        tri!(lexer.next());
+        tri!(lexer.expect(slash));
+        l += 1;
+    }
+
+    // the front face, squashed down into a single edge
+    let corner = tri!(lexer.expect(Tok::Plus));
+    let bottom_w = tri!(lexer.dashes());
+    tri!(lexer.expect(Tok::Plus));
+    tri!(lexer.end());
+    tri!(check(w, bottom_w, corner));
+
+    Ok(Cuboid { w, h: 0, l })
+}
+
+/// Parse the rest of a cuboid leaning to the left (i.e: drawn using `\`),
+/// after its top edge.
+///
+/// This is [`cuboid`] in a mirror: the left-hand face comes _before_ the rest
+/// of each row, instead of after it.
+const fn cuboid_left(lexer: &mut Lexer<'_>, w: usize) -> Result<Cuboid, ParseError> {
+    // the left-hand face, until it reaches its back-bottom corner, along with
+    // the top face
+    let (mut h, mut l) = (0, 0);
+    let mut bottom_l = 0;
+    let mut cornered = false;
+    loop {
+        let token = tri!(lexer.next());
+        match token.tok {
+            Tok::Pipe if !cornered => h += 1,
+            Tok::Plus if !cornered => cornered = true,
+            Tok::Backslash if cornered => bottom_l += 1,
+            _ => return Err(token.unexpected()),
+        }
+        if tri!(lexer.peek()).is(Tok::Plus) {
+            break;
+        }
+        tri!(lexer.expect(Tok::Backslash));
+        tri!(lexer.expect(Tok::Backslash));
+        l += 1;
+    }
+
+    // the front face's top edge
+    let corner = tri!(lexer.expect(Tok::Plus));
+    let mid_w = tri!(lexer.dashes());
+    tri!(lexer.expect(Tok::Plus));
+    tri!(check(w, mid_w, corner));
+
+    // whatever's left of the left-hand face, along with the front face
+    let mut bottom_h = 0;
+    loop {
+        let next = tri!(lexer.peek());
+        let bottom = next.is(Tok::Plus) && !tri!(lexer.peek2()).is(Tok::Pipe);
+        if next.is(Tok::End) || bottom {
+            break;
+        }
+
+        let token = tri!(lexer.next());
+        match token.tok {
+            Tok::Plus => {}
+            Tok::Backslash => bottom_l += 1,
+            Tok::Pipe => h += 1,
+            _ => return Err(token.unexpected()),
+        }
+        tri!(lexer.expect(Tok::Pipe));
+        tri!(lexer.expect(Tok::Pipe));
+        bottom_h += 1;
+    }
+
+    // the front face's bottom edge
+    let corner = tri!(lexer.expect(Tok::Plus));
+    let bottom_w = tri!(lexer.dashes());
+    tri!(lexer.expect(Tok::Plus));
+    tri!(lexer.end());
+    tri!(check(w, bottom_w, corner));
+    tri!(check(h, bottom_h, corner));
+    tri!(check(l, bottom_l, corner));
+
+    Ok(Cuboid { w, h, l })
+}
+
+impl Line {
+    /// Parse a 1D analog literal.
+    pub const fn parse(s: &str) -> Result<Line, ParseError> {
+        match parse_shape(s) {
+            Ok((Shape::Line(line), _)) => Ok(line),
+            Ok((_, at)) => Err(at.error(ParseErrorKind::WrongShape)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Rectangle {
+    /// Parse a 2D analog literal.
+    ///
+    /// If it turns out to be some other kind of literal, the error points at
+    /// the spot where it stopped looking like a rectangle:
+    ///
+    /// ```rust
+    /// # use analog_literals::{parse::ParseErrorKind, Rectangle};
+    /// let cube = "
+    ///   +--+
+    ///  /  /|
+    /// +--+ +
+    /// |  |/
+    /// +--+";
+    ///
+    /// let err = Rectangle::parse(cube).unwrap_err();
+    /// assert_eq!(err.kind, ParseErrorKind::WrongShape);
+    /// assert_eq!((err.row, err.col), (3, 2));
+    /// ```
+    pub const fn parse(s: &str) -> Result<Rectangle, ParseError> {
+        match parse_shape(s) {
+            Ok((Shape::Rectangle(rect), _)) => Ok(rect),
+            Ok((_, at)) => Err(at.error(ParseErrorKind::WrongShape)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl Cuboid {
+    /// Parse a 3D analog literal.
+    ///
+    /// A cuboid with no length looks exactly like its front face, so plain
+    /// old rectangles are parsed as cuboids with `l: 0`.
+    pub const fn parse(s: &str) -> Result<Cuboid, ParseError> {
+        match parse_shape(s) {
+            Ok((Shape::Cuboid(cuboid), _)) => Ok(cuboid),
+            Ok((Shape::Rectangle(Rectangle { w, h }), _)) => Ok(Cuboid { w, h, l: 0 }),
+            Ok((_, at)) => Err(at.error(ParseErrorKind::WrongShape)),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl FromStr for Shape {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Shape, ParseError> {
+        parse(s)
+    }
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Line, ParseError> {
+        Line::parse(s)
+    }
+}
+
+impl FromStr for Rectangle {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Rectangle, ParseError> {
+        Rectangle::parse(s)
+    }
+}
+
+impl FromStr for Cuboid {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Cuboid, ParseError> {
+        Cuboid::parse(s)
+    }
+}
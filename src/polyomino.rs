@@ -0,0 +1,92 @@
+//! Polyominoes (i.e: falling block game pieces), drawn right in the source
+//! code.
+//!
+//! Use the [`analog_polyomino!`](crate::analog_polyomino) macro to construct
+//! them.
+
+use core::hash::{Hash, Hasher};
+
+use crate::Rectangle;
+
+/// A polyomino of up to 8x8 cells.
+///
+/// Polyominoes are always trimmed down to their bounding box, so two
+/// polyominoes are equal if (and only if) they have the same shape. That said,
+/// it would be rude to assume two polyominoes are the same just because
+/// they're rotations of one another, so they're not.
+#[derive(Debug, Copy, Clone)]
+pub struct Polyomino {
+    /// Bit `y * 8 + x` is set if the cell at `(x, y)` is filled in (with
+    /// `(0, 0)` being the top-left corner of the bounding box)
+    pub mask: u64,
+    /// The bounding box of the filled in cells
+    pub bounds: Rectangle,
+}
+
+impl PartialEq for Polyomino {
+    fn eq(&self, other: &Polyomino) -> bool {
+        self.mask == other.mask
+    }
+}
+
+impl Eq for Polyomino {}
+
+impl Hash for Polyomino {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.mask.hash(state)
+    }
+}
+
+impl Polyomino {
+    /// Check if the cell at `(x, y)` is filled in.
+    pub const fn contains(&self, x: usize, y: usize) -> bool {
+        x < 8 && y < 8 && self.mask & (1 << (y * 8 + x)) != 0
+    }
+
+    /// Return the number of filled in cells (e.g: 4 for a tetromino).
+    pub const fn size(&self) -> usize {
+        self.mask.count_ones() as usize
+    }
+
+    /// Rotate the polyomino a quarter turn clockwise.
+    pub const fn rotate_cw(&self) -> Polyomino {
+        let Rectangle { w, h } = self.bounds;
+        let mut mask = 0;
+        let mut y = 0;
+        while y < h {
+            let mut x = 0;
+            while x < w {
+                if self.contains(x, y) {
+                    mask |= 1 << (x * 8 + (h - 1 - y));
+                }
+                x += 1;
+            }
+            y += 1;
+        }
+        Polyomino {
+            mask,
+            bounds: Rectangle { w: h, h: w },
+        }
+    }
+
+    /// Rotate the polyomino a half turn.
+    pub const fn rotate_180(&self) -> Polyomino {
+        self.rotate_cw().rotate_cw()
+    }
+
+    /// Rotate the polyomino a quarter turn counter-clockwise.
+    pub const fn rotate_ccw(&self) -> Polyomino {
+        self.rotate_cw().rotate_cw().rotate_cw()
+    }
+
+    /// Return all 4 rotations of the polyomino, clockwise, starting with the
+    /// polyomino as drawn.
+    pub const fn rotations(&self) -> [Polyomino; 4] {
+        [
+            *self,
+            self.rotate_cw(),
+            self.rotate_180(),
+            self.rotate_ccw(),
+        ]
+    }
+}
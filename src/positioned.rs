@@ -0,0 +1,33 @@
+use crate::Rectangle;
+
+/// A 2D Rectangle with a certain width and height, drawn at a particular spot.
+///
+/// Positions are measured using the same units as the rectangle's dimensions:
+/// `x` counts how many `--` of whitespace are to the left of the rectangle,
+/// and `y` counts how many rows of whitespace are above it.
+///
+/// Produced by the [`analog_positioned!`](crate::analog_positioned) and
+/// [`analog_scene!`](crate::analog_scene) macros. Collision checks (e.g:
+/// [`PositionedRectangle::intersects`]) are only a method call away: see the
+/// [`geometry`](crate::geometry) module.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct PositionedRectangle {
+    /// Horizontal position of the literal's top-left corner
+    pub x: usize,
+    /// Vertical position of the literal's top-left corner
+    pub y: usize,
+    /// Width of the literal (counts how many `--` wide the analog literal is)
+    pub w: usize,
+    /// Height of the literal (counts how many `|` tall the analog literal is)
+    pub h: usize,
+}
+
+impl PositionedRectangle {
+    /// Returns the [`Rectangle`] at the heart of this positioned rectangle.
+    pub const fn rect(&self) -> Rectangle {
+        Rectangle {
+            w: self.w,
+            h: self.h,
+        }
+    }
+}
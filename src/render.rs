@@ -0,0 +1,782 @@
+//! Drawing analog literals back out as ASCII art.
+//!
+//! Every analog literal implements [`Display`](core::fmt::Display), which is
+//! great right up until there's no allocator around to `to_string()` into. For
+//! those occasions, each shape also comes with:
+//!
+//! - `render_into`, which draws the shape into any [`core::fmt::Write`] (e.g: a
+//!   serial console)
+//! - `render_to_slice`, which draws the shape into a plain old `&mut [u8]`
+//! - `rendered_size`, which says how much room the drawing is going to take up
+//!   _before_ committing to drawing it
+//!
+//! Prefer proper box-drawing characters? Use the alternate flag (i.e: `{:#}`),
+//! or `render_unicode_into`:
+//!
+//! ```text
+//! +------+     ┌──────┐
+//! |      |  => │      │
+//! +------+     └──────┘
+//! ```
+//!
+//! Each of these also has a `_with` variant accepting a [`RenderOptions`], for
+//! tweaking things like the scale of the drawing, or which way cuboids lean.
+//!
+//! For when there's no text to be had at all, shapes can also be drawn into a
+//! plain old monochrome framebuffer using `rasterize` (or `rasterize_with`).
+//!
+//! With the `alloc` feature enabled, rectangles and cuboids can also be
+//! exported as SVG images using `to_svg` (or `to_svg_with`), for when ASCII art
+//! just won't cut it.
+//!
+//! # Example
+//!
+//! ```rust
+//! use analog_literals::analog_literal;
+//!
+//! const WINDOW: analog_literals::Rectangle = analog_literal! {
+//!     +----+
+//!     |    |
+//!     +----+
+//! };
+//!
+//! const SIZE: analog_literals::render::Size = WINDOW.rendered_size();
+//! let mut buf = [0; SIZE.max_len()];
+//! let drawn = WINDOW.render_to_slice(&mut buf).unwrap();
+//!
+//! assert_eq!(drawn, "+----+\n|    |\n+----+");
+//! assert_eq!((SIZE.cols, SIZE.rows), (6, 3));
+//!
+//! // and if the buffer's too small, nothing bad happens
+//! assert!(WINDOW.render_to_slice(&mut [0; 4]).is_err());
+//! ```
+
+use core::fmt::{self, Write};
+
+use crate::parse::Shape;
+use crate::{Cuboid, Line, Rectangle};
+
+mod raster;
+#[cfg(feature = "alloc")]
+mod svg;
+
+/// How much room a drawing takes up, in characters.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Size {
+    /// Width of the widest row of the drawing
+    pub cols: usize,
+    /// Number of rows in the drawing
+    pub rows: usize,
+}
+
+impl Size {
+    /// Return the number of bytes it takes to hold the ASCII drawing (newlines
+    /// included), assuming every row is as wide as the widest one.
+    ///
+    /// Since rows are never padded out with trailing whitespace, the actual
+    /// drawing may well come in under this.
+    pub const fn max_len(&self) -> usize {
+        self.max_len_with(&RenderOptions::new())
+    }
+
+    /// Return the number of bytes it takes to hold the drawing when drawn
+    /// using the given options (newlines included).
+    ///
+    /// Box-drawing characters (and fancy fills) take up more than one byte
+    /// apiece, so this is the one to use when sizing a buffer for
+    /// `render_to_slice_with`.
+    ///
+    /// ```rust
+    /// # use analog_literals::analog_literal;
+    /// use analog_literals::render::{GlyphSet, RenderOptions};
+    ///
+    /// const OPTIONS: RenderOptions = RenderOptions::new().glyphs(GlyphSet::Unicode);
+    /// const WINDOW: analog_literals::Rectangle = analog_literal! {
+    ///     +----+
+    ///     |    |
+    ///     +----+
+    /// };
+    ///
+    /// let mut buf = [0; WINDOW.rendered_size_with(&OPTIONS).max_len_with(&OPTIONS)];
+    /// let drawn = WINDOW.render_to_slice_with(&mut buf, &OPTIONS).unwrap();
+    /// assert_eq!(drawn, "┌────┐\n│    │\n└────┘");
+    /// ```
+    pub const fn max_len_with(&self, opts: &RenderOptions) -> usize {
+        if self.rows == 0 {
+            return 0;
+        }
+        let glyph = match opts.glyphs {
+            GlyphSet::Ascii => 1,
+            // every box-drawing character lives in U+2500..U+257F
+            GlyphSet::Unicode => 3,
+        };
+        let fill = opts.fill.len_utf8();
+        let widest = if fill > glyph { fill } else { glyph };
+        self.rows * (self.cols * widest + 1) - 1
+    }
+}
+
+/// A [`fmt::Write`] that writes into a fixed-size buffer.
+struct SliceWriter<'a> {
+    buf: &'a mut [u8],
+    len: usize,
+}
+
+impl Write for SliceWriter<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let dst = self
+            .buf
+            .get_mut(self.len..self.len + s.len())
+            .ok_or(fmt::Error)?;
+        dst.copy_from_slice(s.as_bytes());
+        self.len += s.len();
+        Ok(())
+    }
+}
+
+fn render_to_slice(
+    buf: &mut [u8],
+    render: impl FnOnce(&mut SliceWriter<'_>) -> fmt::Result,
+) -> Result<&str, fmt::Error> {
+    let mut out = SliceWriter { buf, len: 0 };
+    render(&mut out)?;
+    let SliceWriter { buf, len } = out;
+    // only ever gets whole `&str`s written into it
+    core::str::from_utf8(&buf[..len]).map_err(|_| fmt::Error)
+}
+
+/// Which characters to draw shapes with.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum GlyphSet {
+    /// Good old `+`, `-`, `|`, and `/`, exactly as the `analog_literal!` macro
+    /// expects them.
+    Ascii,
+    /// Unicode box-drawing characters (`┌`, `─`, `│`, `╱`, etc...).
+    Unicode,
+}
+
+/// Which way a cuboid's depth recedes into the distance.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Perspective {
+    /// Up and to the right (i.e: `/`), which is how the `analog_literal!`
+    /// macro likes its cuboids.
+    Right,
+    /// Up and to the left (i.e: `\`), for those who draw their boxes the
+    /// other way.
+    Left,
+}
+
+/// Knobs for tweaking how shapes get drawn.
+///
+/// Every rendering entry point (`Display`, `render_into`, `render_to_slice`,
+/// `rasterize`, `to_svg`, and friends) goes through a `RenderOptions` of some
+/// sort, and the `_with` variants of each accept a custom one.
+///
+/// Pixels don't come in glyphs, so `rasterize_with` and `to_svg_with` only
+/// care about the `scale` and `perspective`, and quietly ignore the `glyphs`
+/// and `fill`.
+///
+/// # Example
+///
+/// ```rust
+/// use analog_literals::analog_literal;
+/// use analog_literals::render::{GlyphSet, Perspective, RenderOptions};
+///
+/// const OPTIONS: RenderOptions = RenderOptions::new()
+///     .glyphs(GlyphSet::Unicode)
+///     .perspective(Perspective::Left)
+///     .fill('░');
+///
+/// let cube = analog_literal! {
+///       +--+
+///      /  /|
+///     +--+ +
+///     |  |/
+///     +--+
+/// };
+///
+/// let mut drawn = String::new();
+/// cube.render_with(&mut drawn, &OPTIONS).unwrap();
+/// assert_eq!(drawn, "┌──┐\n│╲░░╲\n└░┌──┐\n ╲│░░│\n  └──┘");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct RenderOptions {
+    /// Which characters to draw with (default: [`GlyphSet::Ascii`])
+    pub glyphs: GlyphSet,
+    /// How many times larger than life to draw the shape (default: `1`)
+    pub scale: usize,
+    /// Which way cuboids recede into the distance (default:
+    /// [`Perspective::Right`])
+    pub perspective: Perspective,
+    /// What to fill the faces of the shape with (default: `' '`)
+    pub fill: char,
+}
+
+impl RenderOptions {
+    /// Return the default options, which draw shapes exactly as the
+    /// `analog_literal!` macro expects them.
+    pub const fn new() -> RenderOptions {
+        RenderOptions {
+            glyphs: GlyphSet::Ascii,
+            scale: 1,
+            perspective: Perspective::Right,
+            fill: ' ',
+        }
+    }
+
+    /// Set which characters to draw with.
+    pub const fn glyphs(self, glyphs: GlyphSet) -> RenderOptions {
+        RenderOptions { glyphs, ..self }
+    }
+
+    /// Set how many times larger than life to draw the shape.
+    pub const fn scale(self, scale: usize) -> RenderOptions {
+        RenderOptions { scale, ..self }
+    }
+
+    /// Set which way cuboids recede into the distance.
+    pub const fn perspective(self, perspective: Perspective) -> RenderOptions {
+        RenderOptions {
+            perspective,
+            ..self
+        }
+    }
+
+    /// Set what to fill the faces of the shape with.
+    pub const fn fill(self, fill: char) -> RenderOptions {
+        RenderOptions { fill, ..self }
+    }
+
+    const fn unicode() -> RenderOptions {
+        RenderOptions::new().glyphs(GlyphSet::Unicode)
+    }
+}
+
+impl Default for RenderOptions {
+    fn default() -> RenderOptions {
+        RenderOptions::new()
+    }
+}
+
+/// The different bits and pieces a drawing is made of.
+#[derive(Copy, Clone)]
+enum Part {
+    Blank,
+    Fill,
+    Horizontal,
+    Vertical,
+    Diagonal,
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+    LineStart,
+    LineEnd,
+}
+
+impl Part {
+    /// Return the part as it would look in a mirror.
+    fn mirror(self) -> Part {
+        match self {
+            Part::TopLeft => Part::TopRight,
+            Part::TopRight => Part::TopLeft,
+            Part::BottomLeft => Part::BottomRight,
+            Part::BottomRight => Part::BottomLeft,
+            Part::LineStart => Part::LineEnd,
+            Part::LineEnd => Part::LineStart,
+            part => part,
+        }
+    }
+
+    fn glyph(self, opts: &RenderOptions) -> char {
+        let mirrored = opts.perspective == Perspective::Left;
+        match (opts.glyphs, self) {
+            (_, Part::Blank) => ' ',
+            (_, Part::Fill) => opts.fill,
+            (GlyphSet::Ascii, Part::Horizontal) => '-',
+            (GlyphSet::Ascii, Part::Vertical) => '|',
+            (GlyphSet::Ascii, Part::Diagonal) if mirrored => '\\',
+            (GlyphSet::Ascii, Part::Diagonal) => '/',
+            (GlyphSet::Ascii, _) => '+',
+            (GlyphSet::Unicode, Part::Horizontal) => '─',
+            (GlyphSet::Unicode, Part::Vertical) => '│',
+            (GlyphSet::Unicode, Part::Diagonal) if mirrored => '╲',
+            (GlyphSet::Unicode, Part::Diagonal) => '╱',
+            (GlyphSet::Unicode, Part::TopLeft) => '┌',
+            (GlyphSet::Unicode, Part::TopRight) => '┐',
+            (GlyphSet::Unicode, Part::BottomLeft) => '└',
+            (GlyphSet::Unicode, Part::BottomRight) => '┘',
+            (GlyphSet::Unicode, Part::LineStart) => '├',
+            (GlyphSet::Unicode, Part::LineEnd) => '┤',
+        }
+    }
+}
+
+/// Something that can be drawn one character at a time.
+trait Drawing {
+    fn size(&self) -> Size;
+
+    /// Return the first and last (inclusive) columns drawn on in the given
+    /// row. Everything before the first column is left blank, and everything
+    /// after the last column is left out entirely.
+    fn row_extent(&self, row: usize) -> (usize, usize);
+
+    fn part(&self, row: usize, col: usize) -> Part;
+
+    fn draw(&self, out: &mut impl Write, opts: &RenderOptions) -> fmt::Result {
+        for row in 0..self.size().rows {
+            if row != 0 {
+                out.write_char('\n')?;
+            }
+            self.draw_row(out, row, opts, false)?;
+        }
+        Ok(())
+    }
+
+    /// Draw a single row of the drawing, optionally padding it out with
+    /// whitespace to the full width of the drawing. Rows past the bottom of
+    /// the drawing are left blank.
+    fn draw_row(
+        &self,
+        out: &mut impl Write,
+        row: usize,
+        opts: &RenderOptions,
+        pad: bool,
+    ) -> fmt::Result {
+        let size = self.size();
+        if row >= size.rows {
+            return if pad {
+                repeat(out, ' ', size.cols)
+            } else {
+                Ok(())
+            };
+        }
+
+        let (first, last) = self.row_extent(row);
+        if opts.perspective == Perspective::Left {
+            for col in (first..size.cols).rev() {
+                out.write_char(self.part(row, col).mirror().glyph(opts))?;
+            }
+            if pad {
+                repeat(out, ' ', first)?;
+            }
+        } else {
+            for col in 0..=last {
+                out.write_char(self.part(row, col).glyph(opts))?;
+            }
+            if pad {
+                repeat(out, ' ', size.cols - last - 1)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+fn repeat(out: &mut impl Write, ch: char, n: usize) -> fmt::Result {
+    for _ in 0..n {
+        out.write_char(ch)?;
+    }
+    Ok(())
+}
+
+impl Drawing for Shape {
+    fn size(&self) -> Size {
+        match self {
+            Shape::Line(line) => line.drawn_size(),
+            Shape::Rectangle(rect) => rect.drawn_size(),
+            Shape::Cuboid(cuboid) => cuboid.drawn_size(),
+        }
+    }
+
+    fn row_extent(&self, row: usize) -> (usize, usize) {
+        match self {
+            Shape::Line(line) => line.row_extent(row),
+            Shape::Rectangle(rect) => rect.row_extent(row),
+            Shape::Cuboid(cuboid) => cuboid.row_extent(row),
+        }
+    }
+
+    fn part(&self, row: usize, col: usize) -> Part {
+        match self {
+            Shape::Line(line) => line.part(row, col),
+            Shape::Rectangle(rect) => rect.part(row, col),
+            Shape::Cuboid(cuboid) => cuboid.part(row, col),
+        }
+    }
+}
+
+/// Draw a pair of shapes next to one another.
+pub(crate) fn side_by_side(out: &mut impl Write, left: &Shape, right: &Shape) -> fmt::Result {
+    let opts = RenderOptions::new();
+    let rows = left.size().rows.max(right.size().rows);
+    for row in 0..rows {
+        if row != 0 {
+            out.write_char('\n')?;
+        }
+        left.draw_row(out, row, &opts, true)?;
+        out.write_str("    ")?;
+        right.draw_row(out, row, &opts, false)?;
+    }
+    Ok(())
+}
+
+impl Line {
+    const fn drawn_size(&self) -> Size {
+        Size {
+            cols: 2 * self.0 + 2,
+            rows: 1,
+        }
+    }
+}
+
+impl Drawing for Line {
+    fn size(&self) -> Size {
+        self.drawn_size()
+    }
+
+    fn row_extent(&self, _row: usize) -> (usize, usize) {
+        (0, 2 * self.0 + 1)
+    }
+
+    fn part(&self, _row: usize, col: usize) -> Part {
+        match col {
+            0 => Part::LineStart,
+            _ if col == 2 * self.0 + 1 => Part::LineEnd,
+            _ => Part::Horizontal,
+        }
+    }
+}
+
+impl Rectangle {
+    const fn drawn_size(&self) -> Size {
+        Size {
+            cols: 2 * self.w + 2,
+            rows: self.h + 2,
+        }
+    }
+}
+
+impl Drawing for Rectangle {
+    fn size(&self) -> Size {
+        self.drawn_size()
+    }
+
+    fn row_extent(&self, _row: usize) -> (usize, usize) {
+        (0, 2 * self.w + 1)
+    }
+
+    fn part(&self, row: usize, col: usize) -> Part {
+        let (bottom, right) = (self.h + 1, 2 * self.w + 1);
+        match (row, col) {
+            (0, 0) => Part::TopLeft,
+            (0, _) if col == right => Part::TopRight,
+            _ if row == bottom && col == 0 => Part::BottomLeft,
+            _ if row == bottom && col == right => Part::BottomRight,
+            (0, _) => Part::Horizontal,
+            _ if row == bottom => Part::Horizontal,
+            (_, 0) => Part::Vertical,
+            _ if col == right => Part::Vertical,
+            _ => Part::Fill,
+        }
+    }
+}
+
+impl Cuboid {
+    const fn drawn_size(&self) -> Size {
+        // cuboids with no length look exactly like their front face
+        if self.l == 0 {
+            return self.front().drawn_size();
+        }
+        Size {
+            cols: self.back_col() + 1,
+            // cuboids with no height have their front face squashed down into
+            // a single edge
+            rows: if self.h == 0 {
+                self.l + 2
+            } else {
+                self.l + self.h + 3
+            },
+        }
+    }
+
+    /// Return the column of the back-right edge.
+    const fn back_col(&self) -> usize {
+        self.l + self.inner_cols() + 2
+    }
+
+    /// Return how many columns wide the inside of the front (and top) face
+    /// is.
+    ///
+    /// Cuboids with no width still get a single column, since drawing their
+    /// top face as a bunch of `//` would turn it into a comment.
+    const fn inner_cols(&self) -> usize {
+        if self.w == 0 {
+            1
+        } else {
+            2 * self.w
+        }
+    }
+
+    /// Return the visible part of the right face in the given row, along
+    /// with the column it's in.
+    fn right_face(&self, row: usize) -> (usize, Part) {
+        let back = self.back_col();
+        match row {
+            _ if row <= self.h => (back, Part::Vertical),
+            _ if row == self.h + 1 => (back, Part::BottomRight),
+            _ => (back - (row - self.h - 1), Part::Diagonal),
+        }
+    }
+
+    /// Like [`Drawing::part`], except for cuboids with no height (and
+    /// therefore no right face).
+    fn flat_part(&self, row: usize, col: usize, first: usize, last: usize) -> Part {
+        match row {
+            0 => match col {
+                _ if col == first => Part::TopLeft,
+                _ if col == last => Part::TopRight,
+                _ => Part::Horizontal,
+            },
+            _ if row == self.l + 1 => match col {
+                _ if col == first => Part::BottomLeft,
+                _ if col == last => Part::BottomRight,
+                _ => Part::Horizontal,
+            },
+            _ if col == first || col == last => Part::Diagonal,
+            _ => Part::Fill,
+        }
+    }
+}
+
+impl Drawing for Cuboid {
+    fn size(&self) -> Size {
+        self.drawn_size()
+    }
+
+    fn row_extent(&self, row: usize) -> (usize, usize) {
+        let Cuboid { h, l, .. } = *self;
+        match row {
+            _ if l == 0 => self.front().row_extent(row),
+            0 => (l + 1, self.back_col()),
+            _ if h == 0 && row <= l => (l + 1 - row, self.back_col() - row),
+            _ if h == 0 => (0, self.back_col() - l - 1),
+            _ if row <= l => (l + 1 - row, self.right_face(row).0),
+            _ if row <= l + h + 1 => (0, self.right_face(row).0),
+            _ => (0, self.back_col() - l - 1),
+        }
+    }
+
+    fn part(&self, row: usize, col: usize) -> Part {
+        if self.l == 0 {
+            return self.front().part(row, col);
+        }
+        match self.solid_part(row, col) {
+            // with no width, there's no edges running across it either
+            Part::Horizontal if self.w == 0 => Part::Blank,
+            part => part,
+        }
+    }
+}
+
+impl Cuboid {
+    /// Like [`Drawing::part`], except for cuboids with some length to them.
+    fn solid_part(&self, row: usize, col: usize) -> Part {
+        let Cuboid { h, l, .. } = *self;
+        let (first, last) = self.row_extent(row);
+        if col < first || col > last {
+            return Part::Blank;
+        }
+
+        if h == 0 {
+            return self.flat_part(row, col, first, last);
+        }
+
+        // the front (or top) face ends here, and the right face begins
+        let front = if row <= l {
+            self.back_col() - row
+        } else {
+            self.back_col() - l - 1
+        };
+
+        match row {
+            _ if row == 0 || row == l + 1 => match col {
+                _ if col == first => Part::TopLeft,
+                _ if col == front => Part::TopRight,
+                _ if col < front => Part::Horizontal,
+                _ if col == last => self.right_face(row).1,
+                _ => Part::Fill,
+            },
+            _ if row == l + h + 2 => match col {
+                _ if col == first => Part::BottomLeft,
+                _ if col == last => Part::BottomRight,
+                _ => Part::Horizontal,
+            },
+            _ => match col {
+                _ if col == first || col == front => {
+                    if row <= l {
+                        Part::Diagonal
+                    } else {
+                        Part::Vertical
+                    }
+                }
+                _ if col == last => self.right_face(row).1,
+                _ => Part::Fill,
+            },
+        }
+    }
+}
+
+impl RenderOptions {
+    const fn line(&self, line: &Line) -> Line {
+        Line(line.0 * self.scale)
+    }
+
+    const fn rectangle(&self, rect: &Rectangle) -> Rectangle {
+        Rectangle {
+            w: rect.w * self.scale,
+            h: rect.h * self.scale,
+        }
+    }
+
+    const fn cuboid(&self, cuboid: &Cuboid) -> Cuboid {
+        Cuboid {
+            w: cuboid.w * self.scale,
+            h: cuboid.h * self.scale,
+            l: cuboid.l * self.scale,
+        }
+    }
+}
+
+macro_rules! entry_points {
+    ($shape:ident, $name:literal, $scaled:ident, $example:literal) => {
+        impl $shape {
+            #[doc = concat!("Draw the ", $name, " into the given writer, exactly as it would be drawn")]
+            /// by its `Display` impl.
+            pub fn render_into(&self, out: &mut impl Write) -> fmt::Result {
+                self.render_with(out, &RenderOptions::new())
+            }
+
+            #[doc = concat!("Like [`", stringify!($shape), "::render_into`], except the ", $name, " is drawn using")]
+            #[doc = concat!("Unicode box-drawing characters (e.g: `", $example, "`).")]
+            pub fn render_unicode_into(&self, out: &mut impl Write) -> fmt::Result {
+                self.render_with(out, &RenderOptions::unicode())
+            }
+
+            #[doc = concat!("Draw the ", $name, " into the given writer, using the given options.")]
+            pub fn render_with(&self, out: &mut impl Write, opts: &RenderOptions) -> fmt::Result {
+                opts.$scaled(self).draw(out, opts)
+            }
+
+            #[doc = concat!("Draw the ", $name, " into the given buffer, returning the part of the")]
+            /// buffer that was drawn on. Errors out if the buffer is too small.
+            pub fn render_to_slice<'a>(&self, buf: &'a mut [u8]) -> Result<&'a str, fmt::Error> {
+                self.render_to_slice_with(buf, &RenderOptions::new())
+            }
+
+            #[doc = concat!("Like [`", stringify!($shape), "::render_to_slice`], except using the given options.")]
+            pub fn render_to_slice_with<'a>(
+                &self,
+                buf: &'a mut [u8],
+                opts: &RenderOptions,
+            ) -> Result<&'a str, fmt::Error> {
+                render_to_slice(buf, |out| self.render_with(out, opts))
+            }
+
+            #[doc = concat!("Return how much room the ", $name, " takes up when drawn.")]
+            pub const fn rendered_size(&self) -> Size {
+                self.rendered_size_with(&RenderOptions::new())
+            }
+
+            #[doc = concat!("Return how much room the ", $name, " takes up when drawn using the given")]
+            /// options. Use [`Size::max_len_with`] (with the same options) to
+            /// turn that into a number of bytes.
+            pub const fn rendered_size_with(&self, opts: &RenderOptions) -> Size {
+                opts.$scaled(self).drawn_size()
+            }
+        }
+    };
+}
+
+entry_points!(Line, "line", line, "├────┤");
+entry_points!(Rectangle, "rectangle", rectangle, "┌──┐");
+entry_points!(Cuboid, "cuboid", cuboid, "┌──┐");
+
+/// ```rust
+/// # use analog_literals::{analog_literal, Line};
+/// let line = Line(analog_literal! { I------I });
+/// assert_eq!(line.to_string(), "+------+");
+/// ```
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            self.render_unicode_into(f)
+        } else {
+            self.render_into(f)
+        }
+    }
+}
+
+/// ```rust
+/// # use analog_literals::analog_literal;
+/// let rect = analog_literal! {
+///     +----+
+///     |    |
+///     +----+
+/// };
+/// assert_eq!(rect.to_string(), "+----+\n|    |\n+----+");
+/// assert_eq!(format!("{:#}", rect), "┌────┐\n│    │\n└────┘");
+/// ```
+impl fmt::Display for Rectangle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            self.render_unicode_into(f)
+        } else {
+            self.render_into(f)
+        }
+    }
+}
+
+/// Drawn, what else, with the back-right edge slanting off into the distance:
+///
+/// ```rust
+/// # use analog_literals::analog_literal;
+/// let cube = analog_literal! {
+///       +------+
+///      /      /|
+///     +------+ |
+///     |      | +
+///     |      |/
+///     +------+
+/// };
+///
+/// println!("{}", cube);
+/// assert_eq!(
+///     cube.to_string(),
+///     "  +------+\n /      /|\n+------+ |\n|      | +\n|      |/\n+------+"
+/// );
+/// ```
+///
+/// Cuboids with no length are drawn as nothing but their front face, and
+/// cuboids with no width get a single space where their width would be (so
+/// their top face doesn't turn into a `//` comment). Either way,
+/// [`Cuboid::parse`] reads them right back in.
+impl fmt::Display for Cuboid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            self.render_unicode_into(f)
+        } else {
+            self.render_into(f)
+        }
+    }
+}
+
+/// Whatever the shape, it gets drawn the same way it would've been on its own.
+impl fmt::Display for Shape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Shape::Line(line) => fmt::Display::fmt(line, f),
+            Shape::Rectangle(rect) => fmt::Display::fmt(rect, f),
+            Shape::Cuboid(cuboid) => fmt::Display::fmt(cuboid, f),
+        }
+    }
+}
@@ -0,0 +1,313 @@
+//! Rasterizing shapes into monochrome framebuffers.
+//!
+//! The `_with` variants take the same [`RenderOptions`] as the ASCII art, and
+//! honour its `scale` and `perspective`. There's only one colour of pixel to
+//! go around, so the `glyphs` and `fill` don't apply.
+
+use super::{Perspective, RenderOptions};
+use crate::{Cuboid, Line, Rectangle};
+
+/// The value of a pixel that's been drawn on.
+const ON: u8 = 0xff;
+
+/// A framebuffer with one byte per pixel, where every row is `stride` bytes
+/// long. Anything drawn outside the buffer is quietly clipped.
+///
+/// If `mirror` is set, everything is flipped left-to-right within a drawing
+/// that many pixels wide.
+struct Framebuffer<'a> {
+    buf: &'a mut [u8],
+    stride: usize,
+    mirror: Option<usize>,
+}
+
+impl<'a> Framebuffer<'a> {
+    fn new(buf: &'a mut [u8], stride: usize, opts: &RenderOptions, width: usize) -> Self {
+        let mirror = match opts.perspective {
+            Perspective::Right => None,
+            Perspective::Left => Some(width),
+        };
+        Framebuffer {
+            buf,
+            stride,
+            mirror,
+        }
+    }
+
+    fn set(&mut self, x: usize, y: usize) {
+        let x = match self.mirror {
+            Some(width) => width - 1 - x,
+            None => x,
+        };
+        if x >= self.stride {
+            return;
+        }
+        if let Some(px) = y
+            .checked_mul(self.stride)
+            .and_then(|row| self.buf.get_mut(row + x))
+        {
+            *px = ON;
+        }
+    }
+
+    fn hline(&mut self, x: usize, y: usize, len: usize) {
+        for x in x..=x + len {
+            self.set(x, y);
+        }
+    }
+
+    fn vline(&mut self, x: usize, y: usize, len: usize) {
+        for y in y..=y + len {
+            self.set(x, y);
+        }
+    }
+
+    /// Draw a 45° line going up and to the right from `(x, y)`.
+    fn diagonal(&mut self, x: usize, y: usize, len: usize) {
+        for k in 0..=len {
+            self.set(x + k, y - k);
+        }
+    }
+
+    fn rectangle(&mut self, x: usize, y: usize, w: usize, h: usize) {
+        self.hline(x, y, w);
+        self.hline(x, y + h, w);
+        self.vline(x, y, h);
+        self.vline(x + w, y, h);
+    }
+}
+
+impl Line {
+    /// Draw the line into a monochrome framebuffer, with every unit of length
+    /// taking up `scale` pixels.
+    ///
+    /// The framebuffer uses one byte per pixel, with each row being `stride`
+    /// bytes long. Pixels on the line are set to `0xff`, and everything else
+    /// is left as-is. Anything that doesn't fit in the buffer is clipped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use analog_literals::{analog_literal, Line};
+    /// let line = Line(analog_literal! { +----+ });
+    ///
+    /// let mut buf = [0; 8];
+    /// line.rasterize(&mut buf, 8, 2);
+    /// assert_eq!(buf, [0xff, 0xff, 0xff, 0xff, 0xff, 0, 0, 0]);
+    /// ```
+    pub fn rasterize(&self, buf: &mut [u8], stride: usize, scale: usize) {
+        self.rasterize_with(buf, stride, &RenderOptions::new().scale(scale))
+    }
+
+    /// Draw the line into a monochrome framebuffer, using custom
+    /// [`RenderOptions`].
+    pub fn rasterize_with(&self, buf: &mut [u8], stride: usize, opts: &RenderOptions) {
+        let (width, _) = self.rasterized_size_with(opts);
+        if width == 0 {
+            return;
+        }
+        Framebuffer::new(buf, stride, opts, width).hline(0, 0, self.0 * opts.scale);
+    }
+
+    /// Return the `(width, height)` in pixels of the line when rasterized at
+    /// the given scale.
+    pub const fn rasterized_size(&self, scale: usize) -> (usize, usize) {
+        self.rasterized_size_with(&RenderOptions::new().scale(scale))
+    }
+
+    /// Return the `(width, height)` in pixels of the line when rasterized
+    /// using custom [`RenderOptions`].
+    pub const fn rasterized_size_with(&self, opts: &RenderOptions) -> (usize, usize) {
+        if opts.scale == 0 {
+            return (0, 0);
+        }
+        (self.0 * opts.scale + 1, 1)
+    }
+}
+
+impl Rectangle {
+    /// Draw the outline of the rectangle into a monochrome framebuffer, with
+    /// every unit of width and height taking up `scale` pixels.
+    ///
+    /// The framebuffer uses one byte per pixel, with each row being `stride`
+    /// bytes long. Pixels on the outline are set to `0xff`, and everything
+    /// else is left as-is. Anything that doesn't fit in the buffer is clipped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use analog_literals::analog_literal;
+    /// let rect = analog_literal! {
+    ///     +------+
+    ///     |      |
+    ///     |      |
+    ///     +------+
+    /// };
+    ///
+    /// let (w, h) = rect.rasterized_size(1);
+    /// let mut buf = [0; 4 * 3];
+    /// rect.rasterize(&mut buf, w, 1);
+    /// assert_eq!(
+    ///     buf,
+    ///     [
+    ///         0xff, 0xff, 0xff, 0xff,
+    ///         0xff, 0x00, 0x00, 0xff,
+    ///         0xff, 0xff, 0xff, 0xff,
+    ///     ]
+    /// );
+    /// # assert_eq!((w, h), (4, 3));
+    /// ```
+    pub fn rasterize(&self, buf: &mut [u8], stride: usize, scale: usize) {
+        self.rasterize_with(buf, stride, &RenderOptions::new().scale(scale))
+    }
+
+    /// Draw the outline of the rectangle into a monochrome framebuffer, using
+    /// custom [`RenderOptions`].
+    pub fn rasterize_with(&self, buf: &mut [u8], stride: usize, opts: &RenderOptions) {
+        let (width, _) = self.rasterized_size_with(opts);
+        if width == 0 {
+            return;
+        }
+        let (w, h) = (self.w * opts.scale, self.h * opts.scale);
+        Framebuffer::new(buf, stride, opts, width).rectangle(0, 0, w, h);
+    }
+
+    /// Return the `(width, height)` in pixels of the rectangle when rasterized
+    /// at the given scale.
+    pub const fn rasterized_size(&self, scale: usize) -> (usize, usize) {
+        self.rasterized_size_with(&RenderOptions::new().scale(scale))
+    }
+
+    /// Return the `(width, height)` in pixels of the rectangle when rasterized
+    /// using custom [`RenderOptions`].
+    pub const fn rasterized_size_with(&self, opts: &RenderOptions) -> (usize, usize) {
+        if opts.scale == 0 {
+            return (0, 0);
+        }
+        (self.w * opts.scale + 1, self.h * opts.scale + 1)
+    }
+}
+
+impl Cuboid {
+    /// Draw the (visible) outline of the cuboid into a monochrome
+    /// framebuffer, with every unit of width, height, and length taking up
+    /// `scale` pixels.
+    ///
+    /// Just like the ASCII art, the cuboid's depth recedes up and to the right
+    /// at a jaunty 45° angle, and the edges hidden around the back aren't
+    /// drawn.
+    ///
+    /// The framebuffer uses one byte per pixel, with each row being `stride`
+    /// bytes long. Pixels on the outline are set to `0xff`, and everything
+    /// else is left as-is. Anything that doesn't fit in the buffer is clipped.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use analog_literals::analog_literal;
+    /// let cube = analog_literal! {
+    ///       +----+
+    ///      /    /|
+    ///     +----+ +
+    ///     |    |/
+    ///     +----+
+    /// };
+    ///
+    /// let (w, h) = cube.rasterized_size(2);
+    /// let mut buf = [0; 7 * 5];
+    /// cube.rasterize(&mut buf, w, 2);
+    ///
+    /// let pixels = buf.iter().map(|&px| if px == 0 { '.' } else { '#' });
+    /// let rows = pixels.collect::<Vec<_>>();
+    /// let rows = rows.chunks(w).map(|row| row.iter().collect::<String>());
+    /// assert_eq!(
+    ///     rows.collect::<Vec<_>>(),
+    ///     [
+    ///         "..#####",
+    ///         ".#...##",
+    ///         "#####.#",
+    ///         "#...##.",
+    ///         "#####..",
+    ///     ]
+    /// );
+    /// # assert_eq!((w, h), (7, 5));
+    /// ```
+    pub fn rasterize(&self, buf: &mut [u8], stride: usize, scale: usize) {
+        self.rasterize_with(buf, stride, &RenderOptions::new().scale(scale))
+    }
+
+    /// Draw the (visible) outline of the cuboid into a monochrome
+    /// framebuffer, using custom [`RenderOptions`].
+    ///
+    /// With [`Perspective::Left`], the whole drawing is flipped over, so the
+    /// cuboid's depth recedes up and to the left instead.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use analog_literals::analog_literal;
+    /// use analog_literals::render::{Perspective, RenderOptions};
+    ///
+    /// let cube = analog_literal! {
+    ///       +--+
+    ///      /  /|
+    ///     +--+ +
+    ///     |  |/
+    ///     +--+
+    /// };
+    ///
+    /// let opts = RenderOptions::new().perspective(Perspective::Left);
+    /// let (w, h) = cube.rasterized_size_with(&opts);
+    /// let mut buf = [0; 3 * 3];
+    /// cube.rasterize_with(&mut buf, w, &opts);
+    /// assert_eq!(
+    ///     buf,
+    ///     [
+    ///         0xff, 0xff, 0x00,
+    ///         0xff, 0xff, 0xff,
+    ///         0x00, 0xff, 0xff,
+    ///     ]
+    /// );
+    /// # assert_eq!((w, h), (3, 3));
+    /// ```
+    pub fn rasterize_with(&self, buf: &mut [u8], stride: usize, opts: &RenderOptions) {
+        let (width, _) = self.rasterized_size_with(opts);
+        if width == 0 {
+            return;
+        }
+        let (w, h, l) = (
+            self.w * opts.scale,
+            self.h * opts.scale,
+            self.l * opts.scale,
+        );
+        let mut fb = Framebuffer::new(buf, stride, opts, width);
+
+        // front
+        fb.rectangle(0, l, w, h);
+        // top
+        fb.diagonal(0, l, l);
+        fb.hline(l, 0, w);
+        fb.diagonal(w, l, l);
+        // side
+        fb.vline(w + l, 0, h);
+        fb.diagonal(w, h + l, l);
+    }
+
+    /// Return the `(width, height)` in pixels of the cuboid when rasterized at
+    /// the given scale.
+    pub const fn rasterized_size(&self, scale: usize) -> (usize, usize) {
+        self.rasterized_size_with(&RenderOptions::new().scale(scale))
+    }
+
+    /// Return the `(width, height)` in pixels of the cuboid when rasterized
+    /// using custom [`RenderOptions`].
+    pub const fn rasterized_size_with(&self, opts: &RenderOptions) -> (usize, usize) {
+        if opts.scale == 0 {
+            return (0, 0);
+        }
+        (
+            (self.w + self.l) * opts.scale + 1,
+            (self.h + self.l) * opts.scale + 1,
+        )
+    }
+}
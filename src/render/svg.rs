@@ -0,0 +1,173 @@
+//! SVG export.
+//!
+//! The `_with` variants take the same [`RenderOptions`] as the ASCII art, and
+//! honour its `scale` and `perspective`. Faces are always shaded in shades of
+//! grey and edges are always plain old lines, so the `glyphs` and `fill`
+//! don't apply.
+
+use alloc::string::String;
+use core::fmt::Write;
+
+use super::{Perspective, RenderOptions};
+use crate::{Cuboid, Rectangle};
+
+/// `cos(30°)`, for isometric projection.
+const COS_30: f64 = 0.866_025_403_784_438_6;
+
+/// Wrap the given shapes up in an `<svg>` element, with a bit of margin
+/// around the given bounding box so the outline doesn't get clipped.
+fn svg(min: (f64, f64), max: (f64, f64), body: &str) -> String {
+    let (w, h) = (max.0 - min.0 + 2.0, max.1 - min.1 + 2.0);
+    let mut svg = String::new();
+    let _ = write!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="{x} {y} {w} {h}">{body}</svg>"#,
+        x = min.0 - 1.0,
+        y = min.1 - 1.0,
+        w = w,
+        h = h,
+        body = body,
+    );
+    svg
+}
+
+impl Rectangle {
+    /// Draw the rectangle as an SVG image, with every unit of width and height
+    /// taking up `unit` pixels.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use analog_literals::analog_literal;
+    /// let rect = analog_literal! {
+    ///     +----+
+    ///     |    |
+    ///     +----+
+    /// };
+    ///
+    /// let svg = rect.to_svg(10.0);
+    /// assert!(svg.contains(r#"<rect x="0" y="0" width="20" height="10""#));
+    /// ```
+    pub fn to_svg(&self, unit: f64) -> String {
+        self.to_svg_with(unit, &RenderOptions::new())
+    }
+
+    /// Draw the rectangle as an SVG image, with every unit of width and height
+    /// taking up `unit` pixels, using custom [`RenderOptions`].
+    ///
+    /// ```rust
+    /// # use analog_literals::{analog_literal, render::RenderOptions};
+    /// let rect = analog_literal! {
+    ///     +----+
+    ///     |    |
+    ///     +----+
+    /// };
+    ///
+    /// let svg = rect.to_svg_with(10.0, &RenderOptions::new().scale(3));
+    /// assert!(svg.contains(r#"<rect x="0" y="0" width="60" height="30""#));
+    /// ```
+    pub fn to_svg_with(&self, unit: f64, opts: &RenderOptions) -> String {
+        let unit = unit * opts.scale as f64;
+        let (w, h) = (self.w as f64 * unit, self.h as f64 * unit);
+        let mut body = String::new();
+        let _ = write!(
+            body,
+            r#"<rect x="0" y="0" width="{}" height="{}" fill="none" stroke="black"/>"#,
+            w, h
+        );
+        svg((0.0, 0.0), (w, h), &body)
+    }
+}
+
+impl Cuboid {
+    /// Draw the cuboid as an SVG image, using an isometric projection where
+    /// every unit of width, height, and length is `unit` pixels long.
+    ///
+    /// Unlike the ASCII art, the top, front, and side faces are all shaded
+    /// differently, so there's no squinting required to figure out which way
+    /// is up.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use analog_literals::analog_literal;
+    /// let cube = analog_literal! {
+    ///       +----+
+    ///      /    /|
+    ///     +----+ +
+    ///     |    |/
+    ///     +----+
+    /// };
+    ///
+    /// let svg = cube.to_svg(10.0);
+    /// assert!(svg.starts_with("<svg"));
+    /// assert_eq!(svg.matches("<polygon").count(), 3);
+    /// ```
+    pub fn to_svg(&self, unit: f64) -> String {
+        self.to_svg_with(unit, &RenderOptions::new())
+    }
+
+    /// Draw the cuboid as an SVG image, using an isometric projection where
+    /// every unit of width, height, and length is `unit` pixels long, using
+    /// custom [`RenderOptions`].
+    ///
+    /// With [`Perspective::Left`], the whole drawing is flipped over, so the
+    /// side face ends up on the left.
+    ///
+    /// ```rust
+    /// # use analog_literals::analog_literal;
+    /// use analog_literals::render::{Perspective, RenderOptions};
+    ///
+    /// let cube = analog_literal! {
+    ///       +----+
+    ///      /    /|
+    ///     +----+ +
+    ///     |    |/
+    ///     +----+
+    /// };
+    ///
+    /// let right = cube.to_svg(10.0);
+    /// let left = cube.to_svg_with(10.0, &RenderOptions::new().perspective(Perspective::Left));
+    /// assert!(right.contains("17.32,10.00") && !right.contains("-17.32"));
+    /// assert!(left.contains("-17.32,10.00"));
+    /// ```
+    pub fn to_svg_with(&self, unit: f64, opts: &RenderOptions) -> String {
+        let unit = unit * opts.scale as f64;
+        let (w, h, l) = (
+            self.w as f64 * unit,
+            self.h as f64 * unit,
+            self.l as f64 * unit,
+        );
+        // width runs down and to the right, length runs down and to the left,
+        // and height runs straight up
+        // (or the other way around, if the drawing's been flipped over)
+        let flip = match opts.perspective {
+            Perspective::Right => 1.0,
+            Perspective::Left => -1.0,
+        };
+        let project = |x: f64, y: f64, z: f64| (flip * (x - z) * COS_30, (x + z) * 0.5 - y);
+
+        let faces = [
+            ("#eee", [(0.0, h, 0.0), (w, h, 0.0), (w, h, l), (0.0, h, l)]),
+            ("#ccc", [(0.0, 0.0, l), (w, 0.0, l), (w, h, l), (0.0, h, l)]),
+            ("#aaa", [(w, 0.0, 0.0), (w, h, 0.0), (w, h, l), (w, 0.0, l)]),
+        ];
+        let mut body = String::new();
+        for (fill, corners) in faces.iter() {
+            body.push_str(r#"<polygon points=""#);
+            for (i, &(x, y, z)) in corners.iter().enumerate() {
+                let (px, py) = project(x, y, z);
+                let sep = if i == 0 { "" } else { " " };
+                let _ = write!(body, "{}{:.2},{:.2}", sep, px, py);
+            }
+            let _ = write!(body, r#"" fill="{}" stroke="black"/>"#, fill);
+        }
+
+        let (left, right) = (project(0.0, 0.0, l).0, project(w, 0.0, 0.0).0);
+        svg(
+            (left.min(right), project(0.0, h, 0.0).1),
+            (left.max(right), project(w, 0.0, l).1),
+            &body,
+        )
+    }
+}
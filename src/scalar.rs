@@ -0,0 +1,341 @@
+//! Measuring rectangles and cuboids made out of something other than `usize`.
+//!
+//! The `usize` versions of these methods live alongside the types themselves
+//! (complete with docs and examples), and are `const` wherever possible. The
+//! ones in here are exactly the same, just stamped out for every primitive
+//! integer type, except that they're trait methods, and so can't be called in a
+//! `const` context (see [`ScalarRectangle`](ScalarRectangle#const-measuring)
+//! for why).
+
+use crate::{Cuboid, Rectangle};
+
+/// Measuring methods for [`Rectangle`]s made out of any primitive integer
+/// type.
+///
+/// `Rectangle<usize>` has (mostly `const`) versions of all of these as inherent
+/// methods (which is where the examples are), so this trait only needs to be in
+/// scope when working with some other scalar.
+///
+/// `Rectangle<usize>` implements this trait too (even though its inherent
+/// methods always win), so that generic code (e.g: `fn total<R:
+/// ScalarRectangle>(..)`) works with rectangles of every scalar, the default
+/// one included.
+///
+/// # `const` Measuring
+///
+/// Giving `Rectangle<u32>` its own inherent `area` would leave a plain old
+/// `Rectangle { w: 3, h: 2 }.area()` unable to tell which `area` it meant,
+/// which is why these are trait methods. The catch is that trait methods can't
+/// be called from a `const fn` (yet), so `const` code measuring some other
+/// scalar has to do the math on the fields directly:
+///
+/// ```rust
+/// use analog_literals::{analog_literal, Rectangle};
+///
+/// const TILE: Rectangle<u32> = analog_literal! { u32;
+///     +----+
+///     |    |
+///     +----+
+/// };
+/// const AREA: u32 = TILE.w * TILE.h;
+/// assert_eq!(AREA, 2);
+/// ```
+///
+/// The odd ones out are [`Rectangle::aspect`] and [`how_many_fit_2d`], which
+/// only work with `usize`s.
+///
+/// [`how_many_fit_2d`]: crate::how_many_fit_2d
+///
+/// # Example
+///
+/// ```rust
+/// use analog_literals::{analog_literal, Rectangle, ScalarRectangle};
+///
+/// const TILE: Rectangle<u32> = analog_literal! { u32;
+///     +----+
+///     |    |
+///     +----+
+/// };
+///
+/// assert_eq!(TILE.area(), 2u32);
+/// assert!(TILE.fits_inside(&Rectangle { w: 1, h: 2 }));
+/// assert!(TILE.cells().eq([(0u32, 0u32), (1, 0)]));
+/// ```
+pub trait ScalarRectangle: Sized {
+    /// The type of the rectangle's width and height.
+    type Scalar;
+
+    /// Return the area of the rectangle.
+    fn area(&self) -> Self::Scalar;
+
+    /// Return the area of the rectangle, or `None` if it doesn't fit in a
+    /// `Self::Scalar`.
+    fn checked_area(&self) -> Option<Self::Scalar>;
+
+    /// Return the area of the rectangle, saturating at the numeric bounds of
+    /// `Self::Scalar`.
+    fn saturating_area(&self) -> Self::Scalar;
+
+    /// Check if two rectangles are the same shape, even if one of them has
+    /// been rotated or flipped over.
+    fn congruent(&self, other: &Self) -> bool;
+
+    /// Return the perimeter of the rectangle.
+    fn perimeter(&self) -> Self::Scalar;
+
+    /// Return the square of the distance between two opposite corners of the
+    /// rectangle.
+    fn diagonal_squared(&self) -> Self::Scalar;
+
+    /// Check if the rectangle is a square.
+    fn is_square(&self) -> bool;
+
+    /// Check if `other` fits inside the rectangle, as-is (i.e: without being
+    /// rotated).
+    fn contains(&self, other: &Self) -> bool;
+
+    /// Check if the rectangle fits inside `container`, turning it on its side
+    /// if need be.
+    fn fits_inside(&self, container: &Self) -> bool;
+
+    /// Return the rectangle, blown up `n` times along each axis.
+    fn scaled(&self, n: Self::Scalar) -> Self;
+
+    /// Slice the rectangle in two with a horizontal cut, `at` `|`s down from
+    /// the top, returning the `(top, bottom)` halves.
+    ///
+    /// Returns `None` if the cut would miss the rectangle entirely.
+    fn split_horizontal(&self, at: Self::Scalar) -> Option<(Self, Self)>;
+
+    /// Slice the rectangle in two with a vertical cut, `at` `--`s in from the
+    /// left, returning the `(left, right)` halves.
+    ///
+    /// Returns `None` if the cut would miss the rectangle entirely.
+    fn split_vertical(&self, at: Self::Scalar) -> Option<(Self, Self)>;
+
+    /// Iterate over the `(x, y)` coordinates of every cell inside the
+    /// rectangle, one row at a time (i.e: left to right, top to bottom).
+    fn cells(&self) -> impl Iterator<Item = (Self::Scalar, Self::Scalar)>;
+
+    /// Drag the rectangle `l` `/`s backwards, turning it into a [`Cuboid`].
+    fn extrude(&self, l: Self::Scalar) -> Cuboid<Self::Scalar>;
+}
+
+/// Measuring methods for [`Cuboid`]s made out of any primitive integer type.
+///
+/// Just like with [`ScalarRectangle`], `Cuboid<usize>` has (mostly `const`)
+/// versions of all of these as inherent methods, so this trait only needs to be
+/// in scope when working with some other scalar (or writing generic code).
+/// [`const` code](ScalarRectangle#const-measuring) has to stick to the fields
+/// (e.g: `CRATE.w * CRATE.h * CRATE.l`).
+///
+/// The odd one out is [`how_many_fit`], which only works with `usize`s.
+///
+/// [`how_many_fit`]: crate::how_many_fit
+///
+/// # Example
+///
+/// ```rust
+/// use analog_literals::{analog_literal, Cuboid, ScalarCuboid};
+///
+/// let crate_: Cuboid<i16> = analog_literal! { i16;
+///       +--+
+///      /  /|
+///     +--+ +
+///     |  |/
+///     +--+
+/// };
+///
+/// assert_eq!(crate_.volume(), 1i16);
+/// assert_eq!(crate_.surface_area(), 6i16);
+/// assert_eq!(crate_.voxels().count(), 1);
+/// ```
+pub trait ScalarCuboid: Sized {
+    /// The type of the cuboid's width, height, and length.
+    type Scalar;
+
+    /// Return the volume of the cuboid.
+    fn volume(&self) -> Self::Scalar;
+
+    /// Return the volume of the cuboid, or `None` if it doesn't fit in a
+    /// `Self::Scalar`.
+    fn checked_volume(&self) -> Option<Self::Scalar>;
+
+    /// Return the volume of the cuboid, saturating at the numeric bounds of
+    /// `Self::Scalar`.
+    fn saturating_volume(&self) -> Self::Scalar;
+
+    /// Check if two cuboids are the same shape, even if one of them has been
+    /// rotated or flipped over.
+    fn congruent(&self, other: &Self) -> bool;
+
+    /// Return the total area of all six faces of the cuboid.
+    fn surface_area(&self) -> Self::Scalar;
+
+    /// Return the combined length of all twelve edges of the cuboid.
+    fn total_edge_length(&self) -> Self::Scalar;
+
+    /// Return the square of the distance between two opposite corners of the
+    /// cuboid.
+    fn space_diagonal_squared(&self) -> Self::Scalar;
+
+    /// Check if the cuboid fits inside `container`, in any of its
+    /// orientations.
+    fn fits_inside(&self, container: &Self) -> bool;
+
+    /// Return the cuboid, blown up `n` times along each axis.
+    fn scaled(&self, n: Self::Scalar) -> Self;
+
+    /// Iterate over the `(x, y, z)` coordinates of every voxel inside the
+    /// cuboid, one layer at a time (i.e: front to back, with each layer going
+    /// in the same order as [`ScalarRectangle::cells`]).
+    fn voxels(&self) -> impl Iterator<Item = (Self::Scalar, Self::Scalar, Self::Scalar)>;
+}
+
+macro_rules! scalar_impls {
+    ($($t:ty)*) => {$(
+        impl ScalarRectangle for Rectangle<$t> {
+            type Scalar = $t;
+
+            fn area(&self) -> $t {
+                self.w * self.h
+            }
+
+            fn checked_area(&self) -> Option<$t> {
+                self.w.checked_mul(self.h)
+            }
+
+            fn saturating_area(&self) -> $t {
+                self.w.saturating_mul(self.h)
+            }
+
+            fn congruent(&self, other: &Rectangle<$t>) -> bool {
+                (self.w == other.w && self.h == other.h)
+                    || (self.w == other.h && self.h == other.w)
+            }
+
+            fn perimeter(&self) -> $t {
+                2 * (self.w + self.h)
+            }
+
+            fn diagonal_squared(&self) -> $t {
+                self.w * self.w + self.h * self.h
+            }
+
+            fn is_square(&self) -> bool {
+                self.w == self.h
+            }
+
+            fn contains(&self, other: &Rectangle<$t>) -> bool {
+                other.w <= self.w && other.h <= self.h
+            }
+
+            fn fits_inside(&self, container: &Rectangle<$t>) -> bool {
+                ScalarRectangle::contains(container, self)
+                    || ScalarRectangle::contains(container, &self.transpose())
+            }
+
+            fn scaled(&self, n: $t) -> Rectangle<$t> {
+                Rectangle {
+                    w: self.w * n,
+                    h: self.h * n,
+                }
+            }
+
+            fn split_horizontal(&self, at: $t) -> Option<(Rectangle<$t>, Rectangle<$t>)> {
+                match self.h.checked_sub(at) {
+                    // signed cuts can miss without underflowing
+                    Some(rest) if at <= self.h && rest <= self.h => Some((
+                        Rectangle { w: self.w, h: at },
+                        Rectangle { w: self.w, h: rest },
+                    )),
+                    _ => None,
+                }
+            }
+
+            fn split_vertical(&self, at: $t) -> Option<(Rectangle<$t>, Rectangle<$t>)> {
+                match self.w.checked_sub(at) {
+                    Some(rest) if at <= self.w && rest <= self.w => Some((
+                        Rectangle { w: at, h: self.h },
+                        Rectangle { w: rest, h: self.h },
+                    )),
+                    _ => None,
+                }
+            }
+
+            fn cells(&self) -> impl Iterator<Item = ($t, $t)> {
+                let w = self.w;
+                (0..self.h).flat_map(move |y| (0..w).map(move |x| (x, y)))
+            }
+
+            fn extrude(&self, l: $t) -> Cuboid<$t> {
+                Cuboid {
+                    w: self.w,
+                    h: self.h,
+                    l,
+                }
+            }
+        }
+
+        impl ScalarCuboid for Cuboid<$t> {
+            type Scalar = $t;
+
+            fn volume(&self) -> $t {
+                self.w * self.h * self.l
+            }
+
+            fn checked_volume(&self) -> Option<$t> {
+                self.w.checked_mul(self.h)?.checked_mul(self.l)
+            }
+
+            fn saturating_volume(&self) -> $t {
+                self.w.saturating_mul(self.h).saturating_mul(self.l)
+            }
+
+            fn congruent(&self, other: &Cuboid<$t>) -> bool {
+                sorted_dims(self) == sorted_dims(other)
+            }
+
+            fn surface_area(&self) -> $t {
+                2 * (self.w * self.h + self.w * self.l + self.h * self.l)
+            }
+
+            fn total_edge_length(&self) -> $t {
+                4 * (self.w + self.h + self.l)
+            }
+
+            fn space_diagonal_squared(&self) -> $t {
+                self.w * self.w + self.h * self.h + self.l * self.l
+            }
+
+            fn fits_inside(&self, container: &Cuboid<$t>) -> bool {
+                let [a, b, c] = sorted_dims(self);
+                let [x, y, z] = sorted_dims(container);
+                a <= x && b <= y && c <= z
+            }
+
+            fn scaled(&self, n: $t) -> Cuboid<$t> {
+                Cuboid {
+                    w: self.w * n,
+                    h: self.h * n,
+                    l: self.l * n,
+                }
+            }
+
+            fn voxels(&self) -> impl Iterator<Item = ($t, $t, $t)> {
+                let (w, h) = (self.w, self.h);
+                (0..self.l).flat_map(move |z| {
+                    (0..h).flat_map(move |y| (0..w).map(move |x| (x, y, z)))
+                })
+            }
+        }
+    )*};
+}
+
+scalar_impls!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+fn sorted_dims<T: Ord + Copy>(cuboid: &Cuboid<T>) -> [T; 3] {
+    let mut dims = [cuboid.w, cuboid.h, cuboid.l];
+    dims.sort_unstable();
+    dims
+}
@@ -0,0 +1,373 @@
+//! Finding analog literals hiding in arbitrary text.
+//!
+//! Unlike the [`parse`](crate::parse) module, which expects to be handed a
+//! single analog literal and nothing else, the [`scan`] function goes looking
+//! for analog literals _anywhere_ in a chunk of text (e.g: a README, or a
+//! source file), and reports every well-formed one it finds.
+//!
+//! Since there's no telling what else might be in the text, scanning works by
+//! actually looking at the picture: edges have to line up, corners have to be
+//! where they belong, and dashes have to be drawn right next to one another
+//! (i.e: `+----+`, not `+- - - -+`). On the flip side, anything can be written
+//! inside a rectangle or cuboid, not just comments.
+//!
+//! Just like in the [`parse`](crate::parse) module, cuboids can lean either way
+//! (i.e: be drawn using `/` or `\`).
+//!
+//! To keep things sane, zero-length lines (`++` and `II`) aren't reported, as
+//! they have a habit of showing up in discussions of C++ and World War II.
+//!
+//! Columns are counted in bytes, so drawings sharing a line with non-ASCII text
+//! will need to be drawn with that in mind.
+//!
+//! # Example
+//!
+//! ```rust
+//! use analog_literals::parse::Shape;
+//! use analog_literals::scan::scan;
+//!
+//! let readme = "
+//! Our new logo is a box:
+//!
+//!     +------+
+//!     |      |
+//!     +------+
+//!
+//! and our new bandwidth cap is +----------+ per second.
+//! ";
+//!
+//! let found = scan(readme).collect::<Vec<_>>();
+//! assert_eq!(found.len(), 2);
+//!
+//! match found[0].shape {
+//!     Shape::Rectangle(rect) => assert_eq!((rect.w, rect.h), (3, 1)),
+//!     _ => panic!("that's no rectangle"),
+//! }
+//! assert!(readme[found[0].start..found[0].end].starts_with("+------+"));
+//!
+//! match found[1].shape {
+//!     Shape::Line(line) => assert_eq!(line.0, 5),
+//!     _ => panic!("that's no line"),
+//! }
+//! ```
+
+use core::convert::TryFrom;
+
+use crate::parse::Shape;
+use crate::{Cuboid, Line, Rectangle};
+
+/// An analog literal found by [`scan`].
+#[derive(Debug, Copy, Clone)]
+pub struct Found {
+    /// The analog literal itself
+    pub shape: Shape,
+    /// Byte offset of the first character of the literal (i.e: its top-left
+    /// corner)
+    pub start: usize,
+    /// Byte offset just past the last character of the literal (i.e: its
+    /// bottom-right corner)
+    pub end: usize,
+}
+
+/// Find every well-formed analog literal in the given text, in reading order
+/// of their top-left corners.
+pub fn scan(text: &str) -> Scan<'_> {
+    Scan {
+        grid: Grid {
+            text: text.as_bytes(),
+        },
+        pos: 0,
+    }
+}
+
+/// Iterator returned by [`scan`].
+#[derive(Debug, Clone)]
+pub struct Scan<'a> {
+    grid: Grid<'a>,
+    pos: usize,
+}
+
+impl Iterator for Scan<'_> {
+    type Item = Found;
+
+    fn next(&mut self) -> Option<Found> {
+        while self.pos < self.grid.text.len() {
+            let pos = self.pos;
+            self.pos += 1;
+
+            let at = Pos {
+                line: self.grid.line_start(pos),
+                row: 0,
+                col: 0,
+            };
+            let at = Pos {
+                col: (pos - at.line) as isize,
+                ..at
+            };
+            let found = match self.grid.text[pos] {
+                b'+' => self
+                    .grid
+                    .cuboid(at, Lean::Right)
+                    .or_else(|| self.grid.cuboid(at, Lean::Left))
+                    .or_else(|| self.grid.rectangle(at))
+                    .or_else(|| self.grid.line(at, b'+')),
+                b'I' => self.grid.line(at, b'I'),
+                _ => None,
+            };
+            if found.is_some() {
+                return found;
+            }
+        }
+        None
+    }
+}
+
+/// A position in the text, relative to the start of a particular line.
+#[derive(Debug, Copy, Clone)]
+struct Pos {
+    /// Byte offset of the start of the line
+    line: usize,
+    row: isize,
+    col: isize,
+}
+
+impl Pos {
+    fn offset(self, row: isize, col: isize) -> Pos {
+        Pos {
+            line: self.line,
+            row: self.row + row,
+            col: self.col + col,
+        }
+    }
+}
+
+/// Which way a cuboid's depth recedes into the distance.
+#[derive(Debug, Copy, Clone)]
+enum Lean {
+    /// Drawn using `/`
+    Right,
+    /// Drawn using `\`
+    Left,
+}
+
+#[derive(Debug, Copy, Clone)]
+struct Grid<'a> {
+    text: &'a [u8],
+}
+
+impl Grid<'_> {
+    /// Return the byte offset of the start of the line containing `pos`.
+    fn line_start(&self, pos: usize) -> usize {
+        self.text[..pos]
+            .iter()
+            .rposition(|b| *b == b'\n')
+            .map_or(0, |i| i + 1)
+    }
+
+    /// Return the byte offset of the given position, if it's actually on a
+    /// line of text.
+    fn resolve(&self, pos: Pos) -> Option<usize> {
+        let mut line = pos.line;
+        if pos.row < 0 {
+            for _ in 0..-pos.row {
+                line = self.line_start(line.checked_sub(1)?);
+            }
+        } else {
+            for _ in 0..pos.row {
+                line += self.text[line..].iter().position(|b| *b == b'\n')? + 1;
+            }
+        }
+
+        let offset = line.checked_add(usize::try_from(pos.col).ok()?)?;
+        let len = self.text[line..]
+            .iter()
+            .position(|b| *b == b'\n')
+            .unwrap_or(self.text.len() - line);
+        if offset < line + len {
+            Some(offset)
+        } else {
+            None
+        }
+    }
+
+    /// Return the byte at the given position, with anything off the edge of
+    /// the text being treated as whitespace.
+    fn get(&self, pos: Pos) -> u8 {
+        self.resolve(pos).map_or(b' ', |offset| self.text[offset])
+    }
+
+    /// Read a horizontal edge starting at the given corner, returning the
+    /// column of its other corner and its length in `--`.
+    fn edge(&self, at: Pos, corner: u8) -> Option<(isize, usize)> {
+        if self.get(at) != corner {
+            return None;
+        }
+        let mut end = at.col + 1;
+        while self.get(Pos { col: end, ..at }) == b'-' {
+            end += 1;
+        }
+        let dashes = (end - at.col - 1) as usize;
+        if self.get(Pos { col: end, ..at }) != corner || dashes % 2 != 0 {
+            return None;
+        }
+        Some((end, dashes / 2))
+    }
+
+    fn found(&self, shape: Shape, start: Pos, end: Pos) -> Option<Found> {
+        Some(Found {
+            shape,
+            start: self.resolve(start)?,
+            end: self.resolve(end)? + 1,
+        })
+    }
+
+    fn line(&self, at: Pos, corner: u8) -> Option<Found> {
+        let (end, len) = self.edge(at, corner)?;
+        if len == 0 {
+            return None;
+        }
+
+        // make sure this isn't actually part of some bigger drawing
+        let is_word = |b: u8| b.is_ascii_alphanumeric() || b == b'_';
+        let before = self.get(at.offset(0, -1));
+        let after = self.get(Pos { col: end + 1, ..at });
+        if matches!(before, b'-' | b'+') || matches!(after, b'-' | b'+') {
+            return None;
+        }
+        if corner == b'I' && (is_word(before) || is_word(after)) {
+            return None;
+        }
+        for col in [at.col, end] {
+            for (row, dc) in [(-1, -1), (-1, 0), (-1, 1), (1, -1), (1, 0), (1, 1)] {
+                if matches!(
+                    self.get(Pos {
+                        col: col + dc,
+                        ..at.offset(row, 0)
+                    }),
+                    b'|' | b'/' | b'\\' | b'+'
+                ) {
+                    return None;
+                }
+            }
+        }
+
+        self.found(Shape::Line(Line(len)), at, Pos { col: end, ..at })
+    }
+
+    fn rectangle(&self, at: Pos) -> Option<Found> {
+        let (right, w) = self.edge(at, b'+')?;
+        // the front face of a cuboid isn't a rectangle in its own right
+        if self.get(at.offset(-1, 1)) == b'/' || self.get(at.offset(-1, -1)) == b'\\' {
+            return None;
+        }
+
+        let mut h = 0;
+        while self.get(at.offset(h + 1, 0)) == b'|' {
+            if self.get(Pos {
+                col: right,
+                ..at.offset(h + 1, 0)
+            }) != b'|'
+            {
+                return None;
+            }
+            h += 1;
+        }
+        if h == 0 && self.get(at.offset(1, 0)) != b'+' {
+            return None;
+        }
+
+        let bottom = at.offset(h + 1, 0);
+        match self.edge(bottom, b'+') {
+            Some((end, bottom_w)) if end == right && bottom_w == w => self.found(
+                Shape::Rectangle(Rectangle { w, h: h as usize }),
+                at,
+                Pos {
+                    col: right,
+                    ..bottom
+                },
+            ),
+            _ => None,
+        }
+    }
+
+    fn cuboid(&self, at: Pos, lean: Lean) -> Option<Found> {
+        let (right, w) = self.edge(at, b'+')?;
+        // which way each row of the drawing shifts as the cuboid gets closer
+        let (slant, shift) = match lean {
+            Lean::Right => (b'/', -1),
+            Lean::Left => (b'\\', 1),
+        };
+
+        // down the sides of the top face...
+        let mut l = 0;
+        while self.get(at.offset(l + 1, shift * (l + 1))) == slant {
+            if self.get(Pos {
+                col: right + shift * (l + 1),
+                ..at.offset(l + 1, 0)
+            }) != slant
+            {
+                return None;
+            }
+            l += 1;
+        }
+        if l == 0 {
+            return None;
+        }
+
+        // ...around the front face...
+        let front = at.offset(l + 1, shift * (l + 1));
+        let front_right = right + shift * (l + 1);
+        match self.edge(front, b'+') {
+            Some((end, front_w)) if end == front_right && front_w == w => {}
+            _ => return None,
+        }
+        let mut h = 0;
+        while self.get(front.offset(h + 1, 0)) == b'|' {
+            if self.get(Pos {
+                col: front_right,
+                ..front.offset(h + 1, 0)
+            }) != b'|'
+            {
+                return None;
+            }
+            h += 1;
+        }
+        let bottom = front.offset(h + 1, 0);
+        match self.edge(bottom, b'+') {
+            Some((end, bottom_w)) if end == front_right && bottom_w == w => {}
+            _ => return None,
+        }
+
+        // ...and back up the side face
+        let back = match lean {
+            Lean::Right => Pos { col: right, ..at },
+            Lean::Left => at,
+        };
+        for row in 1..=h {
+            if self.get(back.offset(row, 0)) != b'|' {
+                return None;
+            }
+        }
+        if self.get(back.offset(h + 1, 0)) != b'+' {
+            return None;
+        }
+        for k in 1..=l {
+            if self.get(back.offset(h + 1 + k, shift * k)) != slant {
+                return None;
+            }
+        }
+
+        self.found(
+            Shape::Cuboid(Cuboid {
+                w,
+                h: h as usize,
+                l: l as usize,
+            }),
+            at,
+            Pos {
+                col: front_right,
+                ..bottom
+            },
+        )
+    }
+}
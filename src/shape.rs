@@ -0,0 +1,163 @@
+use crate::interval::Interval;
+use crate::{Cuboid, LabeledRectangle, Line, PositionedRectangle, Rectangle};
+
+/// One of the three axes an analog literal can extend along.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Axis {
+    /// Left to right (i.e: counted in `--`)
+    X,
+    /// Top to bottom (i.e: counted in `|`)
+    Y,
+    /// Front to back (i.e: counted in `/`)
+    Z,
+}
+
+impl Axis {
+    /// All three axes, in order.
+    pub const ALL: [Axis; 3] = [Axis::X, Axis::Y, Axis::Z];
+}
+
+/// Behavior shared by every kind of analog literal, for when one function
+/// needs to handle lines, rectangles, and cuboids alike.
+///
+/// # Example
+///
+/// ```rust
+/// use analog_literals::{analog_literal, AnalogShape, Axis};
+///
+/// /// Describe just about any analog literal.
+/// fn describe<S: AnalogShape>(shape: &S) -> (usize, [usize; 3], usize) {
+///     let extents = [
+///         shape.extent(Axis::X),
+///         shape.extent(Axis::Y),
+///         shape.extent(Axis::Z),
+///     ];
+///     (S::DIMENSIONS, extents, shape.measure())
+/// }
+///
+/// let line = analog_literal! { line; +------+ };
+/// let rect = analog_literal! {
+///     +------+
+///     |      |
+///     +------+
+/// };
+/// let cube = analog_literal! {
+///       +------+
+///      /      /|
+///     +------+ |
+///     |      | +
+///     |      |/
+///     +------+
+/// };
+///
+/// assert_eq!(describe(&line), (1, [3, 0, 0], 3));
+/// assert_eq!(describe(&rect), (2, [3, 1, 0], 3));
+/// assert_eq!(describe(&cube), (3, [3, 2, 1], 6));
+/// ```
+///
+/// Rectangles that carry something extra along with them (e.g:
+/// [`PositionedRectangle`] and [`LabeledRectangle`]) measure up just like the
+/// rectangle at their heart, and an [`Interval`] is measured like the [`Line`]
+/// it covers.
+///
+/// [`Tagged`](crate::unit::Tagged) literals are deliberately left out: a plain
+/// `usize` extent or measure would drop the unit on the floor, which is
+/// exactly what `Tagged` is there to prevent. Use
+/// [`Tagged::untagged`](crate::unit::Tagged::untagged) to measure the literal
+/// once it's safe to forget about the unit.
+pub trait AnalogShape {
+    /// How many dimensions the analog literal has.
+    const DIMENSIONS: usize;
+
+    /// Return how far the analog literal extends along the given axis.
+    ///
+    /// Axes beyond the literal's dimensions have an extent of 0 (e.g: lines
+    /// are infinitely thin).
+    fn extent(&self, axis: Axis) -> usize;
+
+    /// Return the length, area, or volume of the analog literal (depending on
+    /// how many dimensions it has).
+    fn measure(&self) -> usize;
+}
+
+impl AnalogShape for Line {
+    const DIMENSIONS: usize = 1;
+
+    fn extent(&self, axis: Axis) -> usize {
+        match axis {
+            Axis::X => self.0,
+            Axis::Y | Axis::Z => 0,
+        }
+    }
+
+    fn measure(&self) -> usize {
+        self.0
+    }
+}
+
+impl AnalogShape for Rectangle {
+    const DIMENSIONS: usize = 2;
+
+    fn extent(&self, axis: Axis) -> usize {
+        match axis {
+            Axis::X => self.w,
+            Axis::Y => self.h,
+            Axis::Z => 0,
+        }
+    }
+
+    fn measure(&self) -> usize {
+        self.area()
+    }
+}
+
+impl AnalogShape for Cuboid {
+    const DIMENSIONS: usize = 3;
+
+    fn extent(&self, axis: Axis) -> usize {
+        Cuboid::extent(self, axis)
+    }
+
+    fn measure(&self) -> usize {
+        self.volume()
+    }
+}
+
+impl AnalogShape for PositionedRectangle {
+    const DIMENSIONS: usize = 2;
+
+    fn extent(&self, axis: Axis) -> usize {
+        self.rect().extent(axis)
+    }
+
+    fn measure(&self) -> usize {
+        self.rect().area()
+    }
+}
+
+impl AnalogShape for LabeledRectangle {
+    const DIMENSIONS: usize = 2;
+
+    fn extent(&self, axis: Axis) -> usize {
+        self.rect.extent(axis)
+    }
+
+    fn measure(&self) -> usize {
+        self.rect.area()
+    }
+}
+
+impl AnalogShape for Interval {
+    const DIMENSIONS: usize = 1;
+
+    fn extent(&self, axis: Axis) -> usize {
+        match axis {
+            Axis::X => self.len(),
+            Axis::Y | Axis::Z => 0,
+        }
+    }
+
+    fn measure(&self) -> usize {
+        self.len()
+    }
+}
@@ -0,0 +1,18 @@
+//! Staircases, drawn right in the source code.
+//!
+//! Use the [`analog_stairs!`](crate::analog_stairs) macro to construct them.
+
+/// A flight of stairs, where every step is exactly the same size.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Stairs {
+    /// Number of steps
+    pub steps: usize,
+    /// Height of each step (i.e: its riser), counted in `|`
+    pub rise: usize,
+    /// Depth of each step (i.e: its tread), counted in `--`
+    pub run: usize,
+    /// How far the whole flight goes sideways, counted in `--`
+    pub total_w: usize,
+    /// How far the whole flight goes up, counted in `|`
+    pub total_h: usize,
+}
@@ -0,0 +1,23 @@
+//! Tables, drawn right in the source code.
+//!
+//! Help text and test fixtures tend to come in tables, and tables tend to end
+//! up as a pair of parallel string arrays that nobody can read. The [`Table`]
+//! type in this module keeps the table looking like a table.
+//!
+//! Use the [`analog_table!`](crate::analog_table) macro to construct them.
+
+/// A table with `C` columns and `R` rows (not counting the header row).
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Table<const C: usize, const R: usize> {
+    /// The text in the header row
+    pub headers: [&'static str; C],
+    /// The text in each of the remaining rows, top to bottom
+    pub rows: [[&'static str; C]; R],
+}
+
+impl<const C: usize, const R: usize> Table<C, R> {
+    /// Return the index of the column with the given header.
+    pub fn column(&self, header: &str) -> Option<usize> {
+        self.headers.iter().position(|h| *h == header)
+    }
+}
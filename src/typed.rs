@@ -0,0 +1,132 @@
+//! Analog literals whose dimensions live in the type system.
+//!
+//! Regular analog literals are great and all, but their dimensions are just
+//! boring runtime fields. The types in this module hoist those dimensions all
+//! the way up into const generics, which means the compiler itself has to
+//! squint at your drawings and agree that they're the same size.
+//!
+//! Use the [`analog_literal_typed!`](crate::analog_literal_typed) macro to
+//! construct them.
+//!
+//! # Example
+//!
+//! ```rust
+//! # use analog_literals::{analog_literal_typed, typed::Rect};
+//! let screen: Rect<4, 2> = analog_literal_typed! {
+//!     +--------+
+//!     |        |
+//!     |        |
+//!     +--------+
+//! };
+//!
+//! let framebuffer: [[u8; 4]; 2] = screen.array(0);
+//! assert_eq!(framebuffer.len(), 2);
+//! ```
+//!
+//! And if the drawings don't match up, your code doesn't compile:
+//!
+//! ```rust,compile_fail
+//! # use analog_literals::{analog_literal_typed, typed::Rect};
+//! let screen: Rect<4, 2> = analog_literal_typed! {
+//!     +------+
+//!     |      |
+//!     |      |
+//!     +------+
+//! };
+//! ```
+
+/// A 1D Line of length `L`, where `L` is part of the type.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Line<const L: usize>;
+
+impl<const L: usize> Line<L> {
+    /// Length of the literal (counts how many `--` long the analog literal is)
+    pub const L: usize = L;
+
+    /// Return the length of the line.
+    pub const fn len(&self) -> usize {
+        L
+    }
+
+    /// Return `true` if the line is of the form `II`.
+    pub const fn is_empty(&self) -> bool {
+        L == 0
+    }
+
+    /// Return an array with one element per `--` in the literal.
+    pub const fn array<T: Copy>(&self, init: T) -> [T; L] {
+        [init; L]
+    }
+}
+
+/// A 2D Rectangle of width `W` and height `H`, where both dimensions are part
+/// of the type.
+///
+/// This is the type-level twin of [`Rectangle`](crate::Rectangle). Two `Rect`s
+/// of the same type are quite literally the same rectangle, so `==` is a bit
+/// of a formality.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Rect<const W: usize, const H: usize>;
+
+impl<const W: usize, const H: usize> Rect<W, H> {
+    /// Width of the literal (counts how many `--` wide the analog literal is)
+    pub const W: usize = W;
+    /// Height of the literal (counts how many `|` tall the analog literal is)
+    pub const H: usize = H;
+
+    /// Return the area of the rectangle.
+    pub const fn area(&self) -> usize {
+        W * H
+    }
+
+    /// Forget all about the type-level dimensions, returning a plain old
+    /// [`Rectangle`](crate::Rectangle).
+    pub const fn erase(&self) -> crate::Rectangle {
+        crate::Rectangle { w: W, h: H }
+    }
+
+    /// Return a row-major `[[T; W]; H]` array the exact same size as the
+    /// literal, with every element set to `init`.
+    pub const fn array<T: Copy>(&self, init: T) -> [[T; W]; H] {
+        [[init; W]; H]
+    }
+}
+
+/// A 3D Cuboid of width `W`, height `H`, and length `L`, where all three
+/// dimensions are part of the type.
+///
+/// This is the type-level twin of [`Cuboid`](crate::Cuboid).
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Cuboid<const W: usize, const H: usize, const L: usize>;
+
+impl<const W: usize, const H: usize, const L: usize> Cuboid<W, H, L> {
+    /// Width of the literal (counts how many `--` wide the analog literal is)
+    pub const W: usize = W;
+    /// Height of the literal (counts how many `|` tall the analog literal is)
+    pub const H: usize = H;
+    /// Length of the literal (counts how many `/` deep the analog literal is)
+    pub const L: usize = L;
+
+    /// Return the volume of the cuboid.
+    pub const fn volume(&self) -> usize {
+        W * H * L
+    }
+
+    /// Forget all about the type-level dimensions, returning a plain old
+    /// [`Cuboid`](crate::Cuboid).
+    pub const fn erase(&self) -> crate::Cuboid {
+        crate::Cuboid { w: W, h: H, l: L }
+    }
+
+    /// Returns the type-level [`Rect`] with the same dimensions as the front
+    /// of the cuboid.
+    pub const fn front(&self) -> Rect<W, H> {
+        Rect
+    }
+
+    /// Return a `[[[T; W]; H]; L]` array the exact same size as the literal
+    /// (indexed as `[z][y][x]`), with every element set to `init`.
+    pub const fn array<T: Copy>(&self, init: T) -> [[[T; W]; H]; L] {
+        [[[init; W]; H]; L]
+    }
+}
@@ -0,0 +1,164 @@
+//! Analog literals with units attached, so that pixels and millimeters never
+//! meet.
+//!
+//! A `--` is whatever the surrounding code says it is, which is great right up
+//! until a rectangle drawn in pixels gets handed to code expecting millimeters.
+//! Wrapping a literal in a [`Tagged`] bakes its unit into its type, turning
+//! that sort of mixup into a good old fashioned type error.
+//!
+//! A few common units ([`Px`], [`Mm`], and [`Cell`]) come out of the box, and
+//! any other type can become a unit by implementing [`Unit`].
+//!
+//! [`analog_literal!`](crate::analog_literal) can tag a literal directly by
+//! putting `in <unit>;` in front of the drawing (which can be combined with a
+//! scalar prefix too).
+//!
+//! # Example
+//!
+//! ```rust
+//! use analog_literals::analog_literal;
+//! use analog_literals::unit::{Mm, Px, Tagged};
+//! use analog_literals::{Rectangle, ScalarRectangle};
+//!
+//! const LOGO: Tagged<Rectangle, Px> = analog_literal! { in Px;
+//!     +--------+
+//!     |        |
+//!     +--------+
+//! };
+//!
+//! const MARGIN: Tagged<Rectangle<u32>, Mm> = analog_literal! { in Mm; u32;
+//!     +--+
+//!     |  |
+//!     +--+
+//! };
+//!
+//! fn print(area: Tagged<Rectangle<u32>, Mm>) -> u32 {
+//!     area.untagged().area()
+//! }
+//!
+//! assert_eq!(LOGO.untagged().area(), 4);
+//! assert_eq!(print(MARGIN), 1);
+//! ```
+//!
+//! Passing in the wrong unit is caught at compile time:
+//!
+//! ```compile_fail
+//! # use analog_literals::analog_literal;
+//! # use analog_literals::unit::{Mm, Px, Tagged};
+//! # use analog_literals::Rectangle;
+//! fn print(area: Tagged<Rectangle, Mm>) {}
+//!
+//! print(analog_literal! { in Px; +--+ |  | +--+ });
+//! ```
+//!
+//! Tagged literals don't quietly turn back into plain old literals either,
+//! since code expecting a plain `&Rectangle` has no idea what unit it's in:
+//!
+//! ```compile_fail
+//! # use analog_literals::analog_literal;
+//! # use analog_literals::unit::{Px, Tagged};
+//! # use analog_literals::Rectangle;
+//! fn area(rect: &Rectangle) -> usize {
+//!     rect.area()
+//! }
+//!
+//! let logo: Tagged<Rectangle, Px> = analog_literal! { in Px; +--+ |  | +--+ };
+//! area(&logo);
+//! ```
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::marker::PhantomData;
+
+/// A unit of measurement for analog literals.
+pub trait Unit {
+    /// Name of the unit, as shown when debug-printing a [`Tagged`] literal.
+    const NAME: &'static str;
+}
+
+/// Pixels.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Px;
+
+/// Millimeters.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Mm;
+
+/// Grid cells (e.g: in a terminal, or a spreadsheet).
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Cell;
+
+impl Unit for Px {
+    const NAME: &'static str = "px";
+}
+
+impl Unit for Mm {
+    const NAME: &'static str = "mm";
+}
+
+impl Unit for Cell {
+    const NAME: &'static str = "cell";
+}
+
+/// An analog literal `S`, measured in `U`s.
+///
+/// Deliberately _doesn't_ deref to the literal itself, since that would let it
+/// slip into any code expecting a plain, unitless literal (and would let
+/// `px.area() + mm.area()` compile just fine). Use [`Tagged::untagged`] to get
+/// at the literal once it's safe to forget about the unit.
+pub struct Tagged<S, U> {
+    /// The analog literal itself
+    pub shape: S,
+    unit: PhantomData<U>,
+}
+
+impl<S, U: Unit> Tagged<S, U> {
+    /// Tag an analog literal with a unit.
+    pub const fn new(shape: S) -> Tagged<S, U> {
+        Tagged {
+            shape,
+            unit: PhantomData,
+        }
+    }
+}
+
+impl<S: Copy, U> Tagged<S, U> {
+    /// Return the analog literal, sans unit.
+    pub const fn untagged(&self) -> S {
+        self.shape
+    }
+}
+
+// written out by hand, since `#[derive]` would insist on `U` implementing
+// these traits too, even though there's never actually a `U` around
+
+impl<S: Clone, U> Clone for Tagged<S, U> {
+    fn clone(&self) -> Tagged<S, U> {
+        Tagged {
+            shape: self.shape.clone(),
+            unit: PhantomData,
+        }
+    }
+}
+
+impl<S: Copy, U> Copy for Tagged<S, U> {}
+
+impl<S: PartialEq, U> PartialEq for Tagged<S, U> {
+    fn eq(&self, other: &Tagged<S, U>) -> bool {
+        self.shape == other.shape
+    }
+}
+
+impl<S: Eq, U> Eq for Tagged<S, U> {}
+
+impl<S: Hash, U> Hash for Tagged<S, U> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.shape.hash(state);
+    }
+}
+
+impl<S: fmt::Debug, U: Unit> fmt::Debug for Tagged<S, U> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple(U::NAME).field(&self.shape).finish()
+    }
+}
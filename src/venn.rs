@@ -0,0 +1,41 @@
+//! Venn diagrams, drawn right in the source code.
+//!
+//! Use the [`analog_venn!`](crate::analog_venn) macro to construct them.
+
+/// A two-set Venn diagram, recording how big each of its three regions is.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Venn {
+    /// Size of the part of the left circle that doesn't overlap the right one
+    pub left_only: usize,
+    /// Size of the part of the right circle that doesn't overlap the left one
+    pub right_only: usize,
+    /// Size of the overlap between the two circles
+    pub both: usize,
+}
+
+impl Venn {
+    /// Size of the entire left circle.
+    pub const fn left(&self) -> usize {
+        self.left_only + self.both
+    }
+
+    /// Size of the entire right circle.
+    pub const fn right(&self) -> usize {
+        self.right_only + self.both
+    }
+
+    /// Size of everything in either circle.
+    pub const fn union(&self) -> usize {
+        self.left_only + self.right_only + self.both
+    }
+
+    /// Size of everything in both circles.
+    pub const fn intersection(&self) -> usize {
+        self.both
+    }
+
+    /// Size of everything in exactly one of the circles.
+    pub const fn symmetric_difference(&self) -> usize {
+        self.left_only + self.right_only
+    }
+}
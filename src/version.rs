@@ -0,0 +1,32 @@
+//! Version numbers, drawn right in the source code.
+//!
+//! Use the [`analog_version!`](crate::analog_version) macro to construct them.
+
+/// A `major.minor.patch` version number.
+///
+/// Versions are ordered the way you'd expect (i.e: by major version, then
+/// minor version, then patch version).
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Version {
+    /// Major version, for when things break
+    pub major: u64,
+    /// Minor version, for when things get added
+    pub minor: u64,
+    /// Patch version, for when things get fixed
+    pub patch: u64,
+}
+
+impl Version {
+    /// Check if this version is the same as or newer than `other`.
+    ///
+    /// Same thing as `self >= other`, except it works in a `const` context.
+    pub const fn at_least(&self, other: &Version) -> bool {
+        if self.major != other.major {
+            self.major > other.major
+        } else if self.minor != other.minor {
+            self.minor > other.minor
+        } else {
+            self.patch >= other.patch
+        }
+    }
+}